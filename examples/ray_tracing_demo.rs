@@ -52,7 +52,7 @@ impl RayTracer for RayTracingDemo {
     fn shoot_ray(&self, source: Vec3D, direction: UnitVec3D) -> Color {
         let ray = mach::collisions::shapes::Ray::new(source, direction);
         match self.world.cast_ray(&ray) {
-            Some(body) => {
+            Ok(Some(body)) => {
                 // TODO calculate this based on the combination of appropriate
                 // colors etc.
                 body.extension_data().ambient
@@ -0,0 +1,37 @@
+use Scalar;
+
+/// Describes how a run of an iterative algorithm finished.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ConvergenceReport {
+    iterations: usize,
+    residual: Scalar,
+    converged: bool,
+}
+
+impl ConvergenceReport {
+    pub fn new(iterations: usize, residual: Scalar, converged: bool) -> ConvergenceReport {
+        ConvergenceReport {
+            iterations: iterations,
+            residual: residual,
+            converged: converged,
+        }
+    }
+
+    /// The number of iterations which were run.
+    #[inline(always)]
+    pub fn iterations(&self) -> usize {
+        self.iterations
+    }
+
+    /// The residual of the algorithm after the last iteration.
+    #[inline(always)]
+    pub fn residual(&self) -> Scalar {
+        self.residual
+    }
+
+    /// Returns `false` if the algorithm was stopped before it converged.
+    #[inline(always)]
+    pub fn has_converged(&self) -> bool {
+        self.converged
+    }
+}
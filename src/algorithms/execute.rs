@@ -1,15 +1,31 @@
-use algorithms::IterativeAlgorithm;
+use algorithms::{ConvergenceReport, IterativeAlgorithm};
 
 pub trait Execute: IterativeAlgorithm {
     fn execute(self) -> Self::Result;
+
+    /// Runs the algorithm like `execute`, also reporting how it finished.
+    fn execute_with_report(self) -> (Self::Result, ConvergenceReport);
 }
 
 impl<T: IterativeAlgorithm> Execute for T {
     fn execute(mut self) -> T::Result {
-        while !self.has_converged() {
+        while !self.has_finished() {
             self.next_iteration();
         }
 
         return self.result();
     }
+
+    fn execute_with_report(mut self) -> (T::Result, ConvergenceReport) {
+        let mut iterations = 0;
+
+        while !self.has_finished() {
+            self.next_iteration();
+            iterations += 1;
+        }
+
+        let report = ConvergenceReport::new(iterations, self.residual(), self.has_converged());
+
+        return (self.result(), report);
+    }
 }
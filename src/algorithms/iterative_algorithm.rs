@@ -1,3 +1,5 @@
+use Scalar;
+
 pub trait IterativeAlgorithm: Sized {
     type Result;
 
@@ -6,4 +8,14 @@ pub trait IterativeAlgorithm: Sized {
     fn has_converged(&self) -> bool;
 
     fn next_iteration(&mut self);
+
+    /// Measures how far the algorithm is from converging, where the meaning
+    /// of the value depends on the algorithm.
+    fn residual(&self) -> Scalar;
+
+    /// Returns `true` once no more iterations should be run, which unless
+    /// overridden is when the algorithm has converged.
+    fn has_finished(&self) -> bool {
+        self.has_converged()
+    }
 }
@@ -1,3 +1,8 @@
+#[cfg(test)]
+#[path="../../tests/algorithms/limit_iterations_to_test.rs"]
+mod tests;
+
+use Scalar;
 use algorithms::IterativeAlgorithm;
 
 pub trait LimitIterationsTo: IterativeAlgorithm {
@@ -28,11 +33,21 @@ impl<T: IterativeAlgorithm> IterativeAlgorithm for IterationLimiter<T> {
         self.algorithm.result()
     }
 
+    #[inline(always)]
     fn has_converged(&self) -> bool {
-        self.algorithm.has_converged() ||
+        self.algorithm.has_converged()
+    }
+
+    fn has_finished(&self) -> bool {
+        self.algorithm.has_finished() ||
             self.current_iteration >= self.iteration_limit
     }
 
+    #[inline(always)]
+    fn residual(&self) -> Scalar {
+        self.algorithm.residual()
+    }
+
     fn next_iteration(&mut self) {
         self.algorithm.next_iteration();
         self.current_iteration += 1;
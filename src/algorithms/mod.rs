@@ -2,8 +2,10 @@ mod execute;
 mod panic_on_iteration;
 mod limit_iterations_to;
 mod iterative_algorithm;
+mod convergence_report;
 
 pub use self::execute::Execute;
 pub use self::panic_on_iteration::{PanicOnIteration, IterationLimiterWithPanic};
 pub use self::limit_iterations_to::{IterationLimiter, LimitIterationsTo};
 pub use self::iterative_algorithm::IterativeAlgorithm;
+pub use self::convergence_report::ConvergenceReport;
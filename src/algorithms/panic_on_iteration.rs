@@ -1,3 +1,4 @@
+use Scalar;
 use algorithms::IterativeAlgorithm;
 
 pub trait PanicOnIteration: IterativeAlgorithm {
@@ -35,6 +36,16 @@ impl<T: IterativeAlgorithm> IterativeAlgorithm for IterationLimiterWithPanic<T>
         self.algorithm.has_converged()
     }
 
+    #[inline(always)]
+    fn has_finished(&self) -> bool {
+        self.algorithm.has_finished()
+    }
+
+    #[inline(always)]
+    fn residual(&self) -> Scalar {
+        self.algorithm.residual()
+    }
+
     fn next_iteration(&mut self) {
         self.algorithm.next_iteration();
         self.current_iteration += 1;
@@ -69,7 +69,7 @@ impl<O> Broadphase<O> for BruteForceBroadphase<O> where O: CollisionObject {
             let body_0 = handle_0.borrow();
 
             for handle_1 in self.0.foreground_handles_iter().skip(index + 1) {
-                let body_1 = handle_0.borrow();
+                let body_1 = handle_1.borrow();
 
                 if CollisionGroup::test(body_0.group(), body_1.group()) && O::Narrowphase::test(body_0.narrowphase_ref(), body_1.narrowphase_ref()) {
                     let pair = CloseProximityPair(handle_0.clone(), handle_1.clone());
@@ -82,7 +82,7 @@ impl<O> Broadphase<O> for BruteForceBroadphase<O> where O: CollisionObject {
             let body_0 = handle_0.borrow();
 
             for handle_1 in self.0.environment_handles_iter() {
-                let body_1 = handle_0.borrow();
+                let body_1 = handle_1.borrow();
 
                 if CollisionGroup::test(body_0.group(), body_1.group()) && O::Narrowphase::test(body_0.narrowphase_ref(), body_1.narrowphase_ref()) {
                     let pair = CloseProximityPair(handle_0.clone(), handle_1.clone());
@@ -0,0 +1,66 @@
+#[cfg(test)]
+#[path="../../tests/collisions/collision_filter_test.rs"]
+mod tests;
+
+use std::collections::HashSet;
+
+use ID;
+use utils::ordered_pair;
+
+/// Filters pairs of bodies after their `CollisionGroup`s have been tested.
+/// Pairs can be explicitly ignored (e.g. bodies joined by a joint), and an
+/// optional callback can inspect the extension data of both bodies to make the
+/// final decision.
+pub struct CollisionFilter<E> {
+    ignored_pairs: HashSet<(ID, ID)>,
+    callback: Option<Box<Fn(&E, &E) -> bool>>,
+}
+
+impl<E> CollisionFilter<E> {
+    /// Creates a new `CollisionFilter` which allows all pairs to collide.
+    pub fn new() -> CollisionFilter<E> {
+        CollisionFilter {
+            ignored_pairs: HashSet::new(),
+            callback: None,
+        }
+    }
+
+    /// Prevents the bodies with the given IDs from colliding with each other.
+    pub fn ignore_pair(&mut self, id_0: ID, id_1: ID) {
+        self.ignored_pairs.insert(ordered_pair(id_0, id_1));
+    }
+
+    /// Allows a previously ignored pair of bodies to collide again.
+    pub fn restore_pair(&mut self, id_0: ID, id_1: ID) {
+        self.ignored_pairs.remove(&ordered_pair(id_0, id_1));
+    }
+
+    /// Returns true if the pair of bodies has been ignored.
+    pub fn is_pair_ignored(&self, id_0: ID, id_1: ID) -> bool {
+        self.ignored_pairs.contains(&ordered_pair(id_0, id_1))
+    }
+
+    /// Sets the callback used to decide if two bodies should collide based on
+    /// their extension data. Only pairs for which the callback returns true
+    /// are allowed to collide.
+    pub fn set_callback<F>(&mut self, callback: F) where F: Fn(&E, &E) -> bool + 'static {
+        self.callback = Some(Box::new(callback));
+    }
+
+    /// Removes the callback, if any.
+    pub fn clear_callback(&mut self) {
+        self.callback = None;
+    }
+
+    /// Returns true if the pair of bodies is allowed to collide.
+    pub fn test(&self, id_0: ID, extension_0: &E, id_1: ID, extension_1: &E) -> bool {
+        if self.is_pair_ignored(id_0, id_1) {
+            return false;
+        }
+
+        match self.callback {
+            Some(ref callback) => callback(extension_0, extension_1),
+            None => true,
+        }
+    }
+}
@@ -2,6 +2,11 @@
 #[path="../../tests/collisions/collision_group_test.rs"]
 mod tests;
 
+/// Determines which bodies are allowed to collide with each other. Each group
+/// is described by a 32-bit category (the bits the group belongs to) and a
+/// 32-bit mask (the categories the group collides with). The named variants
+/// are presets occupying the lowest bits, the `Environment` preset being
+/// reserved for bodies that never collide with each other.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub enum CollisionGroup {
     Default,
@@ -11,19 +16,39 @@ pub enum CollisionGroup {
     C,
     D,
     E,
+    Custom { category: u32, mask: u32 },
 }
 
-impl CollisionGroup {
-    pub fn test(group_0: CollisionGroup, group_1: CollisionGroup) -> bool {
-        match (group_0, group_1) {
-            (CollisionGroup::Environment, CollisionGroup::Environment) => false,
-
-            (CollisionGroup::Environment, _) |
-            (_, CollisionGroup::Environment) => true,
+static ENVIRONMENT_CATEGORY: u32 = 1 << 0;
 
-            (a, b) if a == b => true,
+impl CollisionGroup {
+    /// Returns the category bits of the group.
+    pub fn category(&self) -> u32 {
+        match *self {
+            CollisionGroup::Environment => ENVIRONMENT_CATEGORY,
+            CollisionGroup::Default => 1 << 1,
+            CollisionGroup::A => 1 << 2,
+            CollisionGroup::B => 1 << 3,
+            CollisionGroup::C => 1 << 4,
+            CollisionGroup::D => 1 << 5,
+            CollisionGroup::E => 1 << 6,
+            CollisionGroup::Custom { category, .. } => category,
+        }
+    }
 
-            _otherwise => false,
+    /// Returns the mask of categories the group is allowed to collide with.
+    pub fn mask(&self) -> u32 {
+        match *self {
+            CollisionGroup::Environment => !ENVIRONMENT_CATEGORY,
+            CollisionGroup::Custom { mask, .. } => mask,
+            preset => preset.category() | ENVIRONMENT_CATEGORY,
         }
     }
+
+    /// Returns true if the two groups are allowed to collide. Both groups must
+    /// accept the category of the other.
+    pub fn test(group_0: CollisionGroup, group_1: CollisionGroup) -> bool {
+        group_0.category() & group_1.mask() != 0 &&
+            group_1.category() & group_0.mask() != 0
+    }
 }
@@ -7,6 +7,9 @@ use collisions::{ContactSet, CollisionObject};
 pub struct Contact<O> where O: CollisionObject {
     set: ContactSet,
     handles: (Handle<O>, Handle<O>),
+    is_enabled: bool,
+    friction_scale: Scalar,
+    restitution_scale: Scalar,
 }
 
 impl<O> Contact<O> where O: CollisionObject {
@@ -14,6 +17,9 @@ impl<O> Contact<O> where O: CollisionObject {
         Contact {
             set: set,
             handles: (handle_0, handle_1),
+            is_enabled: true,
+            friction_scale: 1.0,
+            restitution_scale: 1.0,
         }
     }
 
@@ -27,11 +33,57 @@ impl<O> Contact<O> where O: CollisionObject {
         self.set.points()
     }
 
+    /// The average of the contact points.
+    pub fn center(&self) -> Vec3D {
+        self.points().iter()
+            .fold(Vec3D::zero(), |total, point| total + point) / self.points().len() as Scalar
+    }
+
     #[inline(always)]
     pub fn normal(&self) -> UnitVec3D {
         self.set.normal()
     }
 
+    /// Overrides the contact normal used to resolve the contact.
+    #[inline(always)]
+    pub fn set_normal(&mut self, normal: UnitVec3D) {
+        self.set.set_normal(normal);
+    }
+
+    #[inline(always)]
+    pub fn is_enabled(&self) -> bool {
+        self.is_enabled
+    }
+
+    /// Prevents the contact from being resolved by the constraint solver.
+    #[inline(always)]
+    pub fn disable(&mut self) {
+        self.is_enabled = false;
+    }
+
+    /// The factor applied to the combined friction coefficient of the bodies.
+    #[inline(always)]
+    pub fn friction_scale(&self) -> Scalar {
+        self.friction_scale
+    }
+
+    #[inline(always)]
+    pub fn set_friction_scale(&mut self, scale: Scalar) {
+        self.friction_scale = scale;
+    }
+
+    /// The factor applied to the combined restitution coefficient of the
+    /// bodies.
+    #[inline(always)]
+    pub fn restitution_scale(&self) -> Scalar {
+        self.restitution_scale
+    }
+
+    #[inline(always)]
+    pub fn set_restitution_scale(&mut self, scale: Scalar) {
+        self.restitution_scale = scale;
+    }
+
     #[inline(always)]
     pub fn handles(&self) -> &(Handle<O>, Handle<O>) {
         &self.handles
@@ -41,4 +93,9 @@ impl<O> Contact<O> where O: CollisionObject {
     pub fn penetration_depth(&self, index: usize) -> Scalar {
         self.set.penetration_depth(index)
     }
+
+    #[inline]
+    pub fn penetration_depths(&self) -> &Vec<Scalar> {
+        self.set.penetration_depths()
+    }
 }
@@ -4,20 +4,37 @@ use collisions::shapes::Plane;
 
 #[derive(Clone, Debug)]
 pub struct ContactSet {
-    plane: Plane,
+    normal: UnitVec3D,
     // at most, there will be 4 points (FACE-FACE), is there anything we can do
     // to optimise for this use case?
     points: Vec<Vec3D>,
+    penetration_depths: Vec<Scalar>,
 }
 
 impl ContactSet {
     pub fn new(plane: Plane, points: Vec<Vec3D>) -> ContactSet {
+        let penetration_depths = points.iter()
+            .map(|&point| plane.normal_projection_of(point))
+            .collect();
+
+        ContactSet::with_penetration_depths(plane.normal(), points, penetration_depths)
+    }
+
+    pub fn with_penetration_depths(normal: UnitVec3D, points: Vec<Vec3D>, penetration_depths: Vec<Scalar>) -> ContactSet {
+        debug_assert_eq!(points.len(), penetration_depths.len());
+
         ContactSet {
-            plane: plane,
+            normal: normal,
             points: points,
+            penetration_depths: penetration_depths,
         }
     }
 
+    #[inline(always)]
+    pub fn set_normal(&mut self, normal: UnitVec3D) {
+        self.normal = normal;
+    }
+
     #[inline(always)]
     pub fn point(&self, index: usize) -> Vec3D {
         self.points[index]
@@ -30,10 +47,16 @@ impl ContactSet {
 
     #[inline(always)]
     pub fn normal(&self) -> UnitVec3D {
-        self.plane.normal()
+        self.normal
     }
 
+    #[inline(always)]
     pub fn penetration_depth(&self, index: usize) -> Scalar {
-        self.plane.normal_projection_of(self.point(index))
+        self.penetration_depths[index]
+    }
+
+    #[inline(always)]
+    pub fn penetration_depths(&self) -> &Vec<Scalar> {
+        &self.penetration_depths
     }
 }
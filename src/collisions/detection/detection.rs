@@ -1,7 +1,11 @@
+use MachError;
 use utils::Handle;
 use collisions::{Contact, CollisionObject};
 
 pub trait Detection<O> where O: CollisionObject {
     fn update(&mut self);
-    fn compute_contacts(&mut self, handle_0: &Handle<O>, handle_1: &Handle<O>) -> Option<Contact<O>>;
+    /// Finds the contact between the bodies, if they intersect. Fails if the
+    /// contact could not be computed, in which case the pair should be
+    /// skipped.
+    fn compute_contacts(&mut self, handle_0: &Handle<O>, handle_1: &Handle<O>) -> Result<Option<Contact<O>>, MachError>;
 }
@@ -2,6 +2,7 @@
 #[path="../../../../tests/collisions/detection/gjkepa/contact_tracker_test.rs"]
 mod tests;
 
+use MachError;
 use maths::Vec3D;
 use utils::{UniqueVec3DGenerator, UnitVec3DGenerator};
 use collisions::CollisionData;
@@ -14,7 +15,9 @@ pub struct ContactTracker {
 }
 
 impl ContactTracker {
-    pub fn new(data_0: &CollisionData, data_1: &CollisionData) -> ContactTracker {
+    /// Creates a tracker with a simplex spanning the Minkowski difference of
+    /// the bodies, failing if their shapes are too degenerate to span one.
+    pub fn new(data_0: &CollisionData, data_1: &CollisionData) -> Result<ContactTracker, MachError> {
         let diff = MinkowskiDifference(data_0, data_1);
         let mut generator = UnitVec3DGenerator::new();
 
@@ -28,16 +31,21 @@ impl ContactTracker {
 
             let unique_vertices: Vec<Vec3D> = unique_vertices_iter.take(4).collect();
 
+            // the difference has fewer than four distinct points
+            if unique_vertices.len() < 4 {
+                return Err(MachError::DegenerateShapes);
+            }
+
             if let Ok(simplex) = GJKSimplex::from_vertices(unique_vertices[0], unique_vertices[1], unique_vertices[2], unique_vertices[3]) {
-                return ContactTracker {
+                return Ok(ContactTracker {
                     simplex: simplex,
-                };
+                });
             }
 
             counter += 1;
         }
 
-        panic!("took more than 1000 iterations to construct a GJKSimplex");
+        Err(MachError::DegenerateShapes)
     }
 
     #[inline(always)]
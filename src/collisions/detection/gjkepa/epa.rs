@@ -2,21 +2,27 @@
 #[path="../../../../tests/collisions/detection/gjkepa/epa_test.rs"]
 mod tests;
 
-use {NEG_INFINITY, Scalar};
-use maths::{ApproxEq, Approximations, CoordinateTransform, Vec3D};
-use maths::_2d::Vec2D;
-use utils::compute_surfaces_for_convex_hull;
+use std::cmp::Ordering;
+
+use {INFINITY, MachError, Scalar, TOLERANCE};
+use maths::{ApproxEq, Approximations, CrossProduct, DotProduct, UnitVec3D, Vec3D};
 use algorithms::IterativeAlgorithm;
 use collisions::{CollisionData, ContactSet, SupportMap};
-use collisions::shapes::{Direction, Intersection, Plane, Ray};
-use collisions::shapes::_2d::{Line2D, Polygon};
+use collisions::shapes::{Direction, Plane, Ray};
 use collisions::shapes::convex_shapes::ConvexPolyhedron;
+use collisions::detection::PolyhedronClipping;
 use collisions::detection::gjkepa::{GJKSimplex, MinkowskiDifference};
 
+/// The distance from the support plane within which vertices are considered
+/// part of the contact feature, as a fraction of the extent of the body along
+/// the contact normal.
+static FEATURE_TOLERANCE: Scalar = 0.02;
+
 pub struct EPA<'a> {
     diff: MinkowskiDifference<'a>,
     vertices: Vec<Vec3D>,
     has_converged: bool,
+    expansion: Scalar,
     triangulated_faces: Vec<[usize; 3]>,
 }
 
@@ -53,11 +59,111 @@ impl<'a> EPA<'a> {
             diff: diff,
             vertices: vertices,
             has_converged: false,
+            expansion: INFINITY,
             triangulated_faces: triangulated_faces,
         }
     }
 }
 
+impl<'a> EPA<'a> {
+    /// Adds the vertex to the polyhedron by replacing the faces it can see
+    /// with new faces joining it to the edges on their boundary. Updating the
+    /// polyhedron in place avoids recomputing the full convex hull, which is
+    /// unreliable for the many nearly coplanar points found when boxes rest
+    /// on one another.
+    fn expand_to(&mut self, face_index: usize, new_vertex: Vec3D) {
+        let new_index = self.vertices.len();
+        self.vertices.push(new_vertex);
+
+        // the visible faces are gathered by walking outwards from the face
+        // the vertex was found for, which keeps them connected even when
+        // rounding errors make the visibility tests inconsistent
+        let mut is_visible = vec!(false; self.triangulated_faces.len());
+        let mut faces_to_visit = vec!(face_index);
+        is_visible[face_index] = true;
+
+        while let Some(current_index) = faces_to_visit.pop() {
+            let current_face = self.triangulated_faces[current_index];
+
+            for (index, face) in self.triangulated_faces.iter().enumerate() {
+                let is_neighbour = has_directed_edge(face, current_face[1], current_face[0]) ||
+                    has_directed_edge(face, current_face[2], current_face[1]) ||
+                    has_directed_edge(face, current_face[0], current_face[2]);
+
+                if is_visible[index] || !is_neighbour {
+                    continue;
+                }
+
+                let plane = Plane::from_counter_clockwise_points(
+                    self.vertices[face[0]],
+                    self.vertices[face[1]],
+                    self.vertices[face[2]],
+                );
+
+                // faces which the vertex is coplanar with are also replaced,
+                // otherwise a vertex on their edge forms a degenerate face
+                if !plane.normal_projection_of(new_vertex).is_strictly_negative() {
+                    is_visible[index] = true;
+                    faces_to_visit.push(index);
+                }
+            }
+        }
+
+        let (visible_faces, mut faces) = self.triangulated_faces.iter()
+            .zip(is_visible.iter())
+            .fold((Vec::new(), Vec::new()), |(mut visible_faces, mut faces), (&face, &is_visible)| {
+                if is_visible {
+                    visible_faces.push(face);
+                } else {
+                    faces.push(face);
+                }
+
+                (visible_faces, faces)
+            });
+
+        // edges which are only shared with faces that remain form the horizon
+        let horizon_edges = visible_faces.iter()
+            .flat_map(|face| vec!([face[0], face[1]], [face[1], face[2]], [face[2], face[0]]))
+            .filter(|edge| {
+                !visible_faces.iter().any(|face| has_directed_edge(face, edge[1], edge[0]))
+            })
+            .collect::<Vec<[usize; 2]>>();
+
+        for edge in horizon_edges {
+            faces.push([edge[0], edge[1], new_index]);
+        }
+
+        // vertices enclosed by the new faces no longer lie on the hull
+        let mut new_indices: Vec<Option<usize>> = vec!(None; self.vertices.len());
+        let mut vertices = Vec::with_capacity(self.vertices.len());
+        for face in faces.iter_mut() {
+            for index in face.iter_mut() {
+                let new_index = match new_indices[*index] {
+                    Some(new_index) => new_index,
+
+                    None => {
+                        vertices.push(self.vertices[*index]);
+                        new_indices[*index] = Some(vertices.len() - 1);
+
+                        vertices.len() - 1
+                    },
+                };
+
+                *index = new_index;
+            }
+        }
+
+        self.vertices = vertices;
+        self.triangulated_faces = faces;
+    }
+}
+
+fn has_directed_edge(face: &[usize; 3], start: usize, end: usize) -> bool {
+    (face[0] == start && face[1] == end) ||
+        (face[1] == start && face[2] == end) ||
+        (face[2] == start && face[0] == end)
+}
+
 impl<'a> IterativeAlgorithm for EPA<'a> {
     type Result = EPAPolyhedron<'a>;
 
@@ -74,64 +180,69 @@ impl<'a> IterativeAlgorithm for EPA<'a> {
         self.has_converged
     }
 
+    /// The distance by which the polyhedron was expanded beyond its closest
+    /// face in the last iteration.
+    fn residual(&self) -> Scalar {
+        self.expansion
+    }
+
     fn next_iteration(&mut self) {
         if self.has_converged {
             return;
         }
 
-        // TODO the triangulated faces never change order, so we can skip work
-        // by filtered values in the next iteration
-        let candidate = self.triangulated_faces.iter()
-            .filter_map(|triangulation| {
+        // only the face closest to the origin is expanded, which keeps the
+        // polyhedron free of the long, thin faces found far from the origin
+        // that are prone to numerical errors
+        let closest_face = self.triangulated_faces.iter()
+            .enumerate()
+            .map(|(face_index, triangulation)| {
                 let plane = Plane::from_counter_clockwise_points(
                     self.vertices[triangulation[0]],
                     self.vertices[triangulation[1]],
                     self.vertices[triangulation[2]],
                 );
 
-                // TODO this should only return points on the boundary support
-                // points
-                self.diff.support_points_iter(Direction::from(plane.normal()))
-                    .filter(|&point| {
-                        // I don’t know why, but the uniqueness test is still
-                        // required
-                        !self.vertices.iter().any(|vertex| vertex.approx_eq(point)) &&
-                            plane.normal_projection_of(point).is_strictly_positive()
-                    })
-                    .next()
+                (face_index, plane)
+            })
+            .max_by(|&(_, ref plane_0), &(_, ref plane_1)| {
+                plane_0.normal_projection_of_origin()
+                    .partial_cmp(&plane_1.normal_projection_of_origin())
+                    .unwrap_or(Ordering::Equal)
+            });
+
+        // without any faces there is nothing left to expand, and the contact
+        // can not be computed from the result
+        let (face_index, plane) = match closest_face {
+            Some(closest_face) => closest_face,
+
+            None => {
+                self.expansion = 0.0;
+                self.has_converged = true;
+
+                return;
+            },
+        };
+
+        // TODO this should only return points on the boundary support points
+        let candidate = self.diff.support_points_iter(Direction::from(plane.normal()))
+            .filter(|&point| {
+                // I don’t know why, but the uniqueness test is still required
+                !self.vertices.iter().any(|vertex| vertex.approx_eq(point)) &&
+                    plane.normal_projection_of(point).is_strictly_positive()
             })
             .next();
 
         match candidate {
             Some(new_support_point) => {
-                self.vertices.push(new_support_point);
-
-                let mid_point = self.vertices.iter()
-                    .fold(Vec3D::zero(), |total, vertex| {
-                        total + vertex
-                    }) / self.vertices.len() as Scalar;
-
-                let surfaces = compute_surfaces_for_convex_hull(&self.vertices);
-                self.triangulated_faces = surfaces.into_iter()
-                    .map(|surface| {
-                        let mut node_guess = surface.nodes;
-
-                        let plane = Plane::from_counter_clockwise_points(
-                            self.vertices[node_guess[0]],
-                            self.vertices[node_guess[1]],
-                            self.vertices[node_guess[2]],
-                        );
-
-                        if plane.normal_projection_of(mid_point).is_strictly_positive() {
-                            node_guess = [node_guess[0], node_guess[2], node_guess[1]];
-                        }
-
-                        node_guess
-                    })
-                    .collect();
+                self.expansion = plane.normal_projection_of(new_support_point);
+                self.expand_to(face_index, new_support_point);
             },
 
-            None => self.has_converged = true,
+            None => {
+                self.expansion = 0.0;
+                self.has_converged = true;
+            },
         }
     }
 }
@@ -142,128 +253,77 @@ pub struct EPAPolyhedron<'a> {
 }
 
 impl<'a> EPAPolyhedron<'a> {
-    pub fn compute_contact_set(self) -> ContactSet {
-        let mut iterator = self.polyhedron.faces_iter();
-        let initial_face = iterator.next()
-            .expect("expected polyhedron to have at least one face, but it did not");
-
-        let (penetration_depth, closest_face) = iterator
-            .fold((NEG_INFINITY, initial_face), |(origin_to_closest_face_offset, closest_face), face| {
-                let offset = face.normal_projection_of_origin();
-                let diff = offset - origin_to_closest_face_offset;
-
-                if diff.is_strictly_positive() {
-                    (offset, face)
-                } else {
-                    (origin_to_closest_face_offset, closest_face)
-                }
+    /// Computes the contact between the bodies from the face of the polyhedron
+    /// closest to the origin. Fails if the polyhedron has no faces or the
+    /// bodies have no vertices to describe the contact with.
+    pub fn compute_contact_set(self) -> Result<ContactSet, MachError> {
+        // the closest face is the one the algorithm converged on, and faces
+        // which have not been expanded can lie within the tolerance of it
+        let closest_face = self.polyhedron.faces_iter()
+            .map(|face| (face.normal_projection_of_origin(), face))
+            .max_by(|&(offset_0, _), &(offset_1, _)| {
+                offset_0.partial_cmp(&offset_1).unwrap_or(Ordering::Equal)
             });
-
-        let contact_normal = -closest_face.normal();
-        let feature_0 = {
-            let vertices = self.diff.0.support_points_iter(-Direction::from(contact_normal))
-                .collect::<Vec<Vec3D>>();
-
-            Feature::from_vertices(vertices)
+        let (origin_offset, closest_face) = match closest_face {
+            Some(closest_face) => closest_face,
+            None => return Err(MachError::DegenerateShapes),
         };
 
-        let feature_1 = {
-            let vertices = self.diff.1.support_points_iter( Direction::from(contact_normal))
-                .collect::<Vec<Vec3D>>();
-
-            Feature::from_vertices(vertices)
+        // rounded shapes (e.g. spheres) extend beyond their vertices, so the
+        // origin may lie outside of the polyhedron by up to the sum of their
+        // rounding radii
+        let penetration_depth = rounding_radius_of(self.diff.0) +
+            rounding_radius_of(self.diff.1) - origin_offset;
+        let contact_normal = -closest_face.normal();
+        let features = (
+            Feature::from_vertices(feature_vertices_of(self.diff.0, -contact_normal)),
+            Feature::from_vertices(feature_vertices_of(self.diff.1,  contact_normal)),
+        );
+        let (feature_0, feature_1) = match features {
+            (Some(feature_0), Some(feature_1)) => (feature_0, feature_1),
+            _otherwise => return Err(MachError::DegenerateShapes),
         };
 
-        return match (feature_0, feature_1) {
+        let contact_set = match (feature_0, feature_1) {
             (Feature::Vertex(vertex), _other) => {
                 let correction = contact_normal * penetration_depth / 2.0;
-                let contact_point = vertex - correction;
+                let contact_point = vertex + correction;
 
-                ContactSet::new(
-                    Plane::new(contact_point - contact_normal * penetration_depth, contact_normal),
-                    vec!(contact_point),
-                )
+                ContactSet::with_penetration_depths(contact_normal, vec!(contact_point), vec!(penetration_depth))
             },
 
             (_other, Feature::Vertex(vertex)) => {
                 let correction = contact_normal * penetration_depth / 2.0;
-                let contact_point = vertex + correction;
+                let contact_point = vertex - correction;
 
-                ContactSet::new(
-                    Plane::new(contact_point - contact_normal * penetration_depth, contact_normal),
-                    vec!(contact_point),
-                )
+                ContactSet::with_penetration_depths(contact_normal, vec!(contact_point), vec!(penetration_depth))
             },
 
             (Feature::Edge(vertex_00, vertex_01), Feature::Edge(vertex_10, vertex_11)) => {
                 let ray_0 = Ray::from_points(vertex_00, vertex_01);
                 let ray_1 = Ray::from_points(vertex_10, vertex_11);
 
-                // TODO does this need a depth correction?
-                let contact_point = Ray::closest_point_to_rays(&ray_0, &ray_1);
+                // parallel edges touch along a segment rather than at a point
+                if !Vec3D::from(ray_0.direction()).cross(ray_1.direction()).length().is_strictly_positive() {
+                    let contact_points = overlap_of_parallel_edges((vertex_00, vertex_01), (vertex_10, vertex_11));
+                    let penetration_depths = vec!(penetration_depth; contact_points.len());
 
-                ContactSet::new(
-                    Plane::new(contact_point - contact_normal * penetration_depth, contact_normal),
-                    vec!(contact_point),
-                )
-            },
+                    return Ok(ContactSet::with_penetration_depths(contact_normal, contact_points, penetration_depths));
+                }
 
-            (Feature::Edge(edge_vertex_0, edge_vertex_1), Feature::Face(face_vertices)) => {
-                EPAPolyhedron::compute_contact_set_for_edge_face((edge_vertex_0, edge_vertex_1), face_vertices, Plane::from(closest_face))
-            },
+                // TODO does this need a depth correction?
+                let contact_point = Ray::closest_point_to_rays(&ray_0, &ray_1);
 
-            (Feature::Face(face_vertices), Feature::Edge(edge_vertex_0, edge_vertex_1)) => {
-                EPAPolyhedron::compute_contact_set_for_edge_face((edge_vertex_0, edge_vertex_1), face_vertices, Plane::from(closest_face).reversed())
+                ContactSet::with_penetration_depths(contact_normal, vec!(contact_point), vec!(penetration_depth))
             },
 
-            (Feature::Face(face_vertices_0), Feature::Face(face_vertices_1)) => {
-                EPAPolyhedron::compute_contact_set_for_face_face(face_vertices_0, face_vertices_1, Plane::from(closest_face))
+            (feature_0, feature_1) => {
+                PolyhedronClipping::new(feature_0.into_vertices(), feature_1.into_vertices(), contact_normal)
+                    .compute_contact_set()
             },
         };
-    }
-
-    fn compute_contact_set_for_edge_face(edge_vertices: (Vec3D, Vec3D), face_vertices: Vec<Vec3D>, contact_plane: Plane) -> ContactSet {
-        let coordinates = CoordinateTransform::from_plane(&contact_plane);
-
-        let start = coordinates.transform(edge_vertices.0);
-        let end = coordinates.transform(edge_vertices.1);
-        let average_z_0 = (start.z + end.z) / 2.0;
-        let edge_points = Line2D::new(
-            Vec2D::new(start.x, start.y),
-            Vec2D::new(end.x, end.y),
-        );
-
-        let (polygon, average_z_1) = project_to_polygon_2d(face_vertices, &coordinates);
-        let average_z = (average_z_0 + average_z_1) / 2.0;
-
-        let intersection = polygon.intersection(&edge_points).unwrap();
-        let contact_point_0 = coordinates.transform_with_inverse(Vec3D::new(intersection.start.x, intersection.start.y, average_z));
-        let contact_point_1 = coordinates.transform_with_inverse(Vec3D::new(intersection.end.x, intersection.end.y, average_z));
 
-        return ContactSet::new(
-            Plane::new(contact_point_0, contact_plane.normal()),
-            vec!(contact_point_0, contact_point_1),
-        );
-    }
-
-    fn compute_contact_set_for_face_face(face_vertices_0: Vec<Vec3D>, face_vertices_1: Vec<Vec3D>, contact_plane: Plane) -> ContactSet {
-        let coordinates = CoordinateTransform::from_plane(&contact_plane);
-
-        let (polygon_0, average_z_0) = project_to_polygon_2d(face_vertices_0, &coordinates);
-        let (polygon_1, average_z_1) = project_to_polygon_2d(face_vertices_1, &coordinates);
-        let average_z = (average_z_0 + average_z_1) / 2.0;
-
-        let intersection = polygon_0.intersection(&polygon_1)
-            .expect("expected an intersection for face-face features, but none was found");
-
-        let points: Vec<Vec3D> = intersection.points().iter()
-            .map(|point| coordinates.transform_with_inverse(Vec3D::new(point.x, point.y, average_z)))
-            .collect();
-
-        return ContactSet::new(
-            Plane::new(points[0], contact_plane.normal()),
-            points,
-        );
+        Ok(contact_set)
     }
 
     pub fn polyhedron(&self) -> &ConvexPolyhedron {
@@ -271,21 +331,72 @@ impl<'a> EPAPolyhedron<'a> {
     }
 }
 
-fn project_to_polygon_2d(vertices: Vec<Vec3D>, coordinates: &CoordinateTransform) -> (Polygon, Scalar) {
-    let points: Vec<Vec3D> = vertices.into_iter()
-        .map(|vertex| coordinates.transform(vertex))
+/// Collects the vertices of the body within `FEATURE_TOLERANCE` of its
+/// support points in the direction, moved out to the surface of the body.
+/// Including the nearly supporting vertices keeps the contact manifold of a
+/// resting body from collapsing to a single point whenever the body tilts
+/// slightly.
+fn feature_vertices_of(data: &CollisionData, direction: UnitVec3D) -> Vec<Vec3D> {
+    let support_points = data.support_points_iter(Direction::from(direction))
+        .collect::<Vec<Vec3D>>();
+    let support_offset = match support_points.first() {
+        Some(support_point) => support_point.dot(direction),
+        None => return Vec::new(),
+    };
+    let offsets: Vec<(Vec3D, Scalar)> = data.vertices_iter()
+        .map(|vertex| (vertex, support_offset - vertex.dot(direction)))
         .collect();
+    let extent = offsets.iter()
+        .fold(0.0, |extent: Scalar, &(_, offset)| extent.max(offset));
+    let tolerance = FEATURE_TOLERANCE * extent;
+    let mut vertices = support_points;
+
+    for (vertex, offset) in offsets {
+        if offset < tolerance && !vertices.iter().any(|other| other.approx_eq(vertex)) {
+            vertices.push(vertex);
+        }
+    }
 
-    let average_z = points.iter().fold(0.0, |total, point| total + point.z) / points.len() as Scalar;
-    let flat_projected_points: Vec<Vec2D> = points.into_iter()
-        .map(|point| Vec2D::new(point.x, point.y))
+    let surface_offset = direction * rounding_radius_of(data);
+
+    return vertices.into_iter()
+        .map(|vertex| vertex + surface_offset)
         .collect();
+}
+
+/// The distance the surface of the body extends beyond its vertices. The
+/// surface radius also includes the tolerance used to detect intersections,
+/// which is not part of the body.
+fn rounding_radius_of(data: &CollisionData) -> Scalar {
+    data.shape().surface_radius() - TOLERANCE
+}
+
+/// Finds the ends of the segment along which two parallel edges overlap,
+/// placed half way between the edges.
+fn overlap_of_parallel_edges(edge_0: (Vec3D, Vec3D), edge_1: (Vec3D, Vec3D)) -> Vec<Vec3D> {
+    let direction = (edge_0.1 - edge_0.0).normalize();
+    let offset_of = |point: Vec3D| (point - edge_0.0).dot(direction);
+    let to_edge_1 = edge_1.0 - edge_0.0;
+    let half_separation = (to_edge_1 - direction * to_edge_1.dot(direction)) / 2.0;
+
+    let offsets_1 = (offset_of(edge_1.0), offset_of(edge_1.1));
+    let mut start = offsets_1.0.min(offsets_1.1).max(0.0);
+    let mut end = offsets_1.0.max(offsets_1.1).min(offset_of(edge_0.1));
+
+    // the edges only just fail to overlap due to rounding errors
+    if start > end {
+        start = (start + end) / 2.0;
+        end = start;
+    }
 
-    let polygon = Polygon::convex_hull_from_points(&flat_projected_points)
-        // TODO can we avoid needing to validate this?
-        .expect("A valid face always has enough points");
+    let start_point = edge_0.0 + direction * start + half_separation;
+    let end_point = edge_0.0 + direction * end + half_separation;
 
-    return (polygon, average_z);
+    if start_point.approx_eq(end_point) {
+        vec!(start_point)
+    } else {
+        vec!(start_point, end_point)
+    }
 }
 
 enum Feature {
@@ -295,14 +406,22 @@ enum Feature {
 }
 
 impl Feature {
-    fn from_vertices(colliding_vertices: Vec<Vec3D>) -> Feature {
+    fn from_vertices(colliding_vertices: Vec<Vec3D>) -> Option<Feature> {
         let length = colliding_vertices.len();
 
         match length {
-            0 => panic!("no vertices found to describe the contact feature!"),
-            1 => Feature::Vertex(colliding_vertices[0]),
-            2 => Feature::Edge(colliding_vertices[0], colliding_vertices[1]),
-            _otherwise => Feature::Face(colliding_vertices),
+            0 => None,
+            1 => Some(Feature::Vertex(colliding_vertices[0])),
+            2 => Some(Feature::Edge(colliding_vertices[0], colliding_vertices[1])),
+            _otherwise => Some(Feature::Face(colliding_vertices)),
+        }
+    }
+
+    fn into_vertices(self) -> Vec<Vec3D> {
+        match self {
+            Feature::Vertex(vertex) => vec!(vertex),
+            Feature::Edge(vertex_0, vertex_1) => vec!(vertex_0, vertex_1),
+            Feature::Face(vertices) => vertices,
         }
     }
 }
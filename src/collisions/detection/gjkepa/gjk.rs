@@ -1,4 +1,4 @@
-use {Scalar, TOLERANCE};
+use {INFINITY, Scalar, TOLERANCE};
 use maths::{Approximations, Vec3D};
 use algorithms::IterativeAlgorithm;
 use collisions::CollisionData;
@@ -10,6 +10,7 @@ pub struct GJK<'a> {
     simplex: &'a mut GJKSimplex,
     intersection_tolerance: Scalar,
     converged_success_result: Option<bool>,
+    separation: Scalar,
 }
 
 impl<'a> GJK<'a> {
@@ -23,6 +24,7 @@ impl<'a> GJK<'a> {
             simplex: simplex,
             intersection_tolerance: intersection_tolerance,
             converged_success_result: None,
+            separation: INFINITY,
         }
     }
 }
@@ -44,6 +46,12 @@ impl<'a> IterativeAlgorithm for GJK<'a> {
         self.converged_success_result.is_some()
     }
 
+    /// The distance by which the origin lay outside of the simplex in the last
+    /// iteration.
+    fn residual(&self) -> Scalar {
+        self.separation
+    }
+
     fn next_iteration(&mut self) {
         if let Some(_result) = self.converged_success_result {
             return;
@@ -62,11 +70,14 @@ impl<'a> IterativeAlgorithm for GJK<'a> {
             .max_by_key(|&(_not_on_plane_index, ref _plane, projection)| (projection / TOLERANCE) as i32);
 
         let (not_on_plane_index, plane) = match next_guess {
-            Some((not_on_plane_index, plane, _projection)) => {
+            Some((not_on_plane_index, plane, projection)) => {
+                self.separation = projection - self.intersection_tolerance;
+
                 (not_on_plane_index, plane)
             },
 
             None => {
+                self.separation = 0.0;
                 self.converged_success_result = Some(true);
                 return;
             },
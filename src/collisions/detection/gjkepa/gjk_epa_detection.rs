@@ -2,17 +2,29 @@
 #[path="../../../../tests/collisions/detection/gjkepa/gjk_epa_detection_test.rs"]
 mod tests;
 
-use ID;
+use {ID, MachError};
 use utils::Handle;
-use algorithms::{Execute, PanicOnIteration};
+use algorithms::{ConvergenceReport, Execute, LimitIterationsTo};
 use collisions::{CollisionObject, Contact, Detection};
 use collisions::detection::gjkepa::{ContactTracker, GJK, EPA};
 
-pub struct GJKEPADetection { }
+pub struct GJKEPADetection {
+    max_iterations: usize,
+}
 
 impl GJKEPADetection {
     pub fn new() -> GJKEPADetection {
-        GJKEPADetection { }
+        GJKEPADetection {
+            max_iterations: 1000,
+        }
+    }
+
+    /// Sets the number of iterations after which the GJK and EPA algorithms
+    /// are considered to have failed.
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> GJKEPADetection {
+        self.max_iterations = max_iterations;
+
+        self
     }
 
     // TODO return Option<&mut ContactTracker> instead
@@ -20,35 +32,57 @@ impl GJKEPADetection {
         None
     }
 
-    fn create_tracker<O>(&mut self, body_0: &O, body_1: &O) -> ContactTracker where O: CollisionObject {
+    fn create_tracker<O>(&mut self, body_0: &O, body_1: &O) -> Result<ContactTracker, MachError> where O: CollisionObject {
         ContactTracker::new(body_0.collision_data(), body_1.collision_data())
     }
 }
 
+/// Fails if the algorithm was stopped at the iteration limit before it
+/// converged.
+fn expect_converged(process: &'static str, report: &ConvergenceReport) -> Result<(), MachError> {
+    if report.has_converged() {
+        Ok(())
+    } else {
+        Err(MachError::IterationLimitExceeded {
+            process: process,
+            iterations: report.iterations(),
+        })
+    }
+}
+
 impl<O> Detection<O> for GJKEPADetection where O: CollisionObject {
     fn update(&mut self) {
         // do nothing
     }
 
-    fn compute_contacts(&mut self, handle_0: &Handle<O>, handle_1: &Handle<O>) -> Option<Contact<O>> {
+    fn compute_contacts(&mut self, handle_0: &Handle<O>, handle_1: &Handle<O>) -> Result<Option<Contact<O>>, MachError> {
         let body_0 = handle_0.borrow();
         let body_1 = handle_1.borrow();
 
-        let mut tracker = self.find_tracker_mut(body_0.id(), body_1.id())
-            .unwrap_or_else(|| self.create_tracker(&*body_0, &*body_1));
-
-        GJK::using_simplex(tracker.simplex_mut(), body_0.collision_data(), body_1.collision_data())
-            .panic_on_iteration(1000, "GJK failed to complete")
-            .execute()
-            .map(|simplex| {
-                // TODO pass the MinkowskiDifference around
-                EPA::new(simplex, body_0.collision_data(), body_1.collision_data())
-                    .panic_on_iteration(1000, "EPA failed to complete")
-                    .execute()
-                    .compute_contact_set()
-            })
-            .map(|contact_set| {
-                Contact::new(contact_set, Handle::clone(handle_0), Handle::clone(handle_1))
-            })
+        let max_iterations = self.max_iterations;
+        let mut tracker = match self.find_tracker_mut(body_0.id(), body_1.id()) {
+            Some(tracker) => tracker,
+            None => try!(self.create_tracker(&*body_0, &*body_1)),
+        };
+
+        let (simplex, report) = GJK::using_simplex(tracker.simplex_mut(), body_0.collision_data(), body_1.collision_data())
+            .limit_iterations_to(max_iterations)
+            .execute_with_report();
+        try!(expect_converged("GJK", &report));
+
+        let simplex = match simplex {
+            Some(simplex) => simplex,
+            None => return Ok(None),
+        };
+
+        // TODO pass the MinkowskiDifference around
+        let (polyhedron, report) = EPA::new(simplex, body_0.collision_data(), body_1.collision_data())
+            .limit_iterations_to(max_iterations)
+            .execute_with_report();
+        try!(expect_converged("EPA", &report));
+
+        let contact_set = try!(polyhedron.compute_contact_set());
+
+        Ok(Some(Contact::new(contact_set, Handle::clone(handle_0), Handle::clone(handle_1))))
     }
 }
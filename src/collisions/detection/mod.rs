@@ -6,6 +6,7 @@ mod behaviours;
 mod contact;
 mod detection;
 mod contact_set;
+mod polyhedron_clipping;
 
 pub mod gjkepa;
 
@@ -13,3 +14,4 @@ pub use self::gjkepa::GJKEPADetection;
 pub use self::contact::Contact;
 pub use self::contact_set::ContactSet;
 pub use self::detection::Detection;
+pub use self::polyhedron_clipping::PolyhedronClipping;
@@ -0,0 +1,271 @@
+#[cfg(test)]
+#[path="../../../tests/collisions/detection/polyhedron_clipping_test.rs"]
+mod tests;
+
+use std::cmp::Ordering;
+
+use {NEG_INFINITY, Scalar};
+use maths::{ApproxEq, Approximations, CoordinateTransform, CrossProduct, DotProduct, UnitVec3D, Vec3D};
+use collisions::ContactSet;
+use collisions::shapes::Plane;
+
+static MAX_CONTACT_POINTS: usize = 4;
+
+/// Computes the contact manifold between two polyhedral features using
+/// reference face clipping. The incident feature is clipped against the side
+/// planes of the reference face (Sutherland–Hodgman), and each point left
+/// beneath the reference face becomes a contact point with its own
+/// penetration depth.
+pub struct PolyhedronClipping {
+    reference_face: Vec<Vec3D>,
+    reference_normal: UnitVec3D,
+    incident_feature: Vec<Vec3D>,
+    contact_normal: UnitVec3D,
+}
+
+impl PolyhedronClipping {
+    /// Creates a new `PolyhedronClipping` from the support points of both
+    /// bodies. The first set of vertices is expected to be the support points
+    /// of the first body in the direction opposite to the contact normal, and
+    /// the second set the support points of the second body in the direction
+    /// of the contact normal. At least one of the features must be a face.
+    pub fn new(feature_0: Vec<Vec3D>, feature_1: Vec<Vec3D>, contact_normal: UnitVec3D) -> PolyhedronClipping {
+        debug_assert!(feature_0.len() >= 3 || feature_1.len() >= 3, "expected at least one of the features to be a face");
+
+        let face_0 = if feature_0.len() >= 3 {
+            Some(ordered_face(feature_0.clone(), -contact_normal))
+        } else {
+            None
+        };
+
+        let face_1 = if feature_1.len() >= 3 {
+            Some(ordered_face(feature_1.clone(), contact_normal))
+        } else {
+            None
+        };
+
+        let use_face_0_as_reference = match (&face_0, &face_1) {
+            (&Some((_, normal_0)), &Some((_, normal_1))) =>
+                normal_0.dot(-contact_normal) >= normal_1.dot(contact_normal),
+
+            (&Some(_), &None) => true,
+
+            _otherwise => false,
+        };
+
+        if use_face_0_as_reference {
+            let (reference_face, reference_normal) = face_0.unwrap();
+            let incident_feature = face_1.map(|(vertices, _normal)| vertices)
+                .unwrap_or(feature_1);
+
+            PolyhedronClipping {
+                reference_face: reference_face,
+                reference_normal: reference_normal,
+                incident_feature: incident_feature,
+                contact_normal: contact_normal,
+            }
+        } else {
+            let (reference_face, reference_normal) = face_1.unwrap();
+            let incident_feature = face_0.map(|(vertices, _normal)| vertices)
+                .unwrap_or(feature_0);
+
+            PolyhedronClipping {
+                reference_face: reference_face,
+                reference_normal: reference_normal,
+                incident_feature: incident_feature,
+                contact_normal: contact_normal,
+            }
+        }
+    }
+
+    /// Returns the vertices of the reference face, ordered around the face.
+    #[inline]
+    pub fn reference_face(&self) -> &Vec<Vec3D> {
+        &self.reference_face
+    }
+
+    /// Returns the outward facing normal of the reference face.
+    #[inline]
+    pub fn reference_normal(&self) -> UnitVec3D {
+        self.reference_normal
+    }
+
+    /// Clips the incident feature against the reference face and returns at
+    /// most four contact points, each located half way between the two
+    /// surfaces.
+    pub fn compute_contact_set(self) -> ContactSet {
+        let reference_plane = Plane::new(self.reference_face[0], self.reference_normal);
+        let centroid = average_of(&self.reference_face);
+
+        let clipped_points = self.reference_face.iter()
+            .zip(self.reference_face.iter().cycle().skip(1))
+            .fold(self.incident_feature.clone(), |points, (&start, &end)| {
+                let mut side_normal = (end - start).cross(self.reference_normal).normalize();
+
+                if side_normal.dot(centroid - start) > 0.0 {
+                    side_normal = -side_normal;
+                }
+
+                clip_against_plane(&points, &Plane::new(start, side_normal))
+            });
+
+        let mut candidates: Vec<(Vec3D, Scalar)> = Vec::with_capacity(clipped_points.len());
+        for point in clipped_points.into_iter() {
+            if !candidates.iter().any(|&(other, _depth)| other.approx_eq(point)) {
+                candidates.push((point, -reference_plane.normal_projection_of(point)));
+            }
+        }
+
+        let mut penetrating: Vec<(Vec3D, Scalar)> = candidates.iter()
+            .cloned()
+            .filter(|&(_point, depth)| !depth.is_strictly_negative())
+            .collect();
+
+        if penetrating.len() == 0 {
+            // ASSUMPTION: the features were reported to be in contact, so at
+            // least the deepest point must be kept
+            penetrating = candidates.into_iter()
+                .fold(None, |deepest: Option<(Vec3D, Scalar)>, candidate| {
+                    match deepest {
+                        Some(current) if current.1 >= candidate.1 => Some(current),
+                        _otherwise => Some(candidate),
+                    }
+                })
+                .into_iter()
+                .collect();
+        }
+
+        let selected_indices = select_contact_point_indices(&penetrating, self.reference_normal);
+        let points = selected_indices.iter()
+            .map(|&index| {
+                let (point, depth) = penetrating[index];
+
+                point + self.reference_normal * (depth / 2.0)
+            })
+            .collect();
+
+        let penetration_depths = selected_indices.iter()
+            .map(|&index| penetrating[index].1)
+            .collect();
+
+        return ContactSet::with_penetration_depths(self.contact_normal, points, penetration_depths);
+    }
+}
+
+fn average_of(points: &Vec<Vec3D>) -> Vec3D {
+    points.iter().fold(Vec3D::zero(), |total, point| total + point) / points.len() as Scalar
+}
+
+/// Sorts the vertices of a face so that they wind around the face, and
+/// computes the normal of the face oriented along the expected direction.
+fn ordered_face(mut vertices: Vec<Vec3D>, expected_normal: UnitVec3D) -> (Vec<Vec3D>, UnitVec3D) {
+    let centroid = average_of(&vertices);
+    let plane = Plane::new(centroid, expected_normal);
+    let coordinates = CoordinateTransform::from_plane(&plane);
+
+    vertices.sort_by(|a, b| {
+        let a = coordinates.transform(a - centroid);
+        let b = coordinates.transform(b - centroid);
+
+        a.y.atan2(a.x).partial_cmp(&b.y.atan2(b.x)).unwrap_or(Ordering::Equal)
+    });
+
+    // Newell’s method is robust to (nearly) collinear consecutive vertices
+    let normal_guess = vertices.iter()
+        .zip(vertices.iter().cycle().skip(1))
+        .fold(Vec3D::zero(), |total, (&start, &end)| {
+            total + (start - centroid).cross(end - centroid)
+        });
+
+    let normal = if normal_guess.squared_length().is_approximately_zero() {
+        expected_normal
+    } else if normal_guess.dot(expected_normal) < 0.0 {
+        -normal_guess.normalize()
+    } else {
+        normal_guess.normalize()
+    };
+
+    return (vertices, normal);
+}
+
+/// Clips the closed polygon against the plane, keeping the parts of the
+/// polygon behind the plane.
+fn clip_against_plane(points: &Vec<Vec3D>, plane: &Plane) -> Vec<Vec3D> {
+    let mut output = Vec::with_capacity(points.len() + 1);
+
+    let mut previous = match points.last() {
+        Some(&point) => point,
+        None => return output,
+    };
+    let mut previous_projection = plane.normal_projection_of(previous);
+
+    for &current in points.iter() {
+        let current_projection = plane.normal_projection_of(current);
+
+        match (previous_projection.is_strictly_positive(), current_projection.is_strictly_positive()) {
+            (false, false) => output.push(current),
+
+            (true, false) => {
+                output.push(edge_plane_intersection(previous, current, previous_projection, current_projection));
+                output.push(current);
+            },
+
+            (false, true) => {
+                output.push(edge_plane_intersection(previous, current, previous_projection, current_projection));
+            },
+
+            (true, true) => (),
+        }
+
+        previous = current;
+        previous_projection = current_projection;
+    }
+
+    return output;
+}
+
+fn edge_plane_intersection(start: Vec3D, end: Vec3D, start_projection: Scalar, end_projection: Scalar) -> Vec3D {
+    let ratio = start_projection / (start_projection - end_projection);
+
+    start + (end - start) * ratio
+}
+
+/// Reduces the contact points to a well distributed subset: the deepest point,
+/// the point furthest from it, and the two points which maximize the contact
+/// area on either side of the line between the first two.
+fn select_contact_point_indices(points: &Vec<(Vec3D, Scalar)>, normal: UnitVec3D) -> Vec<usize> {
+    if points.len() <= MAX_CONTACT_POINTS {
+        return (0..points.len()).collect();
+    }
+
+    let index_of_max = |score: &Fn(Vec3D, Scalar) -> Scalar| -> usize {
+        points.iter()
+            .enumerate()
+            .fold((0, NEG_INFINITY), |(best_index, best_score), (index, &(point, depth))| {
+                let value = score(point, depth);
+
+                if value > best_score {
+                    (index, value)
+                } else {
+                    (best_index, best_score)
+                }
+            }).0
+    };
+
+    let first = index_of_max(&|_point, depth| depth);
+    let first_point = points[first].0;
+    let second = index_of_max(&|point, _depth| (point - first_point).squared_length());
+    let second_point = points[second].0;
+    let edge = second_point - first_point;
+    let third = index_of_max(&|point, _depth| edge.cross(point - first_point).dot(normal));
+    let fourth = index_of_max(&|point, _depth| -edge.cross(point - first_point).dot(normal));
+
+    let mut indices = vec!(first);
+    for &index in [second, third, fourth].iter() {
+        if !indices.contains(&index) {
+            indices.push(index);
+        }
+    }
+
+    return indices;
+}
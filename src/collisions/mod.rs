@@ -2,8 +2,11 @@ mod body;
 mod body_def;
 mod body_data;
 mod collision_data;
+mod collision_filter;
 mod collision_group;
 mod collision_object;
+mod overlap_event;
+mod overlap_tracker;
 
 pub mod shapes;
 pub mod detection;
@@ -19,6 +22,9 @@ pub use self::detection::{Contact, ContactSet, Detection};
 pub use self::broadphase::{Broadphase, CloseProximityPair};
 pub use self::narrowphase::Narrowphase;
 pub use self::collision_data::CollisionData;
+pub use self::collision_filter::CollisionFilter;
 pub use self::collision_group::CollisionGroup;
 pub use self::collision_object::CollisionObject;
+pub use self::overlap_event::OverlapEvent;
+pub use self::overlap_tracker::OverlapTracker;
 pub use self::collisionobjectspace::{CollisionObjectSpace, MachCollisionObjectSpace};
@@ -0,0 +1,36 @@
+use utils::Handle;
+use collisions::CollisionObject;
+
+/// Describes a change in the overlap state between a sensor body and another
+/// body during a single update.
+#[derive(Debug)]
+pub enum OverlapEvent<O> where O: CollisionObject {
+    /// The bodies started overlapping during the update.
+    Enter(Handle<O>, Handle<O>),
+    /// The bodies were overlapping before the update and still are.
+    Stay(Handle<O>, Handle<O>),
+    /// The bodies stopped overlapping during the update.
+    Exit(Handle<O>, Handle<O>),
+}
+
+// the handles are shared, so cloning does not require the bodies to be cloned
+impl<O> Clone for OverlapEvent<O> where O: CollisionObject {
+    fn clone(&self) -> OverlapEvent<O> {
+        match self {
+            &OverlapEvent::Enter(ref handle_0, ref handle_1) => OverlapEvent::Enter(handle_0.clone(), handle_1.clone()),
+            &OverlapEvent::Stay(ref handle_0, ref handle_1) => OverlapEvent::Stay(handle_0.clone(), handle_1.clone()),
+            &OverlapEvent::Exit(ref handle_0, ref handle_1) => OverlapEvent::Exit(handle_0.clone(), handle_1.clone()),
+        }
+    }
+}
+
+impl<O> OverlapEvent<O> where O: CollisionObject {
+    #[inline]
+    pub fn handles(&self) -> (&Handle<O>, &Handle<O>) {
+        match self {
+            &OverlapEvent::Enter(ref handle_0, ref handle_1) |
+            &OverlapEvent::Stay(ref handle_0, ref handle_1) |
+            &OverlapEvent::Exit(ref handle_0, ref handle_1) => (handle_0, handle_1),
+        }
+    }
+}
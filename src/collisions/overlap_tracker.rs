@@ -0,0 +1,51 @@
+#[cfg(test)]
+#[path="../../tests/collisions/overlap_tracker_test.rs"]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use ID;
+use utils::{Handle, ordered_pair};
+use collisions::{CollisionObject, OverlapEvent};
+
+/// Keeps track of the pairs of bodies overlapping across updates, and
+/// generates the corresponding `OverlapEvent`s.
+pub struct OverlapTracker<O> where O: CollisionObject {
+    overlapping: BTreeMap<(ID, ID), (Handle<O>, Handle<O>)>,
+}
+
+impl<O> OverlapTracker<O> where O: CollisionObject {
+    pub fn new() -> OverlapTracker<O> {
+        OverlapTracker {
+            overlapping: BTreeMap::new(),
+        }
+    }
+
+    /// Replaces the currently overlapping pairs with the pairs provided, and
+    /// returns the events describing the changes. Pairs which are no longer
+    /// present generate `Exit` events.
+    pub fn update<I>(&mut self, pairs: I) -> Vec<OverlapEvent<O>> where I: IntoIterator<Item=(Handle<O>, Handle<O>)> {
+        let mut events = Vec::new();
+        let mut overlapping = BTreeMap::new();
+
+        for (handle_0, handle_1) in pairs.into_iter() {
+            let key = ordered_pair(handle_0.borrow().id(), handle_1.borrow().id());
+
+            if self.overlapping.remove(&key).is_some() {
+                events.push(OverlapEvent::Stay(handle_0.clone(), handle_1.clone()));
+            } else {
+                events.push(OverlapEvent::Enter(handle_0.clone(), handle_1.clone()));
+            }
+
+            overlapping.insert(key, (handle_0, handle_1));
+        }
+
+        for (_key, (handle_0, handle_1)) in self.overlapping.iter() {
+            events.push(OverlapEvent::Exit(handle_0.clone(), handle_1.clone()));
+        }
+
+        self.overlapping = overlapping;
+
+        return events;
+    }
+}
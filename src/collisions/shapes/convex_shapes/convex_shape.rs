@@ -13,9 +13,17 @@ pub trait ConvexShape: Shape + fmt::Debug {
     /// Computes the volume for the shape.
     fn volume(&self) -> Scalar;
 
-    /// Returns the normalized inertia tensor for the shape.
+    /// Returns the normalized inertia tensor for the shape, i.e. the inertia
+    /// tensor of the shape with unit mass, about its center of mass.
     fn inertia(&self) -> Matrix;
 
+    /// Returns the center of mass of the shape in local coordinates, assuming
+    /// a uniform density. By default this is the local origin.
+    #[inline]
+    fn center_of_mass(&self) -> Vec3D {
+        Vec3D::zero()
+    }
+
     /// Obtains the vertex with the index specified.
     fn vertex(&self, usize) -> Vec3D;
 
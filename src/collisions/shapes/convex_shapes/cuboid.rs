@@ -13,6 +13,16 @@ use maths::{ApproxEq, DotProduct, Matrix, Vec3D};
 use collisions::shapes::{Direction, Shape};
 use collisions::shapes::convex_shapes::{ConvexShape, ShapeRef};
 
+/// The corners of each face of a cuboid, as indices into its vertices.
+const FACES: [[usize; 4]; 6] = [
+    [0, 1, 2, 3],
+    [4, 5, 6, 7],
+    [0, 3, 7, 4],
+    [1, 2, 6, 5],
+    [0, 1, 5, 4],
+    [3, 2, 6, 7],
+];
+
 /// A representation of a cuboid in 3 dimensions.
 #[derive(Clone, Debug)]
 pub struct Cuboid {
@@ -53,6 +63,16 @@ impl Cuboid {
     pub fn dimensions(&self) -> &Vec3D {
         &self.dimensions
     }
+
+    /// Iterates over the triangles covering the surface of the cuboid, in
+    /// local coordinates.
+    pub fn triangles_iter<'a>(&'a self) -> Box<Iterator<Item=[Vec3D; 3]> + 'a> {
+        Box::new(FACES.iter().flat_map(move |face| {
+            let corners = [self.vertices[face[0]], self.vertices[face[1]], self.vertices[face[2]], self.vertices[face[3]]];
+
+            vec!([corners[0], corners[1], corners[2]], [corners[0], corners[2], corners[3]])
+        }))
+    }
 }
 
 impl fmt::Display for Cuboid {
@@ -109,11 +129,16 @@ impl ConvexShape for Cuboid {
 
     fn support_indices_for(&self, input_direction: Direction) -> Vec<usize> {
         let direction = Vec3D::from(input_direction);
+        // the vertices of a flat cuboid coincide along its zero dimensions,
+        // which therefore do not distinguish between them
+        let scale = |component: Scalar, size: Scalar| {
+            if size > 0.0 { component / size } else { 0.0 }
+        };
 
         let new_direction = Vec3D::new(
-            direction.x/self.dimensions.x,
-            direction.y/self.dimensions.y,
-            direction.z/self.dimensions.z,
+            scale(direction.x, self.dimensions.x),
+            scale(direction.y, self.dimensions.y),
+            scale(direction.z, self.dimensions.z),
         );
         let mut max_value = 0.0;
         let mut max_indices = Vec::new();
@@ -1,9 +1,13 @@
+#[cfg(test)]
+#[path="../../../../tests/collisions/shapes/convex_shapes/triangle_mesh_test.rs"]
+mod tests;
+
 use std::rc::Rc;
 use std::fmt;
 use std::collections::HashMap;
 
 use {Scalar, NEG_INFINITY, TOLERANCE};
-use maths::{DotProduct, Matrix, Vec3D};
+use maths::{CrossProduct, DotProduct, Matrix, Vec3D};
 use collisions::shapes::{Direction, Shape};
 use collisions::shapes::convex_shapes::{ConvexShape, ShapeRef};
 
@@ -43,6 +47,36 @@ impl TriangleMesh {
             unique_nodes: unique_nodes,
         }
     }
+
+    /// Iterates over the triangles of the mesh, in local coordinates.
+    pub fn triangles_iter<'a>(&'a self) -> Box<Iterator<Item=[Vec3D; 3]> + 'a> {
+        Box::new(self.elements.iter().map(move |&(i, j, k)| [self.vertices[i], self.vertices[j], self.vertices[k]]))
+    }
+
+    /// Computes the volume, centroid and covariance (relative to the local
+    /// origin) of the solid enclosed by the mesh, by decomposing it into
+    /// tetrahedra sharing a common interior point.
+    fn integrate_solid(&self) -> (Scalar, Vec3D, Matrix) {
+        let reference = self.vertices_iter()
+            .fold(Vec3D::zero(), |total, vertex| total + vertex) / self.unique_nodes.len() as Scalar;
+
+        self.elements.iter()
+            .fold((0.0, Vec3D::zero(), Matrix::diag(0.0, 0.0, 0.0)), |(volume, moment, covariance), &(i, j, k)| {
+                let points = [reference, self.vertices[i], self.vertices[j], self.vertices[k]];
+                let tetrahedron_volume = (points[1] - reference)
+                    .dot((points[2] - reference).cross(points[3] - reference))
+                    .abs() / 6.0;
+                let sum = points.iter().fold(Vec3D::zero(), |total, point| total + point);
+                let tetrahedron_covariance = points.iter()
+                    .fold(sum.outer(sum), |total, point| total + point.outer(point.clone())) * (tetrahedron_volume / 20.0);
+
+                (
+                    volume + tetrahedron_volume,
+                    moment + sum * (tetrahedron_volume / 4.0),
+                    covariance + tetrahedron_covariance,
+                )
+            })
+    }
 }
 
 impl fmt::Display for TriangleMesh {
@@ -75,12 +109,22 @@ impl ConvexShape for TriangleMesh {
     }
 
     fn volume(&self) -> Scalar {
-        0.0
+        self.integrate_solid().0
+    }
+
+    fn center_of_mass(&self) -> Vec3D {
+        let (volume, moment, _covariance) = self.integrate_solid();
+
+        moment / volume
     }
 
     fn inertia(&self) -> Matrix {
-        // TODO implement this properly (somehow?)
-        Matrix::identity()
+        let (volume, moment, covariance) = self.integrate_solid();
+        let center_of_mass = moment / volume;
+        let central_covariance = covariance - center_of_mass.outer(center_of_mass) * volume;
+        let trace = central_covariance[0] + central_covariance[4] + central_covariance[8];
+
+        (Matrix::diag(trace, trace, trace) - central_covariance) / volume
     }
 
     fn vertex(&self, index: usize) -> Vec3D {
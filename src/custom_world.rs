@@ -1,10 +1,11 @@
+use std::cmp;
 use std::marker::PhantomData;
 
-use {Scalar, World};
+use {MachError, Scalar, World, WorldConfig};
 use maths::Vec3D;
 use utils::{Ref, RefMut, Handle};
-use dynamics::{ConstraintSolver, DynamicBody, DynamicBodyExtension, FixedBodyData, FixedBodyDef, Integrator, RigidBodyData, RigidBodyDef, RigidBodyRefMut};
-use collisions::{BodyDef, Broadphase, CollisionObject, CollisionGroup, Contact, Detection};
+use dynamics::{ConstraintSolver, ContactEvent, ContactEventTracker, DynamicBody, DynamicBodyExtension, DynamicBodyRefMut, FixedBodyData, FixedBodyDef, ForceField, ForceFieldSample, Integrator, KinematicBodyData, KinematicBodyDef, KinematicBodyRefMut, MaterialRegistry, RigidBodyData, RigidBodyDef, RigidBodyRef, RigidBodyRefMut, SolverStats};
+use collisions::{BodyDef, Broadphase, CollisionFilter, CollisionObject, Contact, Detection, OverlapEvent, OverlapTracker};
 use collisions::shapes::{Intersection, Ray};
 use collisions::shapes::convex_shapes::ShapeRef;
 
@@ -16,11 +17,23 @@ pub struct CustomWorld<B, C, D, E, I, O> where
         I: Integrator,
         O: CollisionObject<Extension=DynamicBodyExtension<E>> {
 
+    config: WorldConfig,
     gravity: Vec3D,
+    force_fields: Vec<Box<ForceField>>,
+    linear_damping: Scalar,
+    angular_damping: Scalar,
     detection: D,
     integrator: I,
     broadphase: B,
     constraint_solver: C,
+    collision_filter: CollisionFilter<E>,
+    material_registry: MaterialRegistry,
+    solver_stats: SolverStats,
+    contact_modifier: Option<Box<Fn(&mut Contact<O>)>>,
+    overlap_tracker: OverlapTracker<O>,
+    contact_events: Vec<ContactEvent>,
+    contact_event_tracker: ContactEventTracker<O>,
+    overlap_events: Vec<OverlapEvent<O>>,
     _extra: PhantomData<O>,
 }
 
@@ -32,40 +45,180 @@ impl<B, C, D, E, I, O> CustomWorld<B, C, D, E, I, O> where
         I: Integrator,
         O: CollisionObject<Extension=DynamicBodyExtension<E>> {
 
-    pub fn new(detection: D, integrator: I, broadphase: B, constraint_solver: C, gravity: Vec3D) -> CustomWorld<B, C, D, E, I, O> {
+    pub fn new(detection: D, integrator: I, broadphase: B, constraint_solver: C, gravity: Vec3D, config: WorldConfig) -> CustomWorld<B, C, D, E, I, O> {
         CustomWorld {
+            config: config,
             gravity: gravity,
+            force_fields: Vec::new(),
+            linear_damping: 0.0,
+            angular_damping: 0.0,
             detection: detection,
             integrator: integrator,
             broadphase: broadphase,
             constraint_solver: constraint_solver,
+            collision_filter: CollisionFilter::new(),
+            material_registry: MaterialRegistry::new(),
+            solver_stats: SolverStats::new(),
+            contact_modifier: None,
+            overlap_tracker: OverlapTracker::new(),
+            contact_events: Vec::new(),
+            contact_event_tracker: ContactEventTracker::new(),
+            overlap_events: Vec::new(),
             _extra: PhantomData,
         }
     }
 
+    /// Advances the simulation by the time step, divided into the number of
+    /// sub-steps in the configuration. The events reported cover every
+    /// sub-step, with the contact events merged into one per pair of bodies,
+    /// as do the solver statistics, while the contacts returned are those of
+    /// the last sub-step.
     pub fn update(&mut self, time_step: Scalar) -> Vec<Contact<O>> {
+        let sub_steps = cmp::max(self.config.sub_steps, 1);
+        let sub_step = time_step / sub_steps as Scalar;
+        let mut contacts = Vec::new();
+        let mut contact_events = Vec::new();
+        let mut overlap_events = Vec::new();
+        self.solver_stats = SolverStats::new();
+
+        // targets are reached at the end of the update rather than the first
+        // sub-step
+        for mut body in self.broadphase.bodies_iter_mut() {
+            if let Some(mut kinematic_body) = KinematicBodyRefMut::try_from(&mut *body) {
+                kinematic_body.steer_towards_next_transform(time_step);
+            }
+        }
+
+        for _ in 0..sub_steps {
+            contacts = self.step(sub_step);
+            contact_events.extend(self.contact_events.drain(..));
+            overlap_events.extend(self.overlap_events.drain(..));
+        }
+
+        self.contact_events = ContactEvent::merge(&contact_events);
+        self.overlap_events = overlap_events;
+
         for mut body in self.broadphase.bodies_iter_mut() {
             if let Some(mut rigid_body) = RigidBodyRefMut::try_from(&mut *body) {
-                self.integrator.integrate_in_place(&mut rigid_body.integratable(), time_step, self.gravity);
+                rigid_body.clear_accumulators();
+            }
+        }
+
+        return contacts;
+    }
+
+    fn step(&mut self, time_step: Scalar) -> Vec<Contact<O>> {
+        for mut body in self.broadphase.bodies_iter_mut() {
+            let (field_force, field_torque) = self.force_fields_on(&*body);
+
+            match DynamicBodyRefMut::from(&mut *body) {
+                DynamicBodyRefMut::Rigid(mut rigid_body) => {
+                    let linear_acceleration = self.gravity * rigid_body.gravity_scale() +
+                        (rigid_body.force() + field_force) * rigid_body.mass_inverse();
+                    let angular_acceleration = rigid_body.inertia_inverse() * (rigid_body.torque() + field_torque);
+
+                    rigid_body.integrate(&self.integrator, time_step, linear_acceleration, angular_acceleration);
+                },
+
+                DynamicBodyRefMut::Kinematic(mut kinematic_body) => {
+                    kinematic_body.integrate(&self.integrator, time_step);
+                },
+
+                DynamicBodyRefMut::Fixed(_) => (),
             }
         }
 
         self.broadphase.update();
         self.detection.update();
 
-        let contacts: Vec<Contact<O>> = self.broadphase.close_proximity_pairs_iter()
-            .filter_map(|pair| self.detection.compute_contacts(&pair.0, &pair.1))
+        let detection = &mut self.detection;
+        let collision_filter = &self.collision_filter;
+        let solver_stats = &mut self.solver_stats;
+        let (sensor_contacts, mut contacts): (Vec<Contact<O>>, Vec<Contact<O>>) = self.broadphase.close_proximity_pairs_iter()
+            .filter(|pair| {
+                let body_0 = pair.0.borrow();
+                let body_1 = pair.1.borrow();
+
+                let is_sensor_pair = body_0.dynamic_extension_data().is_sensor() ||
+                    body_1.dynamic_extension_data().is_sensor();
+
+                // fixed and kinematic bodies placed in custom groups can be
+                // paired with each other, but never respond to the contact,
+                // although sensors among them still report the overlap
+                if !is_sensor_pair && RigidBodyRef::try_from(&*body_0).is_none() && RigidBodyRef::try_from(&*body_1).is_none() {
+                    return false;
+                }
+
+                collision_filter.test(
+                    body_0.id(), DynamicBody::extension_data(&*body_0),
+                    body_1.id(), DynamicBody::extension_data(&*body_1),
+                )
+            })
+            .filter_map(|pair| {
+                // the pair is skipped rather than failing the whole step
+                detection.compute_contacts(&pair.0, &pair.1).unwrap_or_else(|error| {
+                    solver_stats.record_failure(pair.0.borrow().id(), pair.1.borrow().id(), error);
+
+                    None
+                })
+            })
+            .partition(|contact| {
+                let &(ref handle_0, ref handle_1) = contact.handles();
+
+                handle_0.borrow().dynamic_extension_data().is_sensor() ||
+                    handle_1.borrow().dynamic_extension_data().is_sensor()
+            });
+
+        self.overlap_events = self.overlap_tracker.update(sensor_contacts.into_iter()
+            .map(|contact| contact.handles().clone()));
+
+        if let Some(ref contact_modifier) = self.contact_modifier {
+            for contact in contacts.iter_mut() {
+                contact_modifier(contact);
+            }
+
+            contacts.retain(|contact| contact.is_enabled());
+        }
+
+        let relative_speeds: Vec<Scalar> = contacts.iter()
+            .map(|contact| ContactEventTracker::relative_speed_of(contact))
             .collect();
 
-        if contacts.len() > 0 {
-            self.constraint_solver.solve_with_contacts(&contacts, &self.integrator, time_step);
+        let normal_impulses = if contacts.len() > 0 {
+            let normal_impulses = self.constraint_solver.solve_with_contacts(&contacts, &self.integrator, time_step, &self.config.solver, &self.material_registry, &mut self.solver_stats);
 
             self.broadphase.update();
-        }
+
+            normal_impulses
+        } else {
+            Vec::new()
+        };
+
+        self.contact_events = self.contact_event_tracker.update(&contacts, &relative_speeds, &normal_impulses);
 
         return contacts;
     }
 
+    /// Sums the forces and torques of the force fields acting on the body,
+    /// which are zero unless the body is rigid.
+    fn force_fields_on(&self, body: &O) -> (Vec3D, Vec3D) {
+        let rigid_body = match RigidBodyRef::try_from(body) {
+            Some(rigid_body) if self.force_fields.len() > 0 => rigid_body,
+
+            _otherwise => return (Vec3D::zero(), Vec3D::zero()),
+        };
+        let sample = ForceFieldSample::new(body.collision_data(), rigid_body.center_of_mass(), rigid_body.mass())
+            .with_velocity(*rigid_body.velocity())
+            .with_angular_velocity(*rigid_body.angular_velocity())
+            .with_gravity(self.gravity);
+
+        self.force_fields.iter()
+            .map(|field| field.force_and_torque_on(&sample))
+            .fold((Vec3D::zero(), Vec3D::zero()), |total, (force, torque)| {
+                (total.0 + force, total.1 + torque)
+            })
+    }
+
     pub fn rigid_bodies_iter_mut<'a>(&'a self) -> Box<Iterator<Item=RefMut<O>> + 'a> {
         let iterator = self.broadphase.bodies_iter_mut()
             .filter(|body| {
@@ -100,7 +253,25 @@ impl<B, C, D, E, I, O> World<O> for CustomWorld<B, C, D, E, I, O> where
         self.gravity = gravity;
     }
 
+    fn add_force_field(&mut self, force_field: Box<ForceField>) {
+        self.force_fields.push(force_field);
+    }
+
+    fn clear_force_fields(&mut self) {
+        self.force_fields.clear();
+    }
+
+    fn set_default_damping(&mut self, linear_damping: Scalar, angular_damping: Scalar) {
+        self.linear_damping = linear_damping;
+        self.angular_damping = angular_damping;
+    }
+
     fn create_rigid_body(&mut self, def: RigidBodyDef, extension: <O as DynamicBody>::Extension) -> Handle<O> {
+        let def = RigidBodyDef {
+            linear_damping: def.linear_damping.or(Some(self.linear_damping)),
+            angular_damping: def.angular_damping.or(Some(self.angular_damping)),
+            .. def
+        };
         let rigid_body_data = RigidBodyData::new(&def, extension);
 
         self.broadphase.create_body(BodyDef {
@@ -115,22 +286,75 @@ impl<B, C, D, E, I, O> World<O> for CustomWorld<B, C, D, E, I, O> where
         let fixed_body_data = FixedBodyData::new(&def, extension);
 
         self.broadphase.create_body(BodyDef {
-            group: CollisionGroup::Environment,
+            group: def.group,
             shape: def.shape,
             rotation: def.rotation,
             translation: def.translation,
         }, DynamicBodyExtension::Fixed(Box::new(fixed_body_data)))
     }
 
-    fn cast_ray<'a>(&'a self, ray: &Ray) -> Option<Ref<'a, O>> {
-        self.broadphase.cast_ray(ray).filter(move |body| {
-            match body.shape().downcast() {
+    fn create_kinematic_body(&mut self, def: KinematicBodyDef, extension: <O as DynamicBody>::Extension) -> Handle<O> {
+        let kinematic_body_data = KinematicBodyData::new(&def, extension);
+
+        self.broadphase.create_body(BodyDef {
+            group: def.group,
+            shape: def.shape,
+            rotation: def.rotation,
+            translation: def.translation,
+        }, DynamicBodyExtension::Kinematic(Box::new(kinematic_body_data)))
+    }
+
+    fn cast_ray<'a>(&'a self, ray: &Ray) -> Result<Option<Ref<'a, O>>, MachError> {
+        for body in self.broadphase.cast_ray(ray) {
+            let is_hit = match body.shape().downcast() {
                 ShapeRef::Sphere(sphere) => {
                     sphere.fast_intersection(ray)
                 },
 
-                _otherwise => panic!("Unhandled shape-ray cast intersection"),
+                _otherwise => return Err(MachError::UnsupportedRayCast(body.id())),
+            };
+
+            if is_hit {
+                return Ok(Some(body));
             }
-        }).next()
+        }
+
+        Ok(None)
+    }
+
+    fn contact_events_iter<'a>(&'a self) -> Box<Iterator<Item=&'a ContactEvent> + 'a> {
+        Box::new(self.contact_events.iter())
+    }
+
+    fn overlap_events_iter<'a>(&'a self) -> Box<Iterator<Item=&'a OverlapEvent<O>> + 'a> {
+        Box::new(self.overlap_events.iter())
+    }
+
+    fn set_contact_modifier(&mut self, modifier: Box<Fn(&mut Contact<O>)>) {
+        self.contact_modifier = Some(modifier);
+    }
+
+    fn clear_contact_modifier(&mut self) {
+        self.contact_modifier = None;
+    }
+
+    fn collision_filter(&self) -> &CollisionFilter<E> {
+        &self.collision_filter
+    }
+
+    fn collision_filter_mut(&mut self) -> &mut CollisionFilter<E> {
+        &mut self.collision_filter
+    }
+
+    fn material_registry(&self) -> &MaterialRegistry {
+        &self.material_registry
+    }
+
+    fn material_registry_mut(&mut self) -> &mut MaterialRegistry {
+        &mut self.material_registry
+    }
+
+    fn solver_stats(&self) -> &SolverStats {
+        &self.solver_stats
     }
 }
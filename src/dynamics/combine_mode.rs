@@ -0,0 +1,34 @@
+#[cfg(test)]
+#[path="../../tests/dynamics/combine_mode_test.rs"]
+mod tests;
+
+use Scalar;
+
+/// The rule used to combine the coefficients of two materials in contact.
+/// When the materials use different rules, the rule declared last takes
+/// precedence.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum CombineMode {
+    Average,
+    GeometricMean,
+    Minimum,
+    Multiply,
+    Maximum,
+}
+
+impl CombineMode {
+    /// Returns the rule which takes precedence over the other.
+    pub fn dominant(self, other: CombineMode) -> CombineMode {
+        if self > other { self } else { other }
+    }
+
+    pub fn combine(self, coefficient_0: Scalar, coefficient_1: Scalar) -> Scalar {
+        match self {
+            CombineMode::Average => (coefficient_0 + coefficient_1) / 2.0,
+            CombineMode::GeometricMean => (coefficient_0 * coefficient_1).sqrt(),
+            CombineMode::Minimum => coefficient_0.min(coefficient_1),
+            CombineMode::Multiply => coefficient_0 * coefficient_1,
+            CombineMode::Maximum => coefficient_0.max(coefficient_1),
+        }
+    }
+}
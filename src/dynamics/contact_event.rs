@@ -0,0 +1,123 @@
+#[cfg(test)]
+#[path="../../tests/dynamics/contact_event_test.rs"]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use {ID, Scalar};
+use utils::ordered_pair;
+
+/// Describes the stage in the lifecycle of a contact between two bodies.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ContactEventKind {
+    /// The bodies started touching during the update.
+    Started,
+    /// The bodies were touching before the update and still are.
+    Persisted,
+    /// The bodies stopped touching during the update.
+    Ended,
+}
+
+/// Reports a change in the contact state between two bodies, along with the
+/// strength of the contact.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContactEvent {
+    kind: ContactEventKind,
+    ids: (ID, ID),
+    normal_impulse: Scalar,
+    relative_speed: Scalar,
+}
+
+impl ContactEvent {
+    pub fn new(kind: ContactEventKind, ids: (ID, ID), normal_impulse: Scalar, relative_speed: Scalar) -> ContactEvent {
+        ContactEvent {
+            kind: kind,
+            ids: ids,
+            normal_impulse: normal_impulse,
+            relative_speed: relative_speed,
+        }
+    }
+
+    /// Combines the events of consecutive sub-steps into one event per pair
+    /// of bodies, in the order the pairs first appear. The combined event
+    /// compares the state of the pair before the first sub-step with its state
+    /// after the last one, and sums the normal impulses. Pairs which start and
+    /// stop touching within the sub-steps report a `Started` and an `Ended`
+    /// event.
+    pub fn merge(events: &[ContactEvent]) -> Vec<ContactEvent> {
+        let mut pairs = Vec::new();
+        let mut events_by_pair: BTreeMap<(ID, ID), Vec<&ContactEvent>> = BTreeMap::new();
+
+        for event in events.iter() {
+            let key = ordered_pair(event.ids.0, event.ids.1);
+
+            events_by_pair.entry(key)
+                .or_insert_with(|| {
+                    pairs.push(key);
+
+                    Vec::new()
+                })
+                .push(event);
+        }
+
+        let mut merged_events = Vec::with_capacity(pairs.len());
+
+        for key in pairs.iter() {
+            let pair_events = &events_by_pair[key];
+            let first_event = pair_events[0];
+            let last_event = pair_events[pair_events.len() - 1];
+            let was_touching = first_event.kind != ContactEventKind::Started;
+            let is_touching = last_event.kind != ContactEventKind::Ended;
+            let normal_impulse = pair_events.iter()
+                .fold(0.0, |total, event| total + event.normal_impulse);
+            let touching_event = pair_events.iter()
+                .find(|event| event.kind != ContactEventKind::Ended)
+                .cloned();
+            let ended_event = ContactEvent::new(ContactEventKind::Ended, last_event.ids, 0.0, last_event.relative_speed);
+
+            match (was_touching, is_touching, touching_event) {
+                (true, true, Some(event)) => {
+                    merged_events.push(ContactEvent::new(ContactEventKind::Persisted, event.ids, normal_impulse, event.relative_speed));
+                },
+
+                (false, true, Some(event)) => {
+                    merged_events.push(ContactEvent::new(ContactEventKind::Started, event.ids, normal_impulse, event.relative_speed));
+                },
+
+                (false, false, Some(event)) => {
+                    merged_events.push(ContactEvent::new(ContactEventKind::Started, event.ids, normal_impulse, event.relative_speed));
+                    merged_events.push(ended_event);
+                },
+
+                _otherwise => merged_events.push(ended_event),
+            }
+        }
+
+        merged_events
+    }
+
+    #[inline(always)]
+    pub fn kind(&self) -> ContactEventKind {
+        self.kind
+    }
+
+    #[inline(always)]
+    pub fn ids(&self) -> (ID, ID) {
+        self.ids
+    }
+
+    /// The total impulse applied along the contact normal during the update.
+    /// This is always zero for `Ended` events.
+    #[inline(always)]
+    pub fn normal_impulse(&self) -> Scalar {
+        self.normal_impulse
+    }
+
+    /// The speed of the bodies relative to each other at the contact center.
+    /// For `Started` and `Persisted` events this is measured before the
+    /// constraint solver is applied.
+    #[inline(always)]
+    pub fn relative_speed(&self) -> Scalar {
+        self.relative_speed
+    }
+}
@@ -0,0 +1,86 @@
+#[cfg(test)]
+#[path="../../tests/dynamics/contact_event_tracker_test.rs"]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use {ID, Scalar};
+use maths::Vec3D;
+use utils::{Handle, ordered_pair};
+use dynamics::{ContactEvent, ContactEventKind, DynamicBody, DynamicBodyRef};
+use collisions::Contact;
+
+struct ActiveContact<T> where T: DynamicBody {
+    ids: (ID, ID),
+    handles: (Handle<T>, Handle<T>),
+    center: Vec3D,
+}
+
+/// Keeps track of the pairs of bodies in contact across updates, and
+/// generates the corresponding `ContactEvent`s.
+pub struct ContactEventTracker<T> where T: DynamicBody {
+    active_contacts: BTreeMap<(ID, ID), ActiveContact<T>>,
+}
+
+impl<T> ContactEventTracker<T> where T: DynamicBody {
+    pub fn new() -> ContactEventTracker<T> {
+        ContactEventTracker {
+            active_contacts: BTreeMap::new(),
+        }
+    }
+
+    /// Computes the speed of the bodies relative to each other at the center
+    /// of the contact.
+    pub fn relative_speed_of(contact: &Contact<T>) -> Scalar {
+        relative_speed_at(contact.handles(), contact.center())
+    }
+
+    /// Replaces the active contacts with the contacts provided, and returns
+    /// the events describing the changes. The relative speeds and normal
+    /// impulses are expected to be in the same order as the contacts.
+    pub fn update(&mut self, contacts: &Vec<Contact<T>>, relative_speeds: &Vec<Scalar>, normal_impulses: &Vec<Scalar>) -> Vec<ContactEvent> {
+        let mut events = Vec::with_capacity(contacts.len());
+        let mut active_contacts = BTreeMap::new();
+
+        for (i, contact) in contacts.iter().enumerate() {
+            let &(ref handle_0, ref handle_1) = contact.handles();
+            let ids = (handle_0.borrow().id(), handle_1.borrow().id());
+            let key = ordered_pair(ids.0, ids.1);
+
+            let kind = if self.active_contacts.remove(&key).is_some() {
+                ContactEventKind::Persisted
+            } else {
+                ContactEventKind::Started
+            };
+
+            let normal_impulse = normal_impulses.get(i).cloned().unwrap_or(0.0);
+            events.push(ContactEvent::new(kind, ids, normal_impulse, relative_speeds[i]));
+
+            active_contacts.insert(key, ActiveContact {
+                ids: ids,
+                handles: (handle_0.clone(), handle_1.clone()),
+                center: contact.center(),
+            });
+        }
+
+        for (_key, contact) in self.active_contacts.iter() {
+            let relative_speed = relative_speed_at(&contact.handles, contact.center);
+
+            events.push(ContactEvent::new(ContactEventKind::Ended, contact.ids, 0.0, relative_speed));
+        }
+
+        self.active_contacts = active_contacts;
+
+        return events;
+    }
+}
+
+fn relative_speed_at<T>(handles: &(Handle<T>, Handle<T>), point: Vec3D) -> Scalar where T: DynamicBody {
+    let body_0 = handles.0.borrow();
+    let body_1 = handles.1.borrow();
+
+    let velocity_0 = DynamicBodyRef::from(&*body_0).velocity_at(point);
+    let velocity_1 = DynamicBodyRef::from(&*body_1).velocity_at(point);
+
+    (velocity_1 - velocity_0).length()
+}
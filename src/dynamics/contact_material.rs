@@ -0,0 +1,10 @@
+use Scalar;
+
+/// The coefficients used to resolve a contact between two materials.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ContactMaterial {
+    pub friction_coefficient: Scalar,
+    pub restitution_coefficient: Scalar,
+    pub rolling_friction: Scalar,
+    pub torsional_friction: Scalar,
+}
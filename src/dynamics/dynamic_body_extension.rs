@@ -1,11 +1,52 @@
-use dynamics::{FixedBodyData, RigidBodyData};
+use dynamics::{FixedBodyData, KinematicBodyData, MaterialData, RigidBodyData};
 
 pub enum DynamicBodyExtension<E> {
     Rigid(Box<RigidBodyData<E>>),
     Fixed(Box<FixedBodyData<E>>),
+    Kinematic(Box<KinematicBodyData<E>>),
 }
 
 impl<E> DynamicBodyExtension<E> {
+    pub fn is_sensor(&self) -> bool {
+        match self {
+            &DynamicBodyExtension::Rigid(ref rigid_body_data) =>
+                rigid_body_data.is_sensor(),
+
+            &DynamicBodyExtension::Fixed(ref fixed_body_data) =>
+                fixed_body_data.is_sensor(),
+
+            &DynamicBodyExtension::Kinematic(ref kinematic_body_data) =>
+                kinematic_body_data.is_sensor(),
+        }
+    }
+
+    pub fn material_data(&self) -> &MaterialData {
+        match self {
+            &DynamicBodyExtension::Rigid(ref rigid_body_data) =>
+                rigid_body_data.material_data(),
+
+            &DynamicBodyExtension::Fixed(ref fixed_body_data) =>
+                fixed_body_data.material_data(),
+
+            &DynamicBodyExtension::Kinematic(ref kinematic_body_data) =>
+                kinematic_body_data.material_data(),
+        }
+    }
+
+    /// The name of the registered material used by the body, if any.
+    pub fn material(&self) -> Option<&str> {
+        match self {
+            &DynamicBodyExtension::Rigid(ref rigid_body_data) =>
+                rigid_body_data.material(),
+
+            &DynamicBodyExtension::Fixed(ref fixed_body_data) =>
+                fixed_body_data.material(),
+
+            &DynamicBodyExtension::Kinematic(ref kinematic_body_data) =>
+                kinematic_body_data.material(),
+        }
+    }
+
     pub fn extension_data(&self) -> &E {
         match self {
             &DynamicBodyExtension::Rigid(ref rigid_body_data) =>
@@ -13,6 +54,9 @@ impl<E> DynamicBodyExtension<E> {
 
             &DynamicBodyExtension::Fixed(ref fixed_body_data) =>
                 fixed_body_data.extension_data(),
+
+            &DynamicBodyExtension::Kinematic(ref kinematic_body_data) =>
+                kinematic_body_data.extension_data(),
         }
     }
 
@@ -23,6 +67,9 @@ impl<E> DynamicBodyExtension<E> {
 
             &mut DynamicBodyExtension::Fixed(ref mut fixed_body_data) =>
                 fixed_body_data.extension_data_mut(),
+
+            &mut DynamicBodyExtension::Kinematic(ref mut kinematic_body_data) =>
+                kinematic_body_data.extension_data_mut(),
         }
     }
 }
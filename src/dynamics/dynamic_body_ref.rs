@@ -1,13 +1,32 @@
-use dynamics::{DynamicBody, DynamicBodyExtension, FixedBodyRef, FixedBodyRefMut, RigidBodyRef, RigidBodyRefMut};
+use maths::{CrossProduct, Vec3D};
+use dynamics::{DynamicBody, DynamicBodyExtension, FixedBodyRef, FixedBodyRefMut, KinematicBodyRef, KinematicBodyRefMut, RigidBodyRef, RigidBodyRefMut};
 
 pub enum DynamicBodyRef<'a, T> where T: DynamicBody {
     Rigid(RigidBodyRef<'a, T>),
     Fixed(FixedBodyRef<'a, T>),
+    Kinematic(KinematicBodyRef<'a, T>),
 }
 
 pub enum DynamicBodyRefMut<'a, T> where T: DynamicBody {
     Rigid(RigidBodyRefMut<'a, T>),
     Fixed(FixedBodyRefMut<'a, T>),
+    Kinematic(KinematicBodyRefMut<'a, T>),
+}
+
+impl<'a, T> DynamicBodyRef<'a, T> where T: DynamicBody {
+    /// Returns the velocity of the body at the given point in world
+    /// coordinates, taking into account the rotation of the body.
+    pub fn velocity_at(&self, point: Vec3D) -> Vec3D {
+        match self {
+            &DynamicBodyRef::Rigid(ref rigid_body) =>
+                rigid_body.velocity() + rigid_body.angular_velocity().cross(point - rigid_body.center_of_mass()),
+
+            &DynamicBodyRef::Fixed(_) => Vec3D::zero(),
+
+            &DynamicBodyRef::Kinematic(ref kinematic_body) =>
+                kinematic_body.velocity() + kinematic_body.angular_velocity().cross(point - kinematic_body.center_of_mass()),
+        }
+    }
 }
 
 impl<'a, T> From<&'a T> for DynamicBodyRef<'a, T> where T: DynamicBody {
@@ -20,6 +39,10 @@ impl<'a, T> From<&'a T> for DynamicBodyRef<'a, T> where T: DynamicBody {
             &DynamicBodyExtension::Fixed(ref data) => {
                 DynamicBodyRef::Fixed(FixedBodyRef::new(body.data(), data))
             },
+
+            &DynamicBodyExtension::Kinematic(ref data) => {
+                DynamicBodyRef::Kinematic(KinematicBodyRef::new(body.data(), data))
+            },
         }
     }
 }
@@ -36,6 +59,10 @@ impl<'a, T> From<&'a mut T> for DynamicBodyRefMut<'a, T> where T: DynamicBody {
             &mut DynamicBodyExtension::Fixed(ref mut data) => {
                 DynamicBodyRefMut::Fixed(FixedBodyRefMut::new(body_data, data))
             },
+
+            &mut DynamicBodyExtension::Kinematic(ref mut data) => {
+                DynamicBodyRefMut::Kinematic(KinematicBodyRefMut::new(body_data, data))
+            },
         }
     }
 }
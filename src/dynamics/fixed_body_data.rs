@@ -5,6 +5,8 @@ use dynamics::{FixedBodyDef, MaterialData};
 pub struct FixedBodyData<E> {
     extension_data: E,
     material_data: MaterialData,
+    material: Option<String>,
+    is_sensor: bool,
 }
 
 impl<E> FixedBodyData<E> {
@@ -14,7 +16,10 @@ impl<E> FixedBodyData<E> {
             material_data: MaterialData {
                 friction_coefficient: def.friction_coefficient,
                 restitution_coefficient: def.restitution_coefficient,
+                .. MaterialData::default()
             },
+            material: def.material.clone(),
+            is_sensor: def.is_sensor,
         }
     }
 
@@ -28,6 +33,24 @@ impl<E> FixedBodyData<E> {
         self.material_data.restitution_coefficient
     }
 
+    #[inline(always)]
+    pub fn material_data(&self) -> &MaterialData {
+        &self.material_data
+    }
+
+    /// The name of the registered material used by the body, if any.
+    #[inline(always)]
+    pub fn material(&self) -> Option<&str> {
+        self.material.as_ref().map(|name| name.as_str())
+    }
+
+    /// Returns true if the body only reports overlaps, without producing a
+    /// response from the constraint solver.
+    #[inline(always)]
+    pub fn is_sensor(&self) -> bool {
+        self.is_sensor
+    }
+
     #[inline(always)]
     pub fn extension_data(&self) -> &E {
         &self.extension_data
@@ -11,6 +11,11 @@ pub struct FixedBodyDef {
     pub translation: Vec3D,
     pub friction_coefficient: Scalar,
     pub restitution_coefficient: Scalar,
+    /// The name of a material in the `MaterialRegistry` of the world. When
+    /// set, the registered material is used instead of the coefficients,
+    /// which remain in use while no material is registered under the name.
+    pub material: Option<String>,
+    pub is_sensor: bool,
 }
 
 impl Default for FixedBodyDef {
@@ -18,12 +23,14 @@ impl Default for FixedBodyDef {
         let material_defaults = MaterialData::default();
 
         FixedBodyDef {
-            group: CollisionGroup::Default,
+            group: CollisionGroup::Environment,
             shape: Box::new(Cuboid::cube(1.0)),
             rotation: UnitQuat::identity(),
             translation: Vec3D::zero(),
             friction_coefficient: material_defaults.friction_coefficient,
             restitution_coefficient: material_defaults.restitution_coefficient,
+            material: None,
+            is_sensor: false,
         }
     }
 }
@@ -0,0 +1,361 @@
+#[cfg(test)]
+#[path="../../../tests/dynamics/force_fields/fluid_volume_test.rs"]
+mod tests;
+
+use {PI, Scalar};
+use maths::{CrossProduct, DotProduct, UnitVec3D, Vec3D};
+use collisions::CollisionData;
+use collisions::shapes::Plane;
+use collisions::shapes::convex_shapes::ShapeRef;
+use dynamics::{ForceField, ForceFieldSample};
+
+type Tetrahedron = [Vec3D; 4];
+
+/// The resolution of the polyhedron approximating spheres which cross an edge
+/// of a fluid volume.
+const SPHERE_LATITUDES: usize = 16;
+const SPHERE_LONGITUDES: usize = 32;
+
+/// The part of a body which is below the surface of a fluid.
+#[derive(Clone, Copy, Debug)]
+pub struct Submersion {
+    volume: Scalar,
+    center_of_buoyancy: Vec3D,
+}
+
+impl Submersion {
+    #[inline(always)]
+    pub fn volume(&self) -> Scalar {
+        self.volume
+    }
+
+    /// The centroid of the submerged volume, in world coordinates.
+    #[inline(always)]
+    pub fn center_of_buoyancy(&self) -> Vec3D {
+        self.center_of_buoyancy
+    }
+}
+
+/// A region filled with a fluid at rest, which pushes the bodies submerged in
+/// it against gravity and damps their motion. The submerged part of each body
+/// is found by clipping its shape against the bounding planes of the region.
+pub struct FluidVolume {
+    /// The planes bounding the region, with their normals pointing out of the
+    /// fluid.
+    planes: Vec<Plane>,
+    density: Scalar,
+    linear_drag: Scalar,
+    angular_drag: Scalar,
+}
+
+impl FluidVolume {
+    /// Creates a fluid which fills everything below the surface, i.e. behind
+    /// the normal.
+    pub fn half_space(surface_point: Vec3D, surface_normal: UnitVec3D, density: Scalar) -> FluidVolume {
+        FluidVolume::bounded_by(vec!(Plane::new(surface_point, surface_normal)), density)
+    }
+
+    /// Creates a fluid which fills the axis-aligned box between the corners.
+    pub fn axis_aligned(min: Vec3D, max: Vec3D, density: Scalar) -> FluidVolume {
+        let axes = [
+            UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0)),
+            UnitVec3D::from(Vec3D::new(0.0, 1.0, 0.0)),
+            UnitVec3D::from(Vec3D::new(0.0, 0.0, 1.0)),
+        ];
+        let planes = axes.iter()
+            .flat_map(|&axis| vec!(Plane::new(max, axis), Plane::new(min, -axis)))
+            .collect();
+
+        FluidVolume::bounded_by(planes, density)
+    }
+
+    fn bounded_by(planes: Vec<Plane>, density: Scalar) -> FluidVolume {
+        FluidVolume {
+            planes: planes,
+            density: density,
+            linear_drag: 0.0,
+            angular_drag: 0.0,
+        }
+    }
+
+    /// Sets the drag force per unit of velocity on a fully submerged body,
+    /// which is scaled by the submerged fraction of partially submerged
+    /// bodies.
+    pub fn with_linear_drag(mut self, linear_drag: Scalar) -> FluidVolume {
+        self.linear_drag = linear_drag;
+
+        self
+    }
+
+    /// Sets the drag torque per unit of angular velocity on a fully submerged
+    /// body, which is scaled by the submerged fraction of partially submerged
+    /// bodies.
+    pub fn with_angular_drag(mut self, angular_drag: Scalar) -> FluidVolume {
+        self.angular_drag = angular_drag;
+
+        self
+    }
+
+    #[inline(always)]
+    pub fn density(&self) -> Scalar {
+        self.density
+    }
+
+    /// Computes the part of the body inside the fluid, if any.
+    pub fn submersion_of(&self, collision_data: &CollisionData) -> Option<Submersion> {
+        match collision_data.shape().downcast() {
+            ShapeRef::Sphere(sphere) => {
+                self.submersion_of_sphere(*collision_data.translation(), sphere.radius())
+            },
+
+            ShapeRef::Cuboid(cuboid) => {
+                self.submersion_of_polyhedron(collision_data, cuboid.triangles_iter())
+            },
+
+            ShapeRef::TriangleMesh(mesh) => {
+                self.submersion_of_polyhedron(collision_data, mesh.triangles_iter())
+            },
+        }
+    }
+
+    /// Intersects the sphere with the plane cutting it, if only one does.
+    /// Spheres crossing an edge of the region are clipped as a polyhedron
+    /// approximating the sphere instead.
+    fn submersion_of_sphere(&self, center: Vec3D, radius: Scalar) -> Option<Submersion> {
+        let cutting_planes: Vec<&Plane> = self.planes.iter()
+            .filter(|plane| plane.normal_projection_of(center) > -radius)
+            .collect();
+
+        if cutting_planes.iter().any(|plane| plane.normal_projection_of(center) >= radius) {
+            return None;
+        }
+
+        let sphere_volume = PI * radius * radius * radius * 4.0 / 3.0;
+
+        match cutting_planes.len() {
+            0 => Some(Submersion {
+                volume: sphere_volume,
+                center_of_buoyancy: center,
+            }),
+
+            1 => {
+                let plane = cutting_planes[0];
+                let depth = radius - plane.normal_projection_of(center);
+                let volume = PI * depth * depth * (3.0 * radius - depth) / 3.0;
+                let centroid_offset = 3.0 * (2.0 * radius - depth) * (2.0 * radius - depth) / (4.0 * (3.0 * radius - depth));
+
+                Some(Submersion {
+                    volume: volume,
+                    center_of_buoyancy: center - Vec3D::from(plane.normal()) * centroid_offset,
+                })
+            },
+
+            _otherwise => {
+                let triangles = sphere_triangles(center, radius);
+                let approximate_volume = triangles.iter()
+                    .fold(0.0, |volume, triangle| volume + volume_of(&[center, triangle[0], triangle[1], triangle[2]]));
+
+                // corrects for the volume lost by the flat faces of the
+                // approximation
+                self.clip(center, triangles.into_iter())
+                    .map(|submersion| Submersion {
+                        volume: submersion.volume * sphere_volume / approximate_volume,
+                        center_of_buoyancy: submersion.center_of_buoyancy,
+                    })
+            },
+        }
+    }
+
+    fn submersion_of_polyhedron<I>(&self, collision_data: &CollisionData, triangles: I) -> Option<Submersion> where I: Iterator<Item=[Vec3D; 3]> {
+        let vertices: Vec<Vec3D> = collision_data.vertices_iter().collect();
+        let is_outside_of = |plane: &Plane| {
+            vertices.iter().all(|&vertex| plane.normal_projection_of(vertex) >= 0.0)
+        };
+        let is_inside_of = |plane: &Plane| {
+            vertices.iter().all(|&vertex| plane.normal_projection_of(vertex) <= 0.0)
+        };
+
+        if self.planes.iter().any(is_outside_of) {
+            return None;
+        }
+
+        let shape = collision_data.shape();
+        let transform = collision_data.transform();
+        let center_of_mass = transform.apply_to_point(shape.center_of_mass());
+
+        if self.planes.iter().all(is_inside_of) {
+            return Some(Submersion {
+                volume: shape.volume(),
+                center_of_buoyancy: center_of_mass,
+            });
+        }
+
+        let world_triangles = triangles.map(|triangle| [
+            transform.apply_to_point(triangle[0]),
+            transform.apply_to_point(triangle[1]),
+            transform.apply_to_point(triangle[2]),
+        ]);
+
+        self.clip(center_of_mass, world_triangles)
+    }
+
+    /// Clips the solid formed by joining each triangle of its surface to the
+    /// interior point against every plane of the region.
+    fn clip<I>(&self, interior_point: Vec3D, triangles: I) -> Option<Submersion> where I: Iterator<Item=[Vec3D; 3]> {
+        let mut tetrahedra: Vec<Tetrahedron> = triangles
+            .map(|triangle| [interior_point, triangle[0], triangle[1], triangle[2]])
+            .collect();
+
+        for plane in self.planes.iter() {
+            let mut clipped = Vec::with_capacity(tetrahedra.len());
+
+            for tetrahedron in tetrahedra.iter() {
+                clip_tetrahedron(tetrahedron, plane, &mut clipped);
+            }
+
+            tetrahedra = clipped;
+        }
+
+        let (volume, moment) = tetrahedra.iter()
+            .fold((0.0, Vec3D::zero()), |(volume, moment), tetrahedron| {
+                let tetrahedron_volume = volume_of(tetrahedron);
+                let centroid = (tetrahedron[0] + tetrahedron[1] + tetrahedron[2] + tetrahedron[3]) / 4.0;
+
+                (volume + tetrahedron_volume, moment + centroid * tetrahedron_volume)
+            });
+
+        if volume <= 0.0 {
+            return None;
+        }
+
+        Some(Submersion {
+            volume: volume,
+            center_of_buoyancy: moment / volume,
+        })
+    }
+}
+
+impl ForceField for FluidVolume {
+    fn force_and_torque_on(&self, body: &ForceFieldSample) -> (Vec3D, Vec3D) {
+        let submersion = match self.submersion_of(body.collision_data()) {
+            Some(submersion) => submersion,
+
+            None => return (Vec3D::zero(), Vec3D::zero()),
+        };
+        let total_volume = body.collision_data().shape().volume();
+        let submerged_fraction = if total_volume > 0.0 {
+            (submersion.volume() / total_volume).min(1.0)
+        } else {
+            0.0
+        };
+
+        let buoyancy = -body.gravity() * self.density * submersion.volume();
+        let buoyancy_torque = (submersion.center_of_buoyancy() - body.center_of_mass()).cross(buoyancy);
+        let drag = -body.velocity() * self.linear_drag * submerged_fraction;
+        let drag_torque = -body.angular_velocity() * self.angular_drag * submerged_fraction;
+
+        (buoyancy + drag, buoyancy_torque + drag_torque)
+    }
+}
+
+/// Covers the surface of the sphere with triangles between lines of latitude
+/// and longitude, whose corners lie on the sphere.
+fn sphere_triangles(center: Vec3D, radius: Scalar) -> Vec<[Vec3D; 3]> {
+    let point = |latitude: usize, longitude: usize| {
+        let polar_angle = PI * latitude as Scalar / SPHERE_LATITUDES as Scalar;
+        let azimuth = 2.0 * PI * longitude as Scalar / SPHERE_LONGITUDES as Scalar;
+
+        center + Vec3D::new(polar_angle.sin() * azimuth.cos(), polar_angle.sin() * azimuth.sin(), polar_angle.cos()) * radius
+    };
+    let mut triangles = Vec::with_capacity(2 * SPHERE_LATITUDES * SPHERE_LONGITUDES);
+
+    for latitude in 0..SPHERE_LATITUDES {
+        for longitude in 0..SPHERE_LONGITUDES {
+            let corners = [
+                point(latitude, longitude),
+                point(latitude, longitude + 1),
+                point(latitude + 1, longitude + 1),
+                point(latitude + 1, longitude),
+            ];
+
+            // the rows touching the poles have a single triangle per column
+            if latitude > 0 {
+                triangles.push([corners[0], corners[1], corners[2]]);
+            }
+
+            if latitude + 1 < SPHERE_LATITUDES {
+                triangles.push([corners[0], corners[2], corners[3]]);
+            }
+        }
+    }
+
+    triangles
+}
+
+fn volume_of(tetrahedron: &Tetrahedron) -> Scalar {
+    let edge_0 = tetrahedron[1] - tetrahedron[0];
+    let edge_1 = tetrahedron[2] - tetrahedron[0];
+    let edge_2 = tetrahedron[3] - tetrahedron[0];
+
+    edge_0.cross(edge_1).dot(edge_2).abs() / 6.0
+}
+
+/// Keeps the part of the tetrahedron behind the plane, split into
+/// tetrahedra.
+fn clip_tetrahedron(tetrahedron: &Tetrahedron, plane: &Plane, clipped: &mut Vec<Tetrahedron>) {
+    let offsets: Vec<Scalar> = tetrahedron.iter()
+        .map(|&vertex| plane.normal_projection_of(vertex))
+        .collect();
+    let (inside, outside): (Vec<usize>, Vec<usize>) = (0..4)
+        .partition(|&index| offsets[index] <= 0.0);
+    let crossing = |from: usize, to: usize| {
+        let ratio = offsets[from] / (offsets[from] - offsets[to]);
+
+        tetrahedron[from] + (tetrahedron[to] - tetrahedron[from]) * ratio
+    };
+
+    match inside.len() {
+        0 => (),
+
+        1 => {
+            let apex = inside[0];
+
+            clipped.push([
+                tetrahedron[apex],
+                crossing(apex, outside[0]),
+                crossing(apex, outside[1]),
+                crossing(apex, outside[2]),
+            ]);
+        },
+
+        2 => {
+            let (a, b) = (inside[0], inside[1]);
+
+            push_prism(
+                [tetrahedron[a], crossing(a, outside[0]), crossing(a, outside[1])],
+                [tetrahedron[b], crossing(b, outside[0]), crossing(b, outside[1])],
+                clipped,
+            );
+        },
+
+        3 => {
+            let tip = outside[0];
+
+            push_prism(
+                [tetrahedron[inside[0]], tetrahedron[inside[1]], tetrahedron[inside[2]]],
+                [crossing(inside[0], tip), crossing(inside[1], tip), crossing(inside[2], tip)],
+                clipped,
+            );
+        },
+
+        _otherwise => clipped.push(*tetrahedron),
+    }
+}
+
+/// Splits the convex solid between two triangles, whose corresponding
+/// vertices are joined by its edges, into tetrahedra.
+fn push_prism(bottom: [Vec3D; 3], top: [Vec3D; 3], tetrahedra: &mut Vec<Tetrahedron>) {
+    tetrahedra.push([bottom[0], bottom[1], bottom[2], top[0]]);
+    tetrahedra.push([bottom[1], bottom[2], top[0], top[1]]);
+    tetrahedra.push([bottom[2], top[0], top[1], top[2]]);
+}
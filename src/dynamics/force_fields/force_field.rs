@@ -0,0 +1,85 @@
+use Scalar;
+use maths::Vec3D;
+use collisions::CollisionData;
+
+/// The state of a rigid body at which a `ForceField` is evaluated, in world
+/// coordinates.
+pub struct ForceFieldSample<'a> {
+    collision_data: &'a CollisionData,
+    center_of_mass: Vec3D,
+    velocity: Vec3D,
+    angular_velocity: Vec3D,
+    mass: Scalar,
+    gravity: Vec3D,
+}
+
+impl<'a> ForceFieldSample<'a> {
+    /// Creates a sample of a body at rest, without gravity.
+    pub fn new(collision_data: &'a CollisionData, center_of_mass: Vec3D, mass: Scalar) -> ForceFieldSample<'a> {
+        ForceFieldSample {
+            collision_data: collision_data,
+            center_of_mass: center_of_mass,
+            velocity: Vec3D::zero(),
+            angular_velocity: Vec3D::zero(),
+            mass: mass,
+            gravity: Vec3D::zero(),
+        }
+    }
+
+    pub fn with_velocity(mut self, velocity: Vec3D) -> ForceFieldSample<'a> {
+        self.velocity = velocity;
+
+        self
+    }
+
+    pub fn with_angular_velocity(mut self, angular_velocity: Vec3D) -> ForceFieldSample<'a> {
+        self.angular_velocity = angular_velocity;
+
+        self
+    }
+
+    pub fn with_gravity(mut self, gravity: Vec3D) -> ForceFieldSample<'a> {
+        self.gravity = gravity;
+
+        self
+    }
+
+    #[inline(always)]
+    pub fn collision_data(&self) -> &'a CollisionData {
+        self.collision_data
+    }
+
+    #[inline(always)]
+    pub fn center_of_mass(&self) -> Vec3D {
+        self.center_of_mass
+    }
+
+    #[inline(always)]
+    pub fn velocity(&self) -> Vec3D {
+        self.velocity
+    }
+
+    #[inline(always)]
+    pub fn angular_velocity(&self) -> Vec3D {
+        self.angular_velocity
+    }
+
+    #[inline(always)]
+    pub fn mass(&self) -> Scalar {
+        self.mass
+    }
+
+    /// The gravity of the world, unscaled by the gravity scale of the body.
+    #[inline(always)]
+    pub fn gravity(&self) -> Vec3D {
+        self.gravity
+    }
+}
+
+/// A source of forces which acts on every rigid body in the world, evaluated
+/// for each body in every step.
+pub trait ForceField {
+    /// Returns the force through the center of mass of the body and the
+    /// torque about it.
+    fn force_and_torque_on(&self, body: &ForceFieldSample) -> (Vec3D, Vec3D);
+}
@@ -0,0 +1,11 @@
+mod force_field;
+mod fluid_volume;
+mod point_attractor;
+mod wind;
+mod vortex;
+
+pub use self::force_field::{ForceField, ForceFieldSample};
+pub use self::fluid_volume::{FluidVolume, Submersion};
+pub use self::point_attractor::PointAttractor;
+pub use self::wind::Wind;
+pub use self::vortex::Vortex;
@@ -0,0 +1,50 @@
+#[cfg(test)]
+#[path="../../../tests/dynamics/force_fields/point_attractor_test.rs"]
+mod tests;
+
+use Scalar;
+use maths::Vec3D;
+use dynamics::{ForceField, ForceFieldSample};
+
+/// Pulls bodies towards a point with an acceleration which falls off with
+/// the square of the distance, like the gravity of a planet.
+pub struct PointAttractor {
+    center: Vec3D,
+    strength: Scalar,
+    min_distance: Scalar,
+}
+
+impl PointAttractor {
+    /// Creates an attractor with the acceleration `strength / distance²`.
+    pub fn new(center: Vec3D, strength: Scalar) -> PointAttractor {
+        PointAttractor {
+            center: center,
+            strength: strength,
+            min_distance: 1.0,
+        }
+    }
+
+    /// Sets the distance within which the acceleration stops growing, which
+    /// keeps bodies passing close to the center from being flung away.
+    pub fn with_min_distance(mut self, min_distance: Scalar) -> PointAttractor {
+        self.min_distance = min_distance;
+
+        self
+    }
+}
+
+impl ForceField for PointAttractor {
+    fn force_and_torque_on(&self, body: &ForceFieldSample) -> (Vec3D, Vec3D) {
+        let offset = self.center - body.center_of_mass();
+        let distance = offset.length();
+
+        if distance == 0.0 {
+            return (Vec3D::zero(), Vec3D::zero());
+        }
+
+        let clamped_distance = distance.max(self.min_distance);
+        let acceleration = self.strength / (clamped_distance * clamped_distance);
+
+        (offset * (body.mass() * acceleration / distance), Vec3D::zero())
+    }
+}
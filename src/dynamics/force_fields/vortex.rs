@@ -0,0 +1,58 @@
+#[cfg(test)]
+#[path="../../../tests/dynamics/force_fields/vortex_test.rs"]
+mod tests;
+
+use Scalar;
+use maths::{CrossProduct, DotProduct, UnitVec3D, Vec3D};
+use dynamics::{ForceField, ForceFieldSample};
+
+/// Swirls bodies around an axis, counter-clockwise when looking down the
+/// axis. The tangential acceleration grows linearly up to the core radius and
+/// falls off with the distance from the axis beyond it.
+pub struct Vortex {
+    center: Vec3D,
+    axis: UnitVec3D,
+    strength: Scalar,
+    core_radius: Scalar,
+}
+
+impl Vortex {
+    /// Creates a vortex with the tangential acceleration `strength / distance`
+    /// outside of its core.
+    pub fn new(center: Vec3D, axis: UnitVec3D, strength: Scalar) -> Vortex {
+        Vortex {
+            center: center,
+            axis: axis,
+            strength: strength,
+            core_radius: 1.0,
+        }
+    }
+
+    pub fn with_core_radius(mut self, core_radius: Scalar) -> Vortex {
+        self.core_radius = core_radius;
+
+        self
+    }
+}
+
+impl ForceField for Vortex {
+    fn force_and_torque_on(&self, body: &ForceFieldSample) -> (Vec3D, Vec3D) {
+        let axis = Vec3D::from(self.axis);
+        let offset = body.center_of_mass() - self.center;
+        let radial_offset = offset - axis * offset.dot(axis);
+        let distance = radial_offset.length();
+
+        if distance == 0.0 {
+            return (Vec3D::zero(), Vec3D::zero());
+        }
+
+        let acceleration = if distance < self.core_radius {
+            self.strength * distance / (self.core_radius * self.core_radius)
+        } else {
+            self.strength / distance
+        };
+        let tangent = axis.cross(radial_offset) / distance;
+
+        (tangent * (body.mass() * acceleration), Vec3D::zero())
+    }
+}
@@ -0,0 +1,29 @@
+#[cfg(test)]
+#[path="../../../tests/dynamics/force_fields/wind_test.rs"]
+mod tests;
+
+use Scalar;
+use maths::Vec3D;
+use dynamics::{ForceField, ForceFieldSample};
+
+/// A uniform wind which drags bodies along with it, with a force proportional
+/// to the velocity of the body relative to the wind.
+pub struct Wind {
+    velocity: Vec3D,
+    drag_coefficient: Scalar,
+}
+
+impl Wind {
+    pub fn new(velocity: Vec3D, drag_coefficient: Scalar) -> Wind {
+        Wind {
+            velocity: velocity,
+            drag_coefficient: drag_coefficient,
+        }
+    }
+}
+
+impl ForceField for Wind {
+    fn force_and_torque_on(&self, body: &ForceFieldSample) -> (Vec3D, Vec3D) {
+        ((self.velocity - body.velocity()) * self.drag_coefficient, Vec3D::zero())
+    }
+}
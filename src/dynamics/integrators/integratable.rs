@@ -1,8 +1,12 @@
-use maths::{Motion, UnitQuat, Transform, Vec3D};
+use Scalar;
+use maths::{CrossProduct, Matrix, Motion, UnitQuat, Transform, Vec3D};
 
 pub struct Integratable<'a> {
     transform: &'a mut Transform,
     motion: &'a mut Motion,
+    inertia: Option<Matrix>,
+    linear_damping: Scalar,
+    angular_damping: Scalar,
 }
 
 impl<'a> Integratable<'a> {
@@ -10,9 +14,44 @@ impl<'a> Integratable<'a> {
         Integratable {
             transform: transform,
             motion: motion,
+            inertia: None,
+            linear_damping: 0.0,
+            angular_damping: 0.0,
         }
     }
 
+    /// Sets the inertia tensor about the center of mass, in the local
+    /// coordinates of the body. Integrators require it to account for the
+    /// gyroscopic torque.
+    pub fn with_inertia(mut self, inertia: Matrix) -> Integratable<'a> {
+        self.inertia = Some(inertia);
+
+        self
+    }
+
+    #[inline]
+    pub fn inertia(&self) -> Option<Matrix> {
+        self.inertia
+    }
+
+    /// Sets the rates at which the velocity and angular velocity decay.
+    pub fn with_damping(mut self, linear_damping: Scalar, angular_damping: Scalar) -> Integratable<'a> {
+        self.linear_damping = linear_damping;
+        self.angular_damping = angular_damping;
+
+        self
+    }
+
+    #[inline]
+    pub fn linear_damping(&self) -> Scalar {
+        self.linear_damping
+    }
+
+    #[inline]
+    pub fn angular_damping(&self) -> Scalar {
+        self.angular_damping
+    }
+
     #[inline]
     pub fn transform(&self) -> &Transform {
         &self.transform
@@ -58,4 +97,50 @@ impl<'a> Integratable<'a> {
     pub fn angular_velocity(&self) -> &Vec3D {
         &self.motion().angular_velocity
     }
+
+    pub fn angular_velocity_mut(&mut self) -> &mut Vec3D {
+        &mut self.motion_mut().angular_velocity
+    }
+
+    /// Rotates the target at the angular velocity provided over the time
+    /// step. The rotation is exact for a constant angular velocity.
+    pub fn rotate_by(&mut self, angular_velocity: Vec3D, time_step: Scalar) {
+        let rotation = UnitQuat::from_rotation_vector(angular_velocity * time_step);
+
+        *self.rotation_mut() = rotation * self.rotation();
+    }
+
+    /// Reduces the velocity and angular velocity according to the damping
+    /// over the time step.
+    pub fn apply_damping(&mut self, time_step: Scalar) {
+        let damped_velocity = self.velocity() / (1.0 + time_step * self.linear_damping);
+        let damped_angular_velocity = self.angular_velocity() / (1.0 + time_step * self.angular_damping);
+
+        *self.velocity_mut() = damped_velocity;
+        *self.angular_velocity_mut() = damped_angular_velocity;
+    }
+
+    /// Applies the gyroscopic torque over the time step, if the target has an
+    /// inertia tensor. Euler's equations for the torque-free rotation of the
+    /// body are solved in the body coordinates, using the implicit midpoint
+    /// rule with a single Newton-Raphson iteration. Unlike an explicit update,
+    /// this does not add energy to bodies tumbling about an unstable axis.
+    pub fn apply_gyroscopic_torque(&mut self, time_step: Scalar) {
+        let inertia = match self.inertia {
+            Some(inertia) => inertia,
+
+            None => return,
+        };
+        let rotation = self.rotation();
+        let w = rotation.inverse().rotate(self.angular_velocity().clone());
+        let iw = inertia * w;
+
+        let residual = w.cross(iw) * time_step;
+        let jacobian = inertia + (Matrix::skew(w.x, w.y, w.z) * inertia - Matrix::skew(iw.x, iw.y, iw.z)) * (time_step * 0.5);
+
+        // `Matrix * Vec3D` applies the transpose of the matrix
+        let new_w = w - jacobian.inverse().transpose() * residual;
+
+        *self.angular_velocity_mut() = rotation.rotate(new_w);
+    }
 }
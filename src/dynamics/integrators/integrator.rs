@@ -2,6 +2,18 @@ use Scalar;
 use maths::Vec3D;
 use dynamics::Integratable;
 
+/// Advances bodies over time. Every integrator damps the velocities at the
+/// start of the step, so the motion over the step uses the damped
+/// velocities, and can include the gyroscopic torque at the same point.
 pub trait Integrator {
-    fn integrate_in_place(&self, target: &mut Integratable, time_step: Scalar, linear_acceleration: Vec3D);
+    /// Advances the target by the time step, under constant linear and
+    /// angular accelerations.
+    fn integrate_in_place(&self, target: &mut Integratable, time_step: Scalar, linear_acceleration: Vec3D, angular_acceleration: Vec3D) {
+        self.integrate_with_acceleration(target, 0.0, time_step, &|_position, _velocity, _time| linear_acceleration, angular_acceleration);
+    }
+
+    /// Advances the target by the time step, starting at the given time. The
+    /// linear acceleration is computed by the callback from the position,
+    /// velocity and time at each stage of the integration.
+    fn integrate_with_acceleration(&self, target: &mut Integratable, time: Scalar, time_step: Scalar, linear_acceleration: &Fn(Vec3D, Vec3D, Scalar) -> Vec3D, angular_acceleration: Vec3D);
 }
@@ -3,10 +3,14 @@
 #[path="../../../tests/dynamics/integrators/integrator_behaviour.rs"]
 mod behaviours;
 
+mod rk4;
 mod integrator;
 mod integratable;
+mod velocity_verlet;
 mod semi_implicit_euler;
 
 pub use self::integrator::Integrator;
 pub use self::integratable::Integratable;
+pub use self::rk4::RK4;
+pub use self::velocity_verlet::VelocityVerlet;
 pub use self::semi_implicit_euler::SemiImplicitEuler;
@@ -0,0 +1,64 @@
+#[cfg(test)]
+#[path="../../../tests/dynamics/integrators/rk4_test.rs"]
+mod tests;
+
+use Scalar;
+use maths::Vec3D;
+use dynamics::{Integratable, Integrator};
+
+/// An implementation of the classical fourth order Runge-Kutta integration
+/// strategy.
+pub struct RK4 {
+    gyroscopic_torque: bool,
+}
+
+impl RK4 {
+    pub fn new() -> RK4 {
+        RK4 {
+            gyroscopic_torque: false,
+        }
+    }
+
+    /// Includes the gyroscopic torque at the start of each step, as
+    /// `SemiImplicitEuler::with_gyroscopic_torque` does.
+    pub fn with_gyroscopic_torque(mut self) -> RK4 {
+        self.gyroscopic_torque = true;
+
+        self
+    }
+}
+
+impl Integrator for RK4 {
+    fn integrate_with_acceleration(&self, target: &mut Integratable, time: Scalar, t: Scalar, linear_acceleration: &Fn(Vec3D, Vec3D, Scalar) -> Vec3D, angular_acceleration: Vec3D) {
+        target.apply_damping(t);
+
+        if self.gyroscopic_torque {
+            target.apply_gyroscopic_torque(t);
+        }
+
+        let position = target.translation().clone();
+        let velocity = target.velocity().clone();
+        let half_t = t / 2.0;
+
+        let velocity_1 = velocity;
+        let acceleration_1 = linear_acceleration(position, velocity_1, time);
+
+        let velocity_2 = velocity + acceleration_1 * half_t;
+        let acceleration_2 = linear_acceleration(position + velocity_1 * half_t, velocity_2, time + half_t);
+
+        let velocity_3 = velocity + acceleration_2 * half_t;
+        let acceleration_3 = linear_acceleration(position + velocity_2 * half_t, velocity_3, time + half_t);
+
+        let velocity_4 = velocity + acceleration_3 * t;
+        let acceleration_4 = linear_acceleration(position + velocity_3 * t, velocity_4, time + t);
+
+        *target.translation_mut() += (velocity_1 + velocity_2 * 2.0 + velocity_3 * 2.0 + velocity_4) * (t / 6.0);
+        *target.velocity_mut() += (acceleration_1 + acceleration_2 * 2.0 + acceleration_3 * 2.0 + acceleration_4) * (t / 6.0);
+
+        // the angular acceleration is constant, so the rotation follows the
+        // average angular velocity over the time step
+        let average_angular_velocity = target.angular_velocity() + angular_acceleration * half_t;
+        target.rotate_by(average_angular_velocity, t);
+        *target.angular_velocity_mut() += angular_acceleration * t;
+    }
+}
@@ -3,26 +3,48 @@
 mod tests;
 
 use Scalar;
-use maths::{Quat, Vec3D};
+use maths::Vec3D;
 use dynamics::{Integratable, Integrator};
 
 /// An implementation of the Semi-Implicit Euler integration strategy.
-pub struct SemiImplicitEuler;
+pub struct SemiImplicitEuler {
+    gyroscopic_torque: bool,
+}
 
 impl SemiImplicitEuler {
     pub fn new() -> SemiImplicitEuler {
-        SemiImplicitEuler
+        SemiImplicitEuler {
+            gyroscopic_torque: false,
+        }
+    }
+
+    /// Includes the gyroscopic torque when integrating the angular velocity,
+    /// for targets which provide an inertia tensor. This allows bodies with
+    /// an uneven mass distribution to tumble while conserving their angular
+    /// momentum.
+    pub fn with_gyroscopic_torque(mut self) -> SemiImplicitEuler {
+        self.gyroscopic_torque = true;
+
+        self
     }
 }
 
 impl Integrator for SemiImplicitEuler {
-    fn integrate_in_place(&self, target: &mut Integratable, t: Scalar, linear_acceleration: Vec3D) {
-        *target.velocity_mut() += linear_acceleration * t;
-        *target.translation_mut() += target.velocity() * t;
+    fn integrate_with_acceleration(&self, target: &mut Integratable, time: Scalar, t: Scalar, linear_acceleration: &Fn(Vec3D, Vec3D, Scalar) -> Vec3D, angular_acceleration: Vec3D) {
+        target.apply_damping(t);
+
+        if self.gyroscopic_torque {
+            target.apply_gyroscopic_torque(t);
+        }
+
+        let acceleration = linear_acceleration(target.translation().clone(), target.velocity().clone(), time);
+        *target.velocity_mut() += acceleration * t;
+        *target.angular_velocity_mut() += angular_acceleration * t;
+
+        let velocity = target.velocity().clone();
+        *target.translation_mut() += velocity * t;
 
         let w = target.angular_velocity().clone();
-        let w_as_quat = Quat::new(0.0, w.x * t, w.y * t, w.z * t);
-        let q = Quat::from(target.rotation().clone());
-        *target.rotation_mut() = (q + w_as_quat * q * 0.5).normalize();
+        target.rotate_by(w, t);
     }
 }
@@ -0,0 +1,54 @@
+#[cfg(test)]
+#[path="../../../tests/dynamics/integrators/velocity_verlet_test.rs"]
+mod tests;
+
+use Scalar;
+use maths::Vec3D;
+use dynamics::{Integratable, Integrator};
+
+/// An implementation of the Velocity Verlet integration strategy. The
+/// strategy is symplectic, which keeps the energy of orbits and springs
+/// bounded over long simulations.
+pub struct VelocityVerlet {
+    gyroscopic_torque: bool,
+}
+
+impl VelocityVerlet {
+    pub fn new() -> VelocityVerlet {
+        VelocityVerlet {
+            gyroscopic_torque: false,
+        }
+    }
+
+    /// Includes the gyroscopic torque at the start of each step, as
+    /// `SemiImplicitEuler::with_gyroscopic_torque` does.
+    pub fn with_gyroscopic_torque(mut self) -> VelocityVerlet {
+        self.gyroscopic_torque = true;
+
+        self
+    }
+}
+
+impl Integrator for VelocityVerlet {
+    fn integrate_with_acceleration(&self, target: &mut Integratable, time: Scalar, t: Scalar, linear_acceleration: &Fn(Vec3D, Vec3D, Scalar) -> Vec3D, angular_acceleration: Vec3D) {
+        target.apply_damping(t);
+
+        if self.gyroscopic_torque {
+            target.apply_gyroscopic_torque(t);
+        }
+
+        let velocity = target.velocity().clone();
+        let acceleration = linear_acceleration(target.translation().clone(), velocity, time);
+
+        *target.translation_mut() += velocity * t + acceleration * (0.5 * t * t);
+
+        // the velocity at the end of the step is not yet known, so it is
+        // estimated with an explicit step for the new acceleration
+        let next_acceleration = linear_acceleration(target.translation().clone(), velocity + acceleration * t, time + t);
+        *target.velocity_mut() += (acceleration + next_acceleration) * (0.5 * t);
+
+        let average_angular_velocity = target.angular_velocity() + angular_acceleration * (0.5 * t);
+        target.rotate_by(average_angular_velocity, t);
+        *target.angular_velocity_mut() += angular_acceleration * t;
+    }
+}
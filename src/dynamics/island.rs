@@ -0,0 +1,52 @@
+#[cfg(test)]
+#[path="../../tests/dynamics/island_test.rs"]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use ID;
+use utils::UnionFind;
+use dynamics::{DynamicBody, RigidBodyRef};
+use collisions::Contact;
+
+/// A group of contacts connected through the rigid bodies they share, which
+/// can be solved independently of the contacts in other islands. Fixed and
+/// kinematic bodies are not moved by their contacts, so they do not connect
+/// the contacts they take part in.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Island {
+    contact_indices: Vec<usize>,
+}
+
+impl Island {
+    /// Partitions the contacts into islands, ordered by their first contact.
+    pub fn partition<T>(contacts: &Vec<Contact<T>>) -> Vec<Island> where T: DynamicBody {
+        let mut union_find = UnionFind::new(contacts.len());
+        let mut first_contacts: BTreeMap<ID, usize> = BTreeMap::new();
+
+        for (contact_index, contact) in contacts.iter().enumerate() {
+            let handles = contact.handles();
+
+            for handle in vec!(&handles.0, &handles.1) {
+                let body = handle.borrow();
+
+                if RigidBodyRef::try_from(&*body).is_none() {
+                    continue;
+                }
+
+                let first_contact = *first_contacts.entry(body.id()).or_insert(contact_index);
+                union_find.union(first_contact, contact_index);
+            }
+        }
+
+        return union_find.sets().into_iter()
+            .map(|contact_indices| Island { contact_indices: contact_indices })
+            .collect();
+    }
+
+    /// The indices of the contacts in the island, in ascending order.
+    #[inline(always)]
+    pub fn contact_indices(&self) -> &Vec<usize> {
+        &self.contact_indices
+    }
+}
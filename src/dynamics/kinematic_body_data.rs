@@ -0,0 +1,92 @@
+use Scalar;
+use maths::{Motion, Transform, Vec3D};
+use dynamics::{KinematicBodyDef, MaterialData};
+
+#[derive(Clone, Debug)]
+pub struct KinematicBodyData<E> {
+    motion: Motion,
+    next_transform: Option<Transform>,
+    material_data: MaterialData,
+    material: Option<String>,
+    is_sensor: bool,
+    extension_data: E,
+}
+
+impl<E> KinematicBodyData<E> {
+    include_motion_helpers! {
+        struct_signature: KinematicBodyData<E>,
+        struct_name: KinematicBodyData,
+    }
+
+    pub fn new(def: &KinematicBodyDef, extension: E) -> KinematicBodyData<E> {
+        KinematicBodyData {
+            motion: Motion {
+                velocity: def.velocity,
+                angular_velocity: def.angular_velocity,
+            },
+            next_transform: None,
+            material_data: MaterialData {
+                friction_coefficient: def.friction_coefficient,
+                restitution_coefficient: def.restitution_coefficient,
+                .. MaterialData::default()
+            },
+            material: def.material.clone(),
+            is_sensor: def.is_sensor,
+            extension_data: extension,
+        }
+    }
+
+    #[inline(always)]
+    pub fn friction_coefficient(&self) -> Scalar {
+        self.material_data.friction_coefficient
+    }
+
+    #[inline(always)]
+    pub fn restitution_coefficient(&self) -> Scalar {
+        self.material_data.restitution_coefficient
+    }
+
+    #[inline(always)]
+    pub fn material_data(&self) -> &MaterialData {
+        &self.material_data
+    }
+
+    /// The name of the registered material used by the body, if any.
+    #[inline(always)]
+    pub fn material(&self) -> Option<&str> {
+        self.material.as_ref().map(|name| name.as_str())
+    }
+
+    /// The transform the body should move to during the next update, if any.
+    #[inline(always)]
+    pub fn next_transform(&self) -> Option<&Transform> {
+        self.next_transform.as_ref()
+    }
+
+    #[inline(always)]
+    pub fn set_next_transform(&mut self, transform: Transform) {
+        self.next_transform = Some(transform);
+    }
+
+    #[inline(always)]
+    pub fn take_next_transform(&mut self) -> Option<Transform> {
+        self.next_transform.take()
+    }
+
+    /// Returns true if the body only reports overlaps, without producing a
+    /// response from the constraint solver.
+    #[inline(always)]
+    pub fn is_sensor(&self) -> bool {
+        self.is_sensor
+    }
+
+    #[inline(always)]
+    pub fn extension_data(&self) -> &E {
+        &self.extension_data
+    }
+
+    #[inline(always)]
+    pub fn extension_data_mut(&mut self) -> &mut E {
+        &mut self.extension_data
+    }
+}
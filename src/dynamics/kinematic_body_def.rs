@@ -0,0 +1,41 @@
+use Scalar;
+use maths::{UnitQuat, Vec3D};
+use dynamics::MaterialData;
+use collisions::CollisionGroup;
+use collisions::shapes::convex_shapes::{Cuboid, ConvexShape};
+
+pub struct KinematicBodyDef {
+    pub group: CollisionGroup,
+    pub shape: Box<ConvexShape>,
+    pub rotation: UnitQuat,
+    /// The velocity of the center of mass of the shape.
+    pub velocity: Vec3D,
+    pub translation: Vec3D,
+    pub angular_velocity: Vec3D,
+    pub friction_coefficient: Scalar,
+    pub restitution_coefficient: Scalar,
+    /// The name of a material in the `MaterialRegistry` of the world. When
+    /// set, the registered material is used instead of the coefficients,
+    /// which remain in use while no material is registered under the name.
+    pub material: Option<String>,
+    pub is_sensor: bool,
+}
+
+impl Default for KinematicBodyDef {
+    fn default() -> KinematicBodyDef {
+        let material_defaults = MaterialData::default();
+
+        KinematicBodyDef {
+            group: CollisionGroup::Environment,
+            shape: Box::new(Cuboid::cube(1.0)),
+            rotation: UnitQuat::identity(),
+            velocity: Vec3D::zero(),
+            translation: Vec3D::zero(),
+            angular_velocity: Vec3D::zero(),
+            friction_coefficient: material_defaults.friction_coefficient,
+            restitution_coefficient: material_defaults.restitution_coefficient,
+            material: None,
+            is_sensor: false,
+        }
+    }
+}
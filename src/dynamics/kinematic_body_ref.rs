@@ -0,0 +1,152 @@
+use Scalar;
+use maths::{Transform, UnitQuat, Vec3D};
+use collisions::BodyData;
+use dynamics::{DynamicBody, DynamicBodyRef, DynamicBodyRefMut, Integratable, Integrator, KinematicBodyData};
+
+pub struct KinematicBodyRef<'a, T>(&'a BodyData<T::Narrowphase>, &'a KinematicBodyData<<T as DynamicBody>::Extension>) where T: DynamicBody;
+pub struct KinematicBodyRefMut<'a, T>(&'a mut BodyData<T::Narrowphase>, &'a mut KinematicBodyData<<T as DynamicBody>::Extension>) where T: DynamicBody;
+
+impl<'a, T> KinematicBodyRef<'a, T> where T: DynamicBody {
+    pub fn new(body_data: &'a BodyData<T::Narrowphase>, kinematic_body_data: &'a KinematicBodyData<<T as DynamicBody>::Extension>) -> KinematicBodyRef<'a, T> {
+        KinematicBodyRef(body_data, kinematic_body_data)
+    }
+
+    pub fn try_from(body: &'a T) -> Option<KinematicBodyRef<'a, T>> {
+        let dynamic_body = DynamicBodyRef::from(body);
+
+        match dynamic_body {
+            DynamicBodyRef::Kinematic(kinematic_body_ref) => Some(kinematic_body_ref),
+
+            _otherwise => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn translation(&self) -> &Vec3D {
+        self.0.translation()
+    }
+
+    #[inline(always)]
+    pub fn rotation(&self) -> UnitQuat {
+        self.0.transform().rotation
+    }
+
+    /// The center of mass of the shape in world coordinates, which the body
+    /// rotates about.
+    pub fn center_of_mass(&self) -> Vec3D {
+        self.0.translation() + self.0.transform().rotation.rotate(self.0.shape().center_of_mass())
+    }
+
+    #[inline(always)]
+    pub fn velocity(&self) -> &Vec3D {
+        self.1.velocity()
+    }
+
+    #[inline(always)]
+    pub fn angular_velocity(&self) -> &Vec3D {
+        self.1.angular_velocity()
+    }
+
+    #[inline(always)]
+    pub fn friction_coefficient(&self) -> Scalar {
+        self.1.friction_coefficient()
+    }
+
+    #[inline(always)]
+    pub fn restitution_coefficient(&self) -> Scalar {
+        self.1.restitution_coefficient()
+    }
+}
+
+impl<'a, T> KinematicBodyRefMut<'a, T> where T: DynamicBody {
+    pub fn new(body_data: &'a mut BodyData<T::Narrowphase>, kinematic_body_data: &'a mut KinematicBodyData<<T as DynamicBody>::Extension>) -> KinematicBodyRefMut<'a, T> {
+        KinematicBodyRefMut(body_data, kinematic_body_data)
+    }
+
+    pub fn try_from(body: &'a mut T) -> Option<KinematicBodyRefMut<'a, T>> {
+        let dynamic_body = DynamicBodyRefMut::from(body);
+
+        match dynamic_body {
+            DynamicBodyRefMut::Kinematic(kinematic_body_ref) => Some(kinematic_body_ref),
+
+            _otherwise => None,
+        }
+    }
+
+    #[inline(always)]
+    pub fn translation(&self) -> &Vec3D {
+        self.0.translation()
+    }
+
+    #[inline(always)]
+    pub fn rotation(&self) -> UnitQuat {
+        self.0.transform().rotation
+    }
+
+    /// The center of mass of the shape in world coordinates, which the body
+    /// rotates about.
+    pub fn center_of_mass(&self) -> Vec3D {
+        self.0.translation() + self.0.transform().rotation.rotate(self.0.shape().center_of_mass())
+    }
+
+    #[inline(always)]
+    pub fn velocity(&self) -> &Vec3D {
+        self.1.velocity()
+    }
+
+    #[inline(always)]
+    pub fn velocity_mut(&mut self) -> &mut Vec3D {
+        self.1.velocity_mut()
+    }
+
+    #[inline(always)]
+    pub fn angular_velocity(&self) -> &Vec3D {
+        self.1.angular_velocity()
+    }
+
+    #[inline(always)]
+    pub fn angular_velocity_mut(&mut self) -> &mut Vec3D {
+        self.1.angular_velocity_mut()
+    }
+
+    /// Moves the body to the transform provided during the next update. The
+    /// velocities of the body are derived from the change in transform, and
+    /// are kept for later updates unless another target is set.
+    pub fn set_next_transform(&mut self, transform: Transform) {
+        self.1.set_next_transform(transform);
+    }
+
+    /// Replaces the target set with `set_next_transform`, if any, with the
+    /// velocities which move the body to it over the time step.
+    pub fn steer_towards_next_transform(&mut self, time_step: Scalar) {
+        if let Some(transform) = self.1.take_next_transform() {
+            let rotation_change = transform.rotation * self.0.transform().rotation.inverse();
+            let next_center_of_mass = transform.apply_to_point(self.0.shape().center_of_mass());
+
+            *self.1.velocity_mut() = (next_center_of_mass - self.center_of_mass()) / time_step;
+            *self.1.angular_velocity_mut() = rotation_change.rotation_vector() / time_step;
+        }
+    }
+
+    /// Advances the body by the time step, either towards the target set with
+    /// `set_next_transform` or using its current velocities, which move and
+    /// rotate the body about its center of mass. Kinematic bodies are not
+    /// affected by forces or contacts.
+    pub fn integrate<I>(&mut self, integrator: &I, time_step: Scalar) where I: Integrator {
+        self.steer_towards_next_transform(time_step);
+
+        let local_center_of_mass = self.0.shape().center_of_mass();
+        let offset = self.0.transform().rotation.rotate(local_center_of_mass);
+        *self.0.translation_mut() += offset;
+
+        {
+            let mut integratable = Integratable::new(self.0.transform_mut(), self.1.motion_mut());
+
+            integrator.integrate_in_place(&mut integratable, time_step, Vec3D::zero(), Vec3D::zero());
+        }
+
+        let rotation = self.0.transform().rotation;
+        let translation = self.0.translation() - rotation.rotate(local_center_of_mass);
+        *self.0.translation_mut() = translation;
+    }
+}
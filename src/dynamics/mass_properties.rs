@@ -0,0 +1,41 @@
+#[cfg(test)]
+#[path="../../tests/dynamics/mass_properties_test.rs"]
+mod tests;
+
+use Scalar;
+use maths::{Matrix, Vec3D};
+use collisions::shapes::convex_shapes::ConvexShape;
+
+/// The mass distribution of a body, in the local coordinates of its shape.
+#[derive(Clone, Copy, Debug)]
+pub struct MassProperties {
+    pub mass: Scalar,
+    pub center_of_mass: Vec3D,
+    pub inertia: Matrix,
+}
+
+impl MassProperties {
+    /// Computes the mass properties for the shape with the given total mass.
+    pub fn from_mass(shape: &ConvexShape, mass: Scalar) -> MassProperties {
+        MassProperties {
+            mass: mass,
+            center_of_mass: shape.center_of_mass(),
+            inertia: shape.inertia() * mass,
+        }
+    }
+
+    /// Computes the mass properties for the shape with a uniform density.
+    pub fn from_density(shape: &ConvexShape, density: Scalar) -> MassProperties {
+        MassProperties::from_mass(shape, density * shape.volume())
+    }
+
+    #[inline(always)]
+    pub fn mass_inverse(&self) -> Scalar {
+        1.0 / self.mass
+    }
+
+    #[inline(always)]
+    pub fn inertia_inverse(&self) -> Matrix {
+        self.inertia.inverse()
+    }
+}
@@ -1,9 +1,40 @@
 use Scalar;
+use dynamics::{CombineMode, ContactMaterial};
 
 #[derive(Clone, Debug)]
 pub struct MaterialData {
     pub friction_coefficient: Scalar,
     pub restitution_coefficient: Scalar,
+    /// The lever arm of the torque resisting rolling, as a multiple of the
+    /// normal force.
+    pub rolling_friction: Scalar,
+    /// The lever arm of the torque resisting spinning about the contact
+    /// normal, as a multiple of the normal force.
+    pub torsional_friction: Scalar,
+    pub friction_combine: CombineMode,
+    pub restitution_combine: CombineMode,
+    /// Defaults to averaging, so that a body with rolling friction is still
+    /// slowed down by a surface without any.
+    pub rolling_friction_combine: CombineMode,
+    /// Defaults to averaging, as for the rolling friction.
+    pub torsional_friction_combine: CombineMode,
+}
+
+impl MaterialData {
+    /// Computes the coefficients used when the materials are in contact.
+    pub fn combine(&self, other: &MaterialData) -> ContactMaterial {
+        let friction_combine = self.friction_combine.dominant(other.friction_combine);
+        let restitution_combine = self.restitution_combine.dominant(other.restitution_combine);
+        let rolling_friction_combine = self.rolling_friction_combine.dominant(other.rolling_friction_combine);
+        let torsional_friction_combine = self.torsional_friction_combine.dominant(other.torsional_friction_combine);
+
+        ContactMaterial {
+            friction_coefficient: friction_combine.combine(self.friction_coefficient, other.friction_coefficient),
+            restitution_coefficient: restitution_combine.combine(self.restitution_coefficient, other.restitution_coefficient),
+            rolling_friction: rolling_friction_combine.combine(self.rolling_friction, other.rolling_friction),
+            torsional_friction: torsional_friction_combine.combine(self.torsional_friction, other.torsional_friction),
+        }
+    }
 }
 
 impl Default for MaterialData {
@@ -11,6 +42,12 @@ impl Default for MaterialData {
         MaterialData {
             friction_coefficient: 0.7,
             restitution_coefficient: 0.7,
+            rolling_friction: 0.0,
+            torsional_friction: 0.0,
+            friction_combine: CombineMode::Multiply,
+            restitution_combine: CombineMode::Multiply,
+            rolling_friction_combine: CombineMode::Average,
+            torsional_friction_combine: CombineMode::Average,
         }
     }
 }
@@ -0,0 +1,76 @@
+#[cfg(test)]
+#[path="../../tests/dynamics/material_registry_test.rs"]
+mod tests;
+
+use std::collections::HashMap;
+
+use utils::ordered_pair;
+use dynamics::{ContactMaterial, DynamicBodyExtension, MaterialData};
+
+/// Holds the named materials which bodies can refer to, along with the
+/// coefficients to use in place of the combined coefficients for specific
+/// pairs of materials (e.g. ice on rubber).
+pub struct MaterialRegistry {
+    materials: HashMap<String, MaterialData>,
+    pair_overrides: HashMap<(String, String), ContactMaterial>,
+}
+
+impl MaterialRegistry {
+    /// Creates a new `MaterialRegistry` without any materials.
+    pub fn new() -> MaterialRegistry {
+        MaterialRegistry {
+            materials: HashMap::new(),
+            pair_overrides: HashMap::new(),
+        }
+    }
+
+    /// Adds the material under the name, replacing any material previously
+    /// registered with the same name. Bodies referring to the name use the
+    /// new material from the next update.
+    pub fn register(&mut self, name: &str, material: MaterialData) {
+        self.materials.insert(name.to_string(), material);
+    }
+
+    /// Returns the material registered under the name, if any.
+    pub fn material(&self, name: &str) -> Option<&MaterialData> {
+        self.materials.get(name)
+    }
+
+    /// Uses the coefficients for contacts between the two named materials
+    /// instead of combining them.
+    pub fn override_pair(&mut self, name_0: &str, name_1: &str, material: ContactMaterial) {
+        self.pair_overrides.insert(ordered_pair(name_0.to_string(), name_1.to_string()), material);
+    }
+
+    /// Combines the two named materials again after they were overridden.
+    pub fn restore_pair(&mut self, name_0: &str, name_1: &str) {
+        self.pair_overrides.remove(&ordered_pair(name_0.to_string(), name_1.to_string()));
+    }
+
+    /// Returns the coefficients overriding the pair of named materials, if
+    /// any.
+    pub fn pair_override(&self, name_0: &str, name_1: &str) -> Option<&ContactMaterial> {
+        self.pair_overrides.get(&ordered_pair(name_0.to_string(), name_1.to_string()))
+    }
+
+    /// Computes the coefficients used to resolve a contact between the
+    /// bodies.
+    pub fn contact_material_between<E>(&self, body_0: &DynamicBodyExtension<E>, body_1: &DynamicBodyExtension<E>) -> ContactMaterial {
+        if let (Some(name_0), Some(name_1)) = (body_0.material(), body_1.material()) {
+            if let Some(&material) = self.pair_override(name_0, name_1) {
+                return material;
+            }
+        }
+
+        self.material_of(body_0).combine(self.material_of(body_1))
+    }
+
+    /// Returns the material registered under the name of the material of the
+    /// body, falling back to the coefficients of the body when the body has
+    /// no material or no material is registered under its name.
+    fn material_of<'a, E>(&'a self, body: &'a DynamicBodyExtension<E>) -> &'a MaterialData {
+        body.material()
+            .and_then(|name| self.material(name))
+            .unwrap_or_else(|| body.material_data())
+    }
+}
@@ -1,26 +1,48 @@
 mod dynamic_body;
+mod contact_event;
+mod combine_mode;
 mod material_data;
+mod contact_material;
+mod material_registry;
+mod mass_properties;
 mod fixed_body_def;
 mod rigid_body_def;
+mod kinematic_body_def;
 mod fixed_body_ref;
 mod rigid_body_ref;
+mod kinematic_body_ref;
 mod fixed_body_data;
 mod rigid_body_data;
+mod kinematic_body_data;
 mod dynamic_body_ref;
 mod dynamic_body_extension;
+mod contact_event_tracker;
+mod island;
 
 pub mod solvers;
 pub mod integrators;
+pub mod force_fields;
 
-pub use self::solvers::ConstraintSolver;
+pub use self::solvers::{ConstraintSolver, PositionCorrection, SolverConfig, SolverStats};
 pub use self::integrators::{Integratable, Integrator};
+pub use self::force_fields::{ForceField, ForceFieldSample};
 pub use self::dynamic_body::DynamicBody;
+pub use self::combine_mode::CombineMode;
 pub use self::material_data::MaterialData;
+pub use self::contact_material::ContactMaterial;
+pub use self::material_registry::MaterialRegistry;
+pub use self::mass_properties::MassProperties;
 pub use self::fixed_body_def::FixedBodyDef;
 pub use self::rigid_body_def::RigidBodyDef;
+pub use self::kinematic_body_def::KinematicBodyDef;
 pub use self::fixed_body_ref::{FixedBodyRef, FixedBodyRefMut};
 pub use self::rigid_body_ref::{RigidBodyRef, RigidBodyRefMut};
+pub use self::kinematic_body_ref::{KinematicBodyRef, KinematicBodyRefMut};
 pub use self::fixed_body_data::FixedBodyData;
 pub use self::rigid_body_data::RigidBodyData;
+pub use self::kinematic_body_data::KinematicBodyData;
 pub use self::dynamic_body_ref::{DynamicBodyRef, DynamicBodyRefMut};
 pub use self::dynamic_body_extension::DynamicBodyExtension;
+pub use self::contact_event::{ContactEvent, ContactEventKind};
+pub use self::contact_event_tracker::ContactEventTracker;
+pub use self::island::Island;
@@ -1,12 +1,21 @@
 use Scalar;
-use maths::{Motion, Vec3D};
-use dynamics::{MaterialData, RigidBodyDef};
+use maths::{Matrix, Motion, UnitQuat, Vec3D};
+use dynamics::{MassProperties, MaterialData, RigidBodyDef};
 
 #[derive(Clone, Debug)]
 pub struct RigidBodyData<E> {
-    mass: Scalar,
+    mass_properties: MassProperties,
+    world_inertia: Matrix,
+    world_inertia_inverse: Matrix,
     motion: Motion,
+    force: Vec3D,
+    torque: Vec3D,
     material_data: MaterialData,
+    material: Option<String>,
+    linear_damping: Scalar,
+    angular_damping: Scalar,
+    gravity_scale: Scalar,
+    is_sensor: bool,
     extension_data: E,
 }
 
@@ -17,18 +26,37 @@ impl<E> RigidBodyData<E> {
     }
 
     pub fn new(def: &RigidBodyDef, extension: E) -> RigidBodyData<E> {
-        RigidBodyData {
-            mass: def.mass,
+        let mass_properties = match def.density {
+            Some(density) => MassProperties::from_density(&*def.shape, density),
+            None => MassProperties::from_mass(&*def.shape, def.mass),
+        };
+
+        let mut rigid_body_data = RigidBodyData {
+            mass_properties: mass_properties,
+            world_inertia: mass_properties.inertia,
+            world_inertia_inverse: mass_properties.inertia_inverse(),
             motion: Motion {
                 velocity: def.velocity,
                 angular_velocity: def.angular_velocity,
             },
+            force: Vec3D::zero(),
+            torque: Vec3D::zero(),
             material_data: MaterialData {
                 friction_coefficient: def.friction_coefficient,
                 restitution_coefficient: def.restitution_coefficient,
+                .. MaterialData::default()
             },
+            material: def.material.clone(),
+            linear_damping: def.linear_damping.unwrap_or(0.0),
+            angular_damping: def.angular_damping.unwrap_or(0.0),
+            gravity_scale: def.gravity_scale,
+            is_sensor: def.is_sensor,
             extension_data: extension,
-        }
+        };
+
+        rigid_body_data.update_world_inertia(def.rotation);
+
+        return rigid_body_data;
     }
 
     #[inline(always)]
@@ -41,14 +69,122 @@ impl<E> RigidBodyData<E> {
         self.material_data.restitution_coefficient
     }
 
+    #[inline(always)]
+    pub fn material_data(&self) -> &MaterialData {
+        &self.material_data
+    }
+
+    /// The name of the registered material used by the body, if any.
+    #[inline(always)]
+    pub fn material(&self) -> Option<&str> {
+        self.material.as_ref().map(|name| name.as_str())
+    }
+
+    #[inline(always)]
+    pub fn linear_damping(&self) -> Scalar {
+        self.linear_damping
+    }
+
+    #[inline(always)]
+    pub fn angular_damping(&self) -> Scalar {
+        self.angular_damping
+    }
+
+    /// The factor applied to the gravity of the world for this body.
+    #[inline(always)]
+    pub fn gravity_scale(&self) -> Scalar {
+        self.gravity_scale
+    }
+
+    #[inline(always)]
+    pub fn gravity_scale_mut(&mut self) -> &mut Scalar {
+        &mut self.gravity_scale
+    }
+
+    /// The total force applied to the body since the accumulators were last
+    /// cleared.
+    #[inline(always)]
+    pub fn force(&self) -> &Vec3D {
+        &self.force
+    }
+
+    #[inline(always)]
+    pub fn force_mut(&mut self) -> &mut Vec3D {
+        &mut self.force
+    }
+
+    /// The total torque applied to the body since the accumulators were last
+    /// cleared.
+    #[inline(always)]
+    pub fn torque(&self) -> &Vec3D {
+        &self.torque
+    }
+
+    #[inline(always)]
+    pub fn torque_mut(&mut self) -> &mut Vec3D {
+        &mut self.torque
+    }
+
+    pub fn clear_accumulators(&mut self) {
+        self.force = Vec3D::zero();
+        self.torque = Vec3D::zero();
+    }
+
+    /// Returns true if the body only reports overlaps, without producing a
+    /// response from the constraint solver.
+    #[inline(always)]
+    pub fn is_sensor(&self) -> bool {
+        self.is_sensor
+    }
+
+    #[inline(always)]
+    pub fn mass_properties(&self) -> &MassProperties {
+        &self.mass_properties
+    }
+
     #[inline(always)]
     pub fn mass(&self) -> Scalar {
-        self.mass
+        self.mass_properties.mass
     }
 
     #[inline(always)]
     pub fn mass_inverse(&self) -> Scalar {
-        1.0 / self.mass
+        self.mass_properties.mass_inverse()
+    }
+
+    /// Returns the center of mass in the local coordinates of the shape.
+    #[inline(always)]
+    pub fn center_of_mass(&self) -> Vec3D {
+        self.mass_properties.center_of_mass
+    }
+
+    /// Returns the inertia tensor about the center of mass in the local
+    /// coordinates of the shape.
+    #[inline(always)]
+    pub fn inertia(&self) -> Matrix {
+        self.mass_properties.inertia
+    }
+
+    /// Returns the inertia tensor about the center of mass in world
+    /// coordinates, as of the last call to `update_world_inertia`.
+    #[inline(always)]
+    pub fn world_inertia(&self) -> Matrix {
+        self.world_inertia
+    }
+
+    /// Returns the inverse of the world inertia tensor, as of the last call to
+    /// `update_world_inertia`.
+    #[inline(always)]
+    pub fn world_inertia_inverse(&self) -> Matrix {
+        self.world_inertia_inverse
+    }
+
+    /// Recomputes the cached world inertia tensors for the rotation provided.
+    pub fn update_world_inertia(&mut self, rotation: UnitQuat) {
+        let orientation = Matrix::from(rotation);
+
+        self.world_inertia = orientation * self.mass_properties.inertia * orientation.transpose();
+        self.world_inertia_inverse = orientation * self.mass_properties.inertia_inverse() * orientation.transpose();
     }
 
     #[inline(always)]
@@ -6,6 +6,9 @@ use collisions::shapes::convex_shapes::{Cuboid, ConvexShape};
 
 pub struct RigidBodyDef {
     pub mass: Scalar,
+    /// When set, the mass is computed from the density and the volume of the
+    /// shape instead.
+    pub density: Option<Scalar>,
     pub group: CollisionGroup,
     pub shape: Box<ConvexShape>,
     pub rotation: UnitQuat,
@@ -14,6 +17,20 @@ pub struct RigidBodyDef {
     pub angular_velocity: Vec3D,
     pub friction_coefficient: Scalar,
     pub restitution_coefficient: Scalar,
+    /// The name of a material in the `MaterialRegistry` of the world. When
+    /// set, the registered material is used instead of the coefficients,
+    /// which remain in use while no material is registered under the name.
+    pub material: Option<String>,
+    pub is_sensor: bool,
+    /// The rate at which the velocity decays. When unset, the default damping
+    /// of the world is used.
+    pub linear_damping: Option<Scalar>,
+    /// The rate at which the angular velocity decays. When unset, the default
+    /// damping of the world is used.
+    pub angular_damping: Option<Scalar>,
+    /// The factor applied to the gravity of the world for this body, e.g.
+    /// zero for a body which floats.
+    pub gravity_scale: Scalar,
 }
 
 impl Default for RigidBodyDef {
@@ -22,6 +39,7 @@ impl Default for RigidBodyDef {
 
         RigidBodyDef {
             mass: 1.0,
+            density: None,
             group: CollisionGroup::Default,
             shape: Box::new(Cuboid::cube(1.0)),
             rotation: UnitQuat::identity(),
@@ -30,6 +48,11 @@ impl Default for RigidBodyDef {
             angular_velocity: Vec3D::zero(),
             friction_coefficient: material_defaults.friction_coefficient,
             restitution_coefficient: material_defaults.restitution_coefficient,
+            material: None,
+            is_sensor: false,
+            linear_damping: None,
+            angular_damping: None,
+            gravity_scale: 1.0,
         }
     }
 }
@@ -1,7 +1,7 @@
 use Scalar;
-use maths::{Matrix, Vec3D};
+use maths::{CrossProduct, Matrix, UnitQuat, Vec3D};
 use collisions::BodyData;
-use dynamics::{DynamicBody, DynamicBodyRef, DynamicBodyRefMut, Integratable, RigidBodyData};
+use dynamics::{DynamicBody, DynamicBodyRef, DynamicBodyRefMut, Integratable, Integrator, RigidBodyData};
 
 pub struct RigidBodyRef<'a, T>(&'a BodyData<T::Narrowphase>, &'a RigidBodyData<<T as DynamicBody>::Extension>) where T: DynamicBody;
 pub struct RigidBodyRefMut<'a, T>(&'a mut BodyData<T::Narrowphase>, &'a mut RigidBodyData<<T as DynamicBody>::Extension>) where T: DynamicBody;
@@ -26,6 +26,11 @@ impl<'a, T> RigidBodyRef<'a, T> where T: DynamicBody {
         self.0.translation()
     }
 
+    /// Returns the center of mass of the body in world coordinates.
+    pub fn center_of_mass(&self) -> Vec3D {
+        self.0.translation() + self.0.transform().rotation.rotate(self.1.center_of_mass())
+    }
+
     #[inline(always)]
     pub fn friction_coefficient(&self) -> Scalar {
         self.1.friction_coefficient()
@@ -46,17 +51,46 @@ impl<'a, T> RigidBodyRef<'a, T> where T: DynamicBody {
         self.1.angular_velocity()
     }
 
+    #[inline(always)]
+    pub fn linear_damping(&self) -> Scalar {
+        self.1.linear_damping()
+    }
+
+    #[inline(always)]
+    pub fn angular_damping(&self) -> Scalar {
+        self.1.angular_damping()
+    }
+
+    #[inline(always)]
+    pub fn gravity_scale(&self) -> Scalar {
+        self.1.gravity_scale()
+    }
+
+    #[inline(always)]
+    pub fn force(&self) -> &Vec3D {
+        self.1.force()
+    }
+
+    #[inline(always)]
+    pub fn torque(&self) -> &Vec3D {
+        self.1.torque()
+    }
+
     #[inline(always)]
     pub fn mass_inverse(&self) -> Scalar {
         self.1.mass_inverse()
     }
 
+    /// Returns the inertia tensor about the center of mass in world
+    /// coordinates.
+    #[inline(always)]
     pub fn inertia(&self) -> Matrix {
-        self.0.shape().inertia() * self.mass()
+        self.1.world_inertia()
     }
 
+    #[inline(always)]
     pub fn inertia_inverse(&self) -> Matrix {
-        self.inertia().inverse()
+        self.1.world_inertia_inverse()
     }
 
     #[inline(always)]
@@ -81,7 +115,30 @@ impl<'a, T> RigidBodyRefMut<'a, T> where T: DynamicBody {
     }
 
     pub fn integratable<'b>(&'b mut self) -> Integratable<'b> {
+        let inertia = self.1.inertia();
+        let linear_damping = self.1.linear_damping();
+        let angular_damping = self.1.angular_damping();
+
         Integratable::new(self.0.transform_mut(), self.1.motion_mut())
+            .with_inertia(inertia)
+            .with_damping(linear_damping, angular_damping)
+    }
+
+    /// Integrates the motion of the body about its center of mass, keeping the
+    /// shape offset from the center of mass consistent with the new rotation.
+    /// The cached world inertia tensors are refreshed afterwards.
+    pub fn integrate<I>(&mut self, integrator: &I, time_step: Scalar, linear_acceleration: Vec3D, angular_acceleration: Vec3D) where I: Integrator {
+        let local_center_of_mass = self.1.center_of_mass();
+        let offset = self.0.transform().rotation.rotate(local_center_of_mass);
+        *self.0.translation_mut() += offset;
+
+        integrator.integrate_in_place(&mut self.integratable(), time_step, linear_acceleration, angular_acceleration);
+
+        let rotation = self.0.transform().rotation;
+        let translation = self.0.translation() - rotation.rotate(local_center_of_mass);
+        *self.0.translation_mut() = translation;
+
+        self.1.update_world_inertia(rotation);
     }
 
     #[inline(always)]
@@ -89,21 +146,58 @@ impl<'a, T> RigidBodyRefMut<'a, T> where T: DynamicBody {
         self.0.translation()
     }
 
+    /// Returns the center of mass of the body in world coordinates.
+    pub fn center_of_mass(&self) -> Vec3D {
+        self.0.translation() + self.0.transform().rotation.rotate(self.1.center_of_mass())
+    }
+
     #[inline(always)]
     pub fn translation_mut(&mut self) -> &mut Vec3D {
         self.0.translation_mut()
     }
 
+    /// Moves the body by the translation and rotates it about its center of
+    /// mass by the rotation vector, without changing its velocities.
+    pub fn displace(&mut self, translation: Vec3D, rotation_vector: Vec3D) {
+        let local_center_of_mass = self.1.center_of_mass();
+        let center_of_mass = self.center_of_mass() + translation;
+        let rotation = UnitQuat::from_rotation_vector(rotation_vector) * self.0.transform().rotation;
+
+        self.0.transform_mut().rotation = rotation;
+        *self.0.translation_mut() = center_of_mass - rotation.rotate(local_center_of_mass);
+        self.1.update_world_inertia(rotation);
+    }
+
+    #[inline(always)]
+    pub fn velocity(&self) -> &Vec3D {
+        self.1.velocity()
+    }
+
     #[inline(always)]
     pub fn velocity_mut(&mut self) -> &mut Vec3D {
         self.1.velocity_mut()
     }
 
+    #[inline(always)]
+    pub fn angular_velocity(&self) -> &Vec3D {
+        self.1.angular_velocity()
+    }
+
     #[inline(always)]
     pub fn angular_velocity_mut(&mut self) -> &mut Vec3D {
         self.1.angular_velocity_mut()
     }
 
+    #[inline(always)]
+    pub fn gravity_scale(&self) -> Scalar {
+        self.1.gravity_scale()
+    }
+
+    #[inline(always)]
+    pub fn gravity_scale_mut(&mut self) -> &mut Scalar {
+        self.1.gravity_scale_mut()
+    }
+
     #[inline(always)]
     pub fn friction_coefficient(&self) -> Scalar {
         self.1.friction_coefficient()
@@ -114,11 +208,74 @@ impl<'a, T> RigidBodyRefMut<'a, T> where T: DynamicBody {
         self.1.mass()
     }
 
+    #[inline(always)]
+    pub fn mass_inverse(&self) -> Scalar {
+        self.1.mass_inverse()
+    }
+
+    /// Returns the inertia tensor about the center of mass in world
+    /// coordinates.
+    #[inline(always)]
     pub fn inertia(&self) -> Matrix {
-        self.0.shape().inertia() * self.mass()
+        self.1.world_inertia()
     }
 
+    #[inline(always)]
     pub fn inertia_inverse(&self) -> Matrix {
-        self.inertia().inverse()
+        self.1.world_inertia_inverse()
+    }
+
+    #[inline(always)]
+    pub fn force(&self) -> &Vec3D {
+        self.1.force()
+    }
+
+    #[inline(always)]
+    pub fn torque(&self) -> &Vec3D {
+        self.1.torque()
+    }
+
+    /// Applies a force through the center of mass of the body. The force acts
+    /// on the body during the next update.
+    pub fn apply_force(&mut self, force: Vec3D) {
+        *self.1.force_mut() += force;
+    }
+
+    /// Applies a force at a point in world coordinates, generating a torque
+    /// if the point is not the center of mass.
+    pub fn apply_force_at_point(&mut self, force: Vec3D, point: Vec3D) {
+        let torque = (point - self.center_of_mass()).cross(force);
+
+        *self.1.force_mut() += force;
+        *self.1.torque_mut() += torque;
+    }
+
+    /// Applies a torque to the body. The torque acts on the body during the
+    /// next update.
+    pub fn apply_torque(&mut self, torque: Vec3D) {
+        *self.1.torque_mut() += torque;
+    }
+
+    /// Applies an impulse through the center of mass of the body, immediately
+    /// changing its velocity.
+    pub fn apply_impulse(&mut self, impulse: Vec3D) {
+        let velocity_change = impulse * self.mass_inverse();
+
+        *self.velocity_mut() += velocity_change;
+    }
+
+    /// Applies an impulse at a point in world coordinates, immediately
+    /// changing both the velocity and angular velocity of the body.
+    pub fn apply_impulse_at_point(&mut self, impulse: Vec3D, point: Vec3D) {
+        let velocity_change = impulse * self.mass_inverse();
+        let angular_velocity_change = self.inertia_inverse() * (point - self.center_of_mass()).cross(impulse);
+
+        *self.velocity_mut() += velocity_change;
+        *self.angular_velocity_mut() += angular_velocity_change;
+    }
+
+    /// Clears the force and torque accumulators.
+    pub fn clear_accumulators(&mut self) {
+        self.1.clear_accumulators();
     }
 }
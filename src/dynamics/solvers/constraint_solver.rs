@@ -1,7 +1,11 @@
 use Scalar;
-use dynamics::{DynamicBody, Integrator};
+use dynamics::{DynamicBody, Integrator, MaterialRegistry, SolverConfig, SolverStats};
 use collisions::Contact;
 
 pub trait ConstraintSolver<I, T> where I: Integrator, T: DynamicBody {
-    fn solve_with_contacts(&mut self, contacts: &Vec<Contact<T>>, integrator: &I, time_step: Scalar);
+    /// Resolves the contacts, and returns the total impulse applied along the
+    /// contact normal for each contact, in the same order as the contacts.
+    /// The friction and restitution of each contact are looked up in the
+    /// materials. How the solver converged is recorded in the statistics.
+    fn solve_with_contacts(&mut self, contacts: &Vec<Contact<T>>, integrator: &I, time_step: Scalar, config: &SolverConfig, materials: &MaterialRegistry, stats: &mut SolverStats) -> Vec<Scalar>;
 }
@@ -1,298 +1,555 @@
-use Scalar;
-use maths::{lcp_solvers, CrossProduct, DotProduct, LCP, LCPSolver, Matrix, UnitVec3D, Vec3D};
-use utils::UnitVec3DGenerator;
-use dynamics::{ConstraintSolver, DynamicBody, DynamicBodyRef, DynamicBodyRefMut, FixedBodyRef, Integrator, RigidBodyRef, RigidBodyRefMut};
+#[cfg(test)]
+#[path="../../../tests/dynamics/solvers/mach_constraint_solver_test.rs"]
+mod tests;
+
+use std::collections::BTreeMap;
+
+use {ID, INFINITY, MachError, Scalar, TOLERANCE};
+use maths::{lcp_solvers, CrossProduct, DotProduct, LCP, LCPBounds, LCPSolver, Matrix, UnitVec3D, Vec3D};
+use utils::{Handle, UnitVec3DGenerator};
+use dynamics::{ConstraintSolver, DynamicBody, DynamicBodyRef, Integrator, Island, MaterialRegistry, PositionCorrection, RigidBodyRef, RigidBodyRefMut, SolverConfig, SolverStats};
 use collisions::Contact;
 
-static NUM_COMPONENTS: usize = 2;
+/// The rigid bodies taking part in the contacts, with the mass properties
+/// used to formulate the constraints.
+struct SolverBody<T> where T: DynamicBody {
+    handle: Handle<T>,
+    mass_inverse: Scalar,
+    inertia_inverse: Matrix,
+}
+
+/// The part of a constraint acting on a single rigid body.
+struct JacobianBlock {
+    body_index: usize,
+    linear: Vec3D,
+    angular: Vec3D,
+}
+
+/// A constraint on the relative velocity of two bodies along a direction at
+/// a contact point. Fixed and kinematic bodies do not contribute a block.
+struct ConstraintRow {
+    blocks: Vec<JacobianBlock>,
+    relative_velocity: Scalar,
+}
+
+/// A row resisting the relative motion of the bodies, with an impulse limited
+/// to the coefficient times the normal impulse at the contact point.
+struct FrictionRow {
+    row: ConstraintRow,
+    coefficient: Scalar,
+}
+
+struct ContactPointConstraint {
+    contact_index: usize,
+    point: Vec3D,
+    normal: ConstraintRow,
+    frictions: Vec<FrictionRow>,
+    restitution_coefficient: Scalar,
+    penetration_depth: Scalar,
+}
+
+impl ContactPointConstraint {
+    /// The number of unknowns needed for the constraint in the velocity LCP.
+    fn size(&self) -> usize {
+        1 + self.frictions.len()
+    }
+}
+
+/// The impulses per unit time applied at a contact point in the previous
+/// step, used as the starting point when solving the next step.
+struct CachedImpulses {
+    point: Vec3D,
+    impulse_rates: Vec<Scalar>,
+}
+
+/// The distance a contact point may move between steps while still being
+/// started from the impulses of the previous step.
+const WARM_START_DISTANCE: Scalar = 0.05;
 
-pub struct MachConstraintSolver;
+/// Solves the contacts with projected Gauss-Seidel. The impulses of each
+/// contact point are carried over to the next step, so resting contacts start
+/// close to their solution and stacks settle within a few iterations.
+pub struct MachConstraintSolver {
+    impulse_cache: BTreeMap<(ID, ID), Vec<CachedImpulses>>,
+}
 
 impl MachConstraintSolver {
     pub fn new() -> MachConstraintSolver {
-        MachConstraintSolver
+        MachConstraintSolver {
+            impulse_cache: BTreeMap::new(),
+        }
     }
 
-    fn formulate_lcp<T>(contacts: &Vec<Contact<T>>, time_step: Scalar) -> (LCP, Vec<UnitVec3D>) where T: DynamicBody {
-        let number_of_contacts = contacts.len();
-        let size = number_of_contacts * NUM_COMPONENTS;
-        let mut problem = LCP::new(size);
-        let mut friction_directions: Vec<UnitVec3D> = Vec::new();
-
-        for (i, contact) in contacts.iter().enumerate() {
-            // TODO handle more than one contact point
-            // NOTE simple approximation of contact center
-            let contact_center = contact.points().iter()
-                .fold(Vec3D::zero(), |total, point| {
-                    total + point
-                }) / contact.points().len() as Scalar;
-            let contact_normal = contact.normal();
-            let body_0 = contact.handles().0.borrow();
-            let body_1 = contact.handles().1.borrow();
+    /// Formulates the constraints of the contacts in the island, skipping the
+    /// contacts which have no rigid body to resolve them.
+    fn formulate_constraints<T>(contacts: &Vec<Contact<T>>, island: &Island, materials: &MaterialRegistry, stats: &mut SolverStats) -> (Vec<SolverBody<T>>, Vec<ContactPointConstraint>) where T: DynamicBody {
+        let mut bodies: Vec<SolverBody<T>> = Vec::new();
+        let mut body_indices: BTreeMap<ID, usize> = BTreeMap::new();
+        let mut constraints = Vec::new();
+
+        for &contact_index in island.contact_indices().iter() {
+            let contact = &contacts[contact_index];
+            let handles = contact.handles();
+            let indices = (
+                MachConstraintSolver::body_index_of(&handles.0, &mut bodies, &mut body_indices),
+                MachConstraintSolver::body_index_of(&handles.1, &mut bodies, &mut body_indices),
+            );
+            let body_0 = handles.0.borrow();
+            let body_1 = handles.1.borrow();
+
+            if indices.0.is_none() && indices.1.is_none() {
+                stats.record_failure(body_0.id(), body_1.id(), MachError::UnsupportedBodies(body_0.id(), body_1.id()));
+
+                continue;
+            }
 
             let dynamic_body_0 = DynamicBodyRef::from(&*body_0);
             let dynamic_body_1 = DynamicBodyRef::from(&*body_1);
+            let contact_normal = contact.normal();
+
+            let material = materials.contact_material_between(
+                body_0.dynamic_extension_data(),
+                body_1.dynamic_extension_data(),
+            );
+            let friction_coefficient = material.friction_coefficient * contact.friction_scale();
+            let restitution_coefficient = material.restitution_coefficient * contact.restitution_scale();
+            let relative_angular_velocity = angular_velocity_of(&dynamic_body_0) - angular_velocity_of(&dynamic_body_1);
+            let tangents = tangent_directions_of(contact_normal);
+
+            for (point_index, &point) in contact.points().iter().enumerate() {
+                let relative_velocity = dynamic_body_0.velocity_at(point) - dynamic_body_1.velocity_at(point);
+
+                let row = |direction: Vec3D| -> ConstraintRow {
+                    let mut blocks = Vec::with_capacity(2);
+
+                    if let (Some(index), &DynamicBodyRef::Rigid(ref rigid_body)) = (indices.0, &dynamic_body_0) {
+                        blocks.push(JacobianBlock {
+                            body_index: index,
+                            linear: direction,
+                            angular: (point - rigid_body.center_of_mass()).cross(direction),
+                        });
+                    }
+
+                    if let (Some(index), &DynamicBodyRef::Rigid(ref rigid_body)) = (indices.1, &dynamic_body_1) {
+                        blocks.push(JacobianBlock {
+                            body_index: index,
+                            linear: -direction,
+                            angular: -(point - rigid_body.center_of_mass()).cross(direction),
+                        });
+                    }
+
+                    ConstraintRow {
+                        blocks: blocks,
+                        relative_velocity: relative_velocity.dot(direction),
+                    }
+                };
+
+                // rows which only resist the relative rotation of the bodies
+                let angular_row = |axis: Vec3D| -> ConstraintRow {
+                    let blocks = vec!((indices.0, 1.0), (indices.1, -1.0)).into_iter()
+                        .filter_map(|(index, sign)| index.map(|index| JacobianBlock {
+                            body_index: index,
+                            linear: Vec3D::zero(),
+                            angular: axis * sign,
+                        }))
+                        .collect();
+
+                    ConstraintRow {
+                        blocks: blocks,
+                        relative_velocity: relative_angular_velocity.dot(axis),
+                    }
+                };
+
+                // the friction cone is approximated by a pyramid, limiting the
+                // friction along each tangent independently
+                let mut frictions = vec!(
+                    FrictionRow {
+                        row: row(tangents.0),
+                        coefficient: friction_coefficient,
+                    },
+                    FrictionRow {
+                        row: row(tangents.1),
+                        coefficient: friction_coefficient,
+                    },
+                );
+
+                if material.rolling_friction > 0.0 {
+                    let rolling_coefficient = material.rolling_friction * contact.friction_scale();
+
+                    frictions.push(FrictionRow {
+                        row: angular_row(tangents.0),
+                        coefficient: rolling_coefficient,
+                    });
+                    frictions.push(FrictionRow {
+                        row: angular_row(tangents.1),
+                        coefficient: rolling_coefficient,
+                    });
+                }
+
+                if material.torsional_friction > 0.0 {
+                    frictions.push(FrictionRow {
+                        row: angular_row(Vec3D::from(contact_normal)),
+                        coefficient: material.torsional_friction * contact.friction_scale(),
+                    });
+                }
+
+                constraints.push(ContactPointConstraint {
+                    contact_index: contact_index,
+                    point: point,
+                    normal: row(Vec3D::from(contact_normal)),
+                    frictions: frictions,
+                    restitution_coefficient: restitution_coefficient,
+                    penetration_depth: contact.penetration_depth(point_index),
+                });
+            }
+        }
 
-            let (mu, mass_inverse, inertia_inverse, rel_vel, contact_offset) = match (dynamic_body_0, dynamic_body_1) {
-                (DynamicBodyRef::Rigid(rigid_body_0), DynamicBodyRef::Rigid(rigid_body_1)) => {
-                    let contact_offset_0 = contact_center - rigid_body_0.translation();
-                    let contact_offset_1 = contact_center - rigid_body_1.translation();
+        return (bodies, constraints);
+    }
 
-                    let mu = rigid_body_0.friction_coefficient() * rigid_body_1.friction_coefficient();
-                    let mass_inverse = (rigid_body_0.mass_inverse(), rigid_body_1.mass_inverse());
-                    let inertia_inverse = (rigid_body_0.inertia_inverse(), rigid_body_1.inertia_inverse());
+    fn body_index_of<T>(handle: &Handle<T>, bodies: &mut Vec<SolverBody<T>>, body_indices: &mut BTreeMap<ID, usize>) -> Option<usize> where T: DynamicBody {
+        let body = handle.borrow();
+        let id = body.id();
 
-                    let rel_vel = rigid_body_0.velocity() - rigid_body_1.velocity() +
-                        contact_offset_0.cross(rigid_body_0.angular_velocity().clone()) -
-                        contact_offset_1.cross(rigid_body_1.angular_velocity().clone());
+        if let Some(&index) = body_indices.get(&id) {
+            return Some(index);
+        }
 
-                    (mu, mass_inverse, inertia_inverse, rel_vel, (contact_offset_0, contact_offset_1))
-                },
+        RigidBodyRef::try_from(&*body).map(|rigid_body| {
+            let index = bodies.len();
 
-                (DynamicBodyRef::Rigid(rigid_body), DynamicBodyRef::Fixed(fixed_body)) => {
-                    let contact_offset_0 = contact_center - rigid_body.translation();
-                    let contact_offset_1 = contact_center - fixed_body.translation();
+            bodies.push(SolverBody {
+                handle: Handle::clone(handle),
+                mass_inverse: rigid_body.mass_inverse(),
+                inertia_inverse: rigid_body.inertia_inverse(),
+            });
+            body_indices.insert(id, index);
 
-                    let mu = rigid_body.friction_coefficient() * fixed_body.friction_coefficient();
-                    let mass_inverse = (rigid_body.mass_inverse(), 0.0);
-                    let inertia_inverse = (rigid_body.inertia_inverse(), Matrix::new(0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0));
+            index
+        })
+    }
 
-                    let rel_vel = rigid_body.velocity() +
-                        contact_offset_0.cross(rigid_body.angular_velocity().clone());
+    /// Computes the change in the relative velocity along the first row due
+    /// to a unit impulse along the second row.
+    fn coupling<T>(row_0: &ConstraintRow, row_1: &ConstraintRow, bodies: &Vec<SolverBody<T>>) -> Scalar where T: DynamicBody {
+        let mut total = 0.0;
 
-                    (mu, mass_inverse, inertia_inverse, rel_vel, (contact_offset_0, contact_offset_1))
-                },
+        for block_0 in row_0.blocks.iter() {
+            for block_1 in row_1.blocks.iter().filter(|block| block.body_index == block_0.body_index) {
+                let body = &bodies[block_0.body_index];
 
-                _otherwise => panic!("unhandled body type combination!"),
-            };
+                total += body.mass_inverse * block_0.linear.dot(block_1.linear) +
+                    block_0.angular.dot(body.inertia_inverse * block_1.angular);
+            }
+        }
 
-            let impulse = ImpulseSolver::compute_impulse_for_event(contact);
-            let impulse = if impulse < 0.0 {
-                0.0
+        return total;
+    }
+
+    /// Partitions the unknowns into a block for each contact, _i.e._ for each
+    /// pair of bodies, given the number of unknowns of each constraint. The
+    /// constraints of each contact are consecutive.
+    fn contact_block_sizes<F>(constraints: &Vec<ContactPointConstraint>, unknowns_of: F) -> Vec<usize> where F: Fn(&ContactPointConstraint) -> usize {
+        let mut block_sizes: Vec<usize> = Vec::new();
+        let mut previous_contact_index = None;
+
+        for constraint in constraints.iter() {
+            if previous_contact_index == Some(constraint.contact_index) {
+                let last = block_sizes.len() - 1;
+                block_sizes[last] += unknowns_of(constraint);
             } else {
-                impulse
-            };
-            let impulse_offset = i * NUM_COMPONENTS;
-            let friction_offset = impulse_offset + 1;
+                block_sizes.push(unknowns_of(constraint));
+            }
+
+            previous_contact_index = Some(constraint.contact_index);
+        }
+
+        return block_sizes;
+    }
+
+    /// Sets the coupling between each pair of rows acting on a common body.
+    /// Rows without a common body are not coupled, so their entries are left
+    /// out of the matrix.
+    fn fill_couplings<T>(problem: &mut LCP, rows: &Vec<&ConstraintRow>, bodies: &Vec<SolverBody<T>>) where T: DynamicBody {
+        let mut rows_of_bodies: Vec<Vec<usize>> = vec!(Vec::new(); bodies.len());
+
+        for (i, row) in rows.iter().enumerate() {
+            for block in row.blocks.iter() {
+                rows_of_bodies[block.body_index].push(i);
+            }
+        }
+
+        for (i, row_0) in rows.iter().enumerate() {
+            let mut coupled_rows: Vec<usize> = row_0.blocks.iter()
+                .flat_map(|block| rows_of_bodies[block.body_index].iter().cloned())
+                .collect();
+            coupled_rows.sort();
+            coupled_rows.dedup();
+
+            for j in coupled_rows {
+                *problem.matrix_mut(i, j) = MachConstraintSolver::coupling(row_0, rows[j], bodies);
+            }
+        }
+    }
+
+    /// Returns the index of the first unknown of each constraint in the
+    /// velocity LCP, followed by the total number of unknowns.
+    fn offsets_of(constraints: &Vec<ContactPointConstraint>) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(constraints.len() + 1);
+        offsets.push(0);
+
+        for constraint in constraints.iter() {
+            let offset = offsets[offsets.len() - 1] + constraint.size();
+            offsets.push(offset);
+        }
+
+        return offsets;
+    }
+
+    fn formulate_velocity_lcp<T>(bodies: &Vec<SolverBody<T>>, constraints: &Vec<ContactPointConstraint>, time_step: Scalar, config: &SolverConfig) -> LCP where T: DynamicBody {
+        let offsets = MachConstraintSolver::offsets_of(constraints);
+        let mut problem = LCP::with_blocks(&MachConstraintSolver::contact_block_sizes(constraints, |constraint| constraint.size()));
+        let rows: Vec<&ConstraintRow> = constraints.iter()
+            .flat_map(|constraint| {
+                Some(&constraint.normal).into_iter()
+                    .chain(constraint.frictions.iter().map(|friction| &friction.row))
+            })
+            .collect();
+
+        MachConstraintSolver::fill_couplings(&mut problem, &rows, bodies);
+
+        for (constraint, &impulse_offset) in constraints.iter().zip(offsets.iter()) {
+            let normal_velocity = constraint.normal.relative_velocity;
 
-            let generalized_mass_inverse = |vect: UnitVec3D| -> Vec3D {
-                (mass_inverse.0 + mass_inverse.1) * vect +
-                    contact_offset.0.cross(inertia_inverse.0 * contact_offset.0.cross(vect)) +
-                    contact_offset.1.cross(inertia_inverse.1 * contact_offset.1.cross(vect))
+            let restitution_velocity = if normal_velocity < -config.restitution_threshold {
+                -constraint.restitution_coefficient * normal_velocity
+            } else {
+                0.0
             };
 
-            let generalized_mass_inverse_norm = generalized_mass_inverse(contact_normal);
-
-            let mut generator = UnitVec3DGenerator::new();
-            // FRICTION
-            let mut perpendicular_direction = rel_vel.cross(contact_normal).normalize();
-            // ASSUMPTION: Non-finite length means that the relative velocity
-            // and contact normal directions are aligned
-            while !perpendicular_direction.squared_length().is_finite() {
-                // pick any arbitrary direction to avoid the singularity when
-                // relative velocity is aligned  with the contact normal
-                let guess = Vec3D::from(generator.gen_next());
-                perpendicular_direction = rel_vel.cross(guess).normalize();
+            let correction_velocity = match config.position_correction {
+                PositionCorrection::Baumgarte => correction_velocity_for(constraint, time_step, config),
+                PositionCorrection::SplitImpulse => 0.0,
             };
-            let friction_direction = -contact_normal.cross(perpendicular_direction);
-            let generalized_mass_inverse_fric = generalized_mass_inverse(friction_direction);
-
-            *problem.matrix_mut(friction_offset, friction_offset) = friction_direction.dot(generalized_mass_inverse_fric);
-            *problem.matrix_mut(impulse_offset, friction_offset) = contact_normal.dot(generalized_mass_inverse_fric);
-            *problem.matrix_mut(friction_offset, impulse_offset) = friction_direction.dot(generalized_mass_inverse_norm);
-
-            *problem.unknown_mut(friction_offset) = time_step * mu * impulse;
-
-            *problem.bias_mut(friction_offset) = friction_direction.dot(rel_vel);
-
-            let max_friction_impulse = time_step * rel_vel.dot(friction_direction).abs();
-            problem.add_value_constraint(friction_offset, Box::new(move |local_problem, value| {
-                if value < 0.0 {
-                    0.0
-                } else {
-                    let friction_limit_due_to_normal_impulse = mu * local_problem.solution(impulse_offset);
-                    let friction_limit = if max_friction_impulse > friction_limit_due_to_normal_impulse {
-                        friction_limit_due_to_normal_impulse
-                    } else {
-                        max_friction_impulse
-                    };
-
-                    if value > friction_limit {
-                        friction_limit
-                    } else {
-                        value
-                    }
-                }
-            }));
 
-            friction_directions.push(friction_direction);
-
-            // IMPULSE
-            let impulse_diagonal = contact_normal.dot(generalized_mass_inverse_norm);
-            *problem.matrix_mut(impulse_offset, impulse_offset) = impulse_diagonal;
-            *problem.unknown_mut(impulse_offset) = time_step * impulse;
-            // TODO this satisfies the constraints when friction is zero, but
-            // is it theoretically valid?
-            *problem.bias_mut(impulse_offset) = problem.solution(impulse_offset) * impulse_diagonal;
-
-            let impulse_limit = time_step * impulse;
-            problem.add_value_constraint(impulse_offset, Box::new(move |_problem: &LCP, value: Scalar| -> Scalar {
-                if value < 0.0 {
-                    0.0
-                } else if value > impulse_limit {
-                    impulse_limit
-                } else {
-                    value
+            *problem.bias_mut(impulse_offset) = restitution_velocity + correction_velocity - normal_velocity;
+            problem.set_bounds(impulse_offset, LCPBounds::Fixed(0.0, INFINITY));
+
+            for (index, friction) in constraint.frictions.iter().enumerate() {
+                let friction_offset = impulse_offset + 1 + index;
+
+                *problem.bias_mut(friction_offset) = -friction.row.relative_velocity;
+                problem.set_bounds(friction_offset, LCPBounds::ScaledBy(impulse_offset, friction.coefficient));
+            }
+        }
+
+        return problem;
+    }
+
+    fn formulate_position_lcp<T>(bodies: &Vec<SolverBody<T>>, constraints: &Vec<ContactPointConstraint>, time_step: Scalar, config: &SolverConfig) -> LCP where T: DynamicBody {
+        let mut problem = LCP::with_blocks(&MachConstraintSolver::contact_block_sizes(constraints, |_constraint| 1));
+        let rows: Vec<&ConstraintRow> = constraints.iter()
+            .map(|constraint| &constraint.normal)
+            .collect();
+
+        MachConstraintSolver::fill_couplings(&mut problem, &rows, bodies);
+
+        for (i, constraint) in constraints.iter().enumerate() {
+            *problem.bias_mut(i) = correction_velocity_for(constraint, time_step, config);
+            problem.set_bounds(i, LCPBounds::Fixed(0.0, INFINITY));
+        }
+
+        return problem;
+    }
+
+    /// Solves the contacts in the island, adding the normal impulse applied at
+    /// each contact to its total.
+    fn solve_island<T>(&self, contacts: &Vec<Contact<T>>, island: &Island, time_step: Scalar, config: &SolverConfig, materials: &MaterialRegistry, normal_impulses: &mut Vec<Scalar>, next_cache: &mut BTreeMap<(ID, ID), Vec<CachedImpulses>>, stats: &mut SolverStats) where T: DynamicBody {
+        let (bodies, constraints) = MachConstraintSolver::formulate_constraints(contacts, island, materials, stats);
+
+        if constraints.is_empty() {
+            return;
+        }
+
+        let mut problem = MachConstraintSolver::formulate_velocity_lcp(&bodies, &constraints, time_step, config);
+        let offsets = MachConstraintSolver::offsets_of(&constraints);
+
+        for (constraint, &offset) in constraints.iter().zip(offsets.iter()) {
+            if let Some(cached) = self.cached_impulses_for(contacts, constraint) {
+                for (index, &impulse_rate) in cached.impulse_rates.iter().enumerate() {
+                    *problem.unknown_mut(offset + index) = impulse_rate * time_step;
                 }
+            }
+        }
+
+        let report = lcp_solvers::GaussSeidel::new()
+            .with_max_iterations(config.velocity_iterations)
+            .with_convergence_tolerance(config.convergence_tolerance)
+            .solve_in_place(&mut problem);
+        stats.record_velocity_solve(report);
+
+        for (constraint, &offset) in constraints.iter().zip(offsets.iter()) {
+            normal_impulses[constraint.contact_index] += problem.solution(offset);
+
+            next_cache.entry(pair_key_of(&contacts[constraint.contact_index]))
+                .or_insert_with(Vec::new)
+                .push(CachedImpulses {
+                    point: constraint.point,
+                    impulse_rates: (0..constraint.size())
+                        .map(|index| problem.solution(offset + index) / time_step)
+                        .collect(),
+                });
+        }
+
+        let changes = MachConstraintSolver::velocity_changes(&bodies, constraints.iter()
+            .zip(offsets.iter())
+            .flat_map(|(constraint, &offset)| {
+                let problem = &problem;
+
+                Some((&constraint.normal, problem.solution(offset))).into_iter()
+                    .chain(constraint.frictions.iter()
+                        .enumerate()
+                        .map(move |(index, friction)| (&friction.row, problem.solution(offset + 1 + index))))
             }));
+
+        for (body, change) in bodies.iter().zip(changes.into_iter()) {
+            let mut body = body.handle.borrow_mut();
+            let mut rigid_body = RigidBodyRefMut::try_from(&mut *body)
+                .expect("expected the body to be rigid but was not");
+
+            *rigid_body.velocity_mut() += change.0;
+            *rigid_body.angular_velocity_mut() += change.1;
+            // the body was already moved with its velocity from before the
+            // contacts were resolved, so the change also applies to the
+            // displacement over the step (otherwise static friction could not
+            // hold the body against gravity)
+            rigid_body.displace(change.0 * time_step, change.1 * time_step);
         }
 
-        return (problem, friction_directions);
-    }
+        if config.position_correction == PositionCorrection::SplitImpulse && config.position_iterations > 0 {
+            let mut problem = MachConstraintSolver::formulate_position_lcp(&bodies, &constraints, time_step, config);
 
-    fn apply_lcp_solution<I, T>(problem: LCP, friction_directions: Vec<UnitVec3D>, time_step: Scalar, contacts: &Vec<Contact<T>>, integrator: &I) where I: Integrator, T: DynamicBody {
-        for (i, contact) in contacts.iter().enumerate() {
-            let impulse_offset = NUM_COMPONENTS * i;
-            let friction_offset = impulse_offset + 1;
+            let report = lcp_solvers::GaussSeidel::new()
+                .with_max_iterations(config.position_iterations)
+                .with_convergence_tolerance(config.convergence_tolerance)
+                .solve_in_place(&mut problem);
+            stats.record_position_solve(report);
 
-            let friction_direction = &friction_directions[i];
+            let pseudo_velocities = MachConstraintSolver::velocity_changes(&bodies, constraints.iter()
+                .enumerate()
+                .map(|(i, constraint)| (&constraint.normal, problem.solution(i))));
 
-            let contact_normal = contact.normal();
-            let contact_center = contact.point(0);
-            let penetration_depth = contact.penetration_depth(0);
-            let mut body_0 = contact.handles().0.borrow_mut();
-            let mut body_1 = contact.handles().1.borrow_mut();
-
-            let dynamic_body_0 = DynamicBodyRefMut::from(&mut *body_0);
-            let dynamic_body_1 = DynamicBodyRefMut::from(&mut *body_1);
-
-            match (dynamic_body_0, dynamic_body_1) {
-                (DynamicBodyRefMut::Rigid(mut rigid_body_0), DynamicBodyRefMut::Rigid(mut rigid_body_1)) => {
-                    let velocity_change = (contact_normal * problem.solution(impulse_offset)
-                        + friction_direction * problem.solution(friction_offset))
-                        / time_step;
-
-                    let mass = [rigid_body_0.mass(), rigid_body_1.mass()];
-                    let inertia_inverse = [rigid_body_0.inertia().inverse(), rigid_body_1.inertia().inverse()];
-                    // relative vector from position to contact center
-                    let to_contact_center = [
-                        contact_center - rigid_body_0.translation(),
-                        contact_center - rigid_body_1.translation(),
-                    ];
-
-                    let angular_velocity_change_0 = inertia_inverse[0]*to_contact_center[0].cross( velocity_change);
-                    let angular_velocity_change_1 = inertia_inverse[1]*to_contact_center[1].cross(-velocity_change);
-
-                    let correction = -0.5 * penetration_depth * contact_normal;
-                    let change_0 = (velocity_change / mass[0], angular_velocity_change_0);
-                    MachConstraintSolver::update_rigid_body::<I, T>(&mut rigid_body_0, integrator, change_0, time_step, correction);
-
-                    let change_1 = (-velocity_change / mass[1], angular_velocity_change_1);
-                    MachConstraintSolver::update_rigid_body::<I, T>(&mut rigid_body_1, integrator, change_1, 0.0, -correction);
-                },
-
-                (DynamicBodyRefMut::Rigid(mut rigid_body), DynamicBodyRefMut::Fixed(_fixed_body)) => {
-                    let velocity_change = (contact_normal * problem.solution(impulse_offset)
-                        + friction_direction * problem.solution(friction_offset))
-                        / time_step;
-
-                    let to_contact_center = contact_center - rigid_body.translation();
-
-                    let angular_velocity_change = rigid_body.inertia().inverse()*to_contact_center.cross(velocity_change);
-
-                    let correction = -0.5 * penetration_depth * contact_normal;
-                    let change = (velocity_change / rigid_body.mass(), angular_velocity_change);
-                    MachConstraintSolver::update_rigid_body::<I, T>(&mut rigid_body, integrator, change, 0.0, correction);
-                },
-
-                _otherwise => panic!("unhandled body combination"),
+            for (body, pseudo_velocity) in bodies.iter().zip(pseudo_velocities.into_iter()) {
+                let mut body = body.handle.borrow_mut();
+                let mut rigid_body = RigidBodyRefMut::try_from(&mut *body)
+                    .expect("expected the body to be rigid but was not");
+
+                rigid_body.displace(pseudo_velocity.0 * time_step, pseudo_velocity.1 * time_step);
             }
         }
     }
 
-    fn update_rigid_body<I, T>(rigid_body: &mut RigidBodyRefMut<T>, integrator: &I, change: (Vec3D, Vec3D), remaining_time: Scalar, correction: Vec3D) where I: Integrator, T: DynamicBody {
-        *rigid_body.velocity_mut() += change.0;
-        *rigid_body.angular_velocity_mut() += change.1;
-        *rigid_body.translation_mut() += correction;
+    /// Returns the impulses of the nearest contact point between the same
+    /// bodies in the previous step, if it is close enough to the constraint's
+    /// point and had the same number of unknowns.
+    fn cached_impulses_for<T>(&self, contacts: &Vec<Contact<T>>, constraint: &ContactPointConstraint) -> Option<&CachedImpulses> where T: DynamicBody {
+        let cached_points = match self.impulse_cache.get(&pair_key_of(&contacts[constraint.contact_index])) {
+            Some(cached_points) => cached_points,
+            None => return None,
+        };
+
+        let nearest = cached_points.iter()
+            .filter(|cached| cached.impulse_rates.len() == constraint.size())
+            .map(|cached| (cached.point.distance_to(constraint.point), cached))
+            .filter(|&(distance, _cached)| distance < WARM_START_DISTANCE)
+            .fold(None, |nearest: Option<(Scalar, &CachedImpulses)>, candidate| match nearest {
+                Some(nearest) if nearest.0 <= candidate.0 => Some(nearest),
+                _ => Some(candidate),
+            });
+
+        nearest.map(|(_distance, cached)| cached)
+    }
+
+    /// Computes the change in velocity and angular velocity of each body due
+    /// to the impulses along the rows.
+    fn velocity_changes<'a, T, R>(bodies: &Vec<SolverBody<T>>, rows_with_impulses: R) -> Vec<(Vec3D, Vec3D)> where T: DynamicBody, R: Iterator<Item=(&'a ConstraintRow, Scalar)> {
+        let mut changes = vec!((Vec3D::zero(), Vec3D::zero()); bodies.len());
+
+        for (row, impulse) in rows_with_impulses {
+            for block in row.blocks.iter() {
+                let body = &bodies[block.body_index];
+                let change = changes[block.body_index];
+
+                changes[block.body_index] = (
+                    change.0 + block.linear * (body.mass_inverse * impulse),
+                    change.1 + body.inertia_inverse * block.angular * impulse,
+                );
+            }
+        }
 
-        // TODO missing gravity!
-        integrator.integrate_in_place(&mut rigid_body.integratable(), remaining_time, Vec3D::zero());
+        return changes;
     }
 }
 
 impl<I, T> ConstraintSolver<I, T> for MachConstraintSolver where I: Integrator, T: DynamicBody {
-    fn solve_with_contacts(&mut self, contacts: &Vec<Contact<T>>, integrator: &I, time_step: Scalar) {
-        let (mut problem, friction_directions) = MachConstraintSolver::formulate_lcp::<T>(contacts, time_step);
+    fn solve_with_contacts(&mut self, contacts: &Vec<Contact<T>>, _integrator: &I, time_step: Scalar, config: &SolverConfig, materials: &MaterialRegistry, stats: &mut SolverStats) -> Vec<Scalar> {
+        let mut normal_impulses = vec!(0.0; contacts.len());
+        let mut next_cache = BTreeMap::new();
+
+        // the islands do not share any rigid bodies, so each island is solved
+        // as a separate problem
+        for island in Island::partition(contacts).iter() {
+            self.solve_island(contacts, island, time_step, config, materials, &mut normal_impulses, &mut next_cache, stats);
+        }
 
-        lcp_solvers::GaussSeidel.solve_in_place(&mut problem);
+        // only the contacts of this step are kept, so bodies which separate
+        // and touch again later start from no impulse
+        self.impulse_cache = next_cache;
 
-        MachConstraintSolver::apply_lcp_solution(problem, friction_directions, time_step, contacts, integrator);
+        return normal_impulses;
     }
 }
 
-pub struct ImpulseSolver;
+/// Identifies the bodies of the contact in the order they appear in it, since
+/// the cached friction impulses act along directions relative to the first
+/// body.
+fn pair_key_of<T>(contact: &Contact<T>) -> (ID, ID) where T: DynamicBody {
+    let handles = contact.handles();
+    let ids = (handles.0.borrow().id(), handles.1.borrow().id());
 
-impl ImpulseSolver {
-    pub fn compute_impulse_for_event<T>(contact: &Contact<T>) -> Scalar where T: DynamicBody {
-        let contact_center = contact.point(0);
-        let contact_normal = contact.normal();
-        let body_0 = contact.handles().0.borrow();
-        let body_1 = contact.handles().1.borrow();
+    ids
+}
 
-        let dynamic_body_0 = DynamicBodyRef::from(&*body_0);
-        let dynamic_body_1 = DynamicBodyRef::from(&*body_1);
+/// The separating velocity required to remove the configured fraction of the
+/// penetration beyond the slop within the time step.
+fn correction_velocity_for(constraint: &ContactPointConstraint, time_step: Scalar, config: &SolverConfig) -> Scalar {
+    let excess_penetration = (constraint.penetration_depth - config.penetration_slop).max(0.0);
 
-        match (dynamic_body_0, dynamic_body_1) {
-            (DynamicBodyRef::Rigid(rigid_body_0), DynamicBodyRef::Rigid(rigid_body_1)) => {
-                ImpulseSolver::compute_rigid_rigid_impulse::<T>((&rigid_body_0, &rigid_body_1), contact_center, contact_normal)
-            },
+    config.correction_factor * excess_penetration / time_step
+}
 
-            (DynamicBodyRef::Rigid(rigid_body), DynamicBodyRef::Fixed(fixed_body)) => {
-                ImpulseSolver::compute_rigid_fixed_impulse::<T>((&rigid_body, &fixed_body), contact_center, contact_normal)
-            },
+/// Returns two directions along the contact plane, orthogonal to each other.
+/// The directions only depend on the contact normal, so that the friction at
+/// a resting contact acts along the same directions from one step to the
+/// next.
+fn tangent_directions_of(contact_normal: UnitVec3D) -> (Vec3D, Vec3D) {
+    let mut generator = UnitVec3DGenerator::new();
+    let mut guess = generator.gen_next();
 
-            _otherwise => panic!("unhandled body combination"),
-        }
+    while (1.0 - guess.dot(contact_normal).abs()) < TOLERANCE {
+        guess = generator.gen_next();
     }
 
-    fn compute_rigid_rigid_impulse<T>(bodies: (&RigidBodyRef<T>, &RigidBodyRef<T>), center: Vec3D, normal: UnitVec3D) -> Scalar where T: DynamicBody {
-        let epsilon = bodies.0.restitution_coefficient() *
-            bodies.1.restitution_coefficient();
-        let mass_inverse = (bodies.0.mass_inverse(), bodies.1.mass_inverse());
-        let inertia_inverse = (bodies.0.inertia_inverse(), bodies.1.inertia_inverse());
-        let velocities = (bodies.0.velocity(), bodies.1.velocity());
-        let angular_velocities = (bodies.0.angular_velocity(), bodies.1.angular_velocity());
-        let to_contact_center = (
-            center - bodies.0.translation(),
-            center - bodies.1.translation(),
-        );
-        let k_scaled = (
-            to_contact_center.0.cross(normal.clone()),
-            to_contact_center.1.cross(normal.clone()),
-        );
-        let velocity_due_to_rotation = (
-            angular_velocities.0.dot(k_scaled.0),
-            angular_velocities.1.dot(k_scaled.1),
-        );
-
-        let numerator = normal.dot(velocities.0 - velocities.1) + velocity_due_to_rotation.0 - velocity_due_to_rotation.1;
-        let denominator = mass_inverse.0 + mass_inverse.1 +
-            k_scaled.0.dot(inertia_inverse.0 * k_scaled.0) +
-            k_scaled.1.dot(inertia_inverse.1 * k_scaled.1);
-
-        - (1.0 + epsilon) * numerator / denominator
-    }
-
-    fn compute_rigid_fixed_impulse<T>((rigid_body, fixed_body): (&RigidBodyRef<T>, &FixedBodyRef<T>), center: Vec3D, normal: UnitVec3D) -> Scalar where T: DynamicBody {
-        let epsilon = rigid_body.restitution_coefficient() *
-            fixed_body.restitution_coefficient();
-        let to_contact_center = center - rigid_body.translation();
-        let k_scaled = to_contact_center.cross(normal.clone());
-        let velocity_due_to_rotation = rigid_body.angular_velocity().dot(k_scaled);
+    let tangent_0 = contact_normal.cross(guess);
+    let tangent_1 = contact_normal.cross(tangent_0);
 
-        let numerator = normal.dot(rigid_body.velocity().clone()) + velocity_due_to_rotation;
-        let denominator = rigid_body.mass_inverse() + k_scaled.dot(rigid_body.inertia_inverse() * k_scaled);
+    (Vec3D::from(tangent_0), Vec3D::from(tangent_1))
+}
 
-        - (1.0 + epsilon) * numerator / denominator
+fn angular_velocity_of<T>(body: &DynamicBodyRef<T>) -> Vec3D where T: DynamicBody {
+    match body {
+        &DynamicBodyRef::Rigid(ref rigid_body) => *rigid_body.angular_velocity(),
+        &DynamicBodyRef::Fixed(_) => Vec3D::zero(),
+        &DynamicBodyRef::Kinematic(ref kinematic_body) => *kinematic_body.angular_velocity(),
     }
 }
@@ -1,5 +1,9 @@
+mod solver_config;
+mod solver_stats;
 mod constraint_solver;
 mod mach_constraint_solver;
 
+pub use self::solver_config::{PositionCorrection, SolverConfig};
+pub use self::solver_stats::SolverStats;
 pub use self::constraint_solver::ConstraintSolver;
 pub use self::mach_constraint_solver::MachConstraintSolver;
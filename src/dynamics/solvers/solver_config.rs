@@ -0,0 +1,55 @@
+use {Scalar, TOLERANCE};
+
+/// The method used to push penetrating bodies apart.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PositionCorrection {
+    /// Adds a velocity proportional to the penetration depth to the contact
+    /// constraints. This is cheap, but the added velocity remains after the
+    /// bodies have separated.
+    Baumgarte,
+    /// Solves for separate pseudo-velocities which are only used to move the
+    /// bodies apart, leaving the real velocities unchanged.
+    SplitImpulse,
+}
+
+/// Controls the accuracy of the constraint solver.
+///
+/// The defaults keep a tower of ten boxes standing. The contact impulses are
+/// carried over from one step to the next, so the iterations of a resting
+/// stack add up over the steps and few are needed within each one.
+#[derive(Clone, Copy, Debug)]
+pub struct SolverConfig {
+    /// The maximum number of iterations used to solve for the contact
+    /// impulses.
+    pub velocity_iterations: usize,
+    /// The maximum number of iterations used to solve for the position
+    /// corrections when using split impulses. Penetrations are left
+    /// uncorrected when zero.
+    pub position_iterations: usize,
+    /// The average change in the contact impulses between iterations below
+    /// which the impulses are considered to have converged.
+    pub convergence_tolerance: Scalar,
+    /// The method used to push penetrating bodies apart.
+    pub position_correction: PositionCorrection,
+    /// The fraction of the penetration depth corrected in each step.
+    pub correction_factor: Scalar,
+    /// The penetration depth which is allowed to remain uncorrected, which
+    /// keeps resting contacts from jittering.
+    pub penetration_slop: Scalar,
+    /// The approach speed below which contacts do not bounce.
+    pub restitution_threshold: Scalar,
+}
+
+impl Default for SolverConfig {
+    fn default() -> SolverConfig {
+        SolverConfig {
+            velocity_iterations: 50,
+            position_iterations: 20,
+            convergence_tolerance: 0.01 * TOLERANCE,
+            position_correction: PositionCorrection::SplitImpulse,
+            correction_factor: 0.2,
+            penetration_slop: 0.005,
+            restitution_threshold: 0.5,
+        }
+    }
+}
@@ -0,0 +1,81 @@
+use {ID, MachError, Scalar};
+use algorithms::ConvergenceReport;
+
+/// Records how the constraint solver converged for each island of contacts,
+/// which helps to find badly conditioned scenes, along with the pairs of
+/// bodies which were skipped because their contact could not be detected or
+/// resolved.
+#[derive(Clone, Debug)]
+pub struct SolverStats {
+    velocity_reports: Vec<ConvergenceReport>,
+    position_reports: Vec<ConvergenceReport>,
+    failures: Vec<(ID, ID, MachError)>,
+}
+
+impl SolverStats {
+    pub fn new() -> SolverStats {
+        SolverStats {
+            velocity_reports: Vec::new(),
+            position_reports: Vec::new(),
+            failures: Vec::new(),
+        }
+    }
+
+    /// The reports of the solves for the contact impulses.
+    #[inline(always)]
+    pub fn velocity_reports(&self) -> &Vec<ConvergenceReport> {
+        &self.velocity_reports
+    }
+
+    /// The reports of the solves for the position corrections, which only
+    /// take place when using split impulses.
+    #[inline(always)]
+    pub fn position_reports(&self) -> &Vec<ConvergenceReport> {
+        &self.position_reports
+    }
+
+    /// The pairs of bodies which were skipped, with the reason they were
+    /// skipped.
+    #[inline(always)]
+    pub fn failures(&self) -> &Vec<(ID, ID, MachError)> {
+        &self.failures
+    }
+
+    pub fn record_velocity_solve(&mut self, report: ConvergenceReport) {
+        self.velocity_reports.push(report);
+    }
+
+    pub fn record_position_solve(&mut self, report: ConvergenceReport) {
+        self.position_reports.push(report);
+    }
+
+    pub fn record_failure(&mut self, id_0: ID, id_1: ID, error: MachError) {
+        self.failures.push((id_0, id_1, error));
+    }
+
+    /// The total number of iterations over all of the solves.
+    pub fn total_iterations(&self) -> usize {
+        self.reports_iter()
+            .map(|report| report.iterations())
+            .sum()
+    }
+
+    /// The largest residual left by any of the solves, or zero if there were
+    /// none.
+    pub fn max_residual(&self) -> Scalar {
+        self.reports_iter()
+            .map(|report| report.residual())
+            .fold(0.0, Scalar::max)
+    }
+
+    /// The number of solves which stopped before converging.
+    pub fn unconverged_count(&self) -> usize {
+        self.reports_iter()
+            .filter(|report| !report.has_converged())
+            .count()
+    }
+
+    fn reports_iter<'a>(&'a self) -> Box<Iterator<Item=&'a ConvergenceReport> + 'a> {
+        Box::new(self.velocity_reports.iter().chain(self.position_reports.iter()))
+    }
+}
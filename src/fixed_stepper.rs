@@ -0,0 +1,189 @@
+#[cfg(test)]
+#[path="../tests/worlds/fixed_stepper_test.rs"]
+mod tests;
+
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use {ID, Scalar, World};
+use maths::{Transform, UnitQuat};
+use dynamics::{ContactEvent, DynamicBody};
+use collisions::{Contact, OverlapEvent};
+
+static DEFAULT_MAX_STEPS: usize = 5;
+
+/// The contacts and events of the steps taken in a single update of a
+/// `FixedStepper`, in the order the steps were taken.
+pub struct FixedSteps<T> where T: DynamicBody {
+    count: usize,
+    contacts: Vec<Contact<T>>,
+    contact_events: Vec<ContactEvent>,
+    overlap_events: Vec<OverlapEvent<T>>,
+}
+
+impl<T> FixedSteps<T> where T: DynamicBody {
+    /// The number of steps taken.
+    #[inline(always)]
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    #[inline(always)]
+    pub fn contacts(&self) -> &Vec<Contact<T>> {
+        &self.contacts
+    }
+
+    #[inline(always)]
+    pub fn contact_events(&self) -> &Vec<ContactEvent> {
+        &self.contact_events
+    }
+
+    #[inline(always)]
+    pub fn overlap_events(&self) -> &Vec<OverlapEvent<T>> {
+        &self.overlap_events
+    }
+}
+
+/// Advances a `World` in fixed time steps regardless of the time elapsed
+/// between updates, which keeps the simulation reproducible. The time left
+/// over after the last step is carried over to the next update, and the
+/// transforms of the bodies before and after the last step are kept to allow
+/// the bodies to be rendered in between.
+pub struct FixedStepper<T, W> where T: DynamicBody, W: World<T> {
+    world: W,
+    time_step: Scalar,
+    max_steps: usize,
+    accumulated_time: Scalar,
+    previous_transforms: BTreeMap<ID, Transform>,
+    current_transforms: BTreeMap<ID, Transform>,
+    _body: PhantomData<T>,
+}
+
+impl<T, W> FixedStepper<T, W> where T: DynamicBody, W: World<T> {
+    pub fn new(world: W, time_step: Scalar) -> FixedStepper<T, W> {
+        let mut stepper = FixedStepper {
+            world: world,
+            time_step: time_step,
+            max_steps: DEFAULT_MAX_STEPS,
+            accumulated_time: 0.0,
+            previous_transforms: BTreeMap::new(),
+            current_transforms: BTreeMap::new(),
+            _body: PhantomData,
+        };
+
+        stepper.current_transforms = stepper.record_transforms();
+        stepper.previous_transforms = stepper.current_transforms.clone();
+
+        return stepper;
+    }
+
+    /// Sets the maximum number of steps taken in a single update. Any time
+    /// which could not be simulated within the limit is discarded, so that a
+    /// slow update does not cause even slower updates afterwards.
+    pub fn with_max_steps(mut self, max_steps: usize) -> FixedStepper<T, W> {
+        self.max_steps = max_steps;
+
+        self
+    }
+
+    #[inline(always)]
+    pub fn world(&self) -> &W {
+        &self.world
+    }
+
+    #[inline(always)]
+    pub fn world_mut(&mut self) -> &mut W {
+        &mut self.world
+    }
+
+    #[inline(always)]
+    pub fn time_step(&self) -> Scalar {
+        self.time_step
+    }
+
+    #[inline(always)]
+    pub fn max_steps(&self) -> usize {
+        self.max_steps
+    }
+
+    /// Adds the elapsed time to the accumulated time, and advances the world
+    /// in fixed steps while there is enough time accumulated. Returns the
+    /// contacts and events of every step taken.
+    pub fn update(&mut self, elapsed_time: Scalar) -> FixedSteps<T> {
+        self.accumulated_time += elapsed_time;
+
+        let mut steps = FixedSteps {
+            count: 0,
+            contacts: Vec::new(),
+            contact_events: Vec::new(),
+            overlap_events: Vec::new(),
+        };
+        while self.accumulated_time >= self.time_step && steps.count < self.max_steps {
+            self.previous_transforms = self.record_transforms();
+            steps.contacts.extend(self.world.update(self.time_step));
+            steps.contact_events.extend(self.world.contact_events_iter().cloned());
+            steps.overlap_events.extend(self.world.overlap_events_iter().cloned());
+            self.accumulated_time -= self.time_step;
+            steps.count += 1;
+        }
+
+        if self.accumulated_time >= self.time_step {
+            self.accumulated_time = self.accumulated_time % self.time_step;
+        }
+
+        if steps.count > 0 {
+            self.current_transforms = self.record_transforms();
+        }
+
+        return steps;
+    }
+
+    /// The fraction of a time step accumulated but not yet simulated, between
+    /// zero and one. This is the weight used to interpolate between the
+    /// previous and current transforms.
+    #[inline]
+    pub fn alpha(&self) -> Scalar {
+        self.accumulated_time / self.time_step
+    }
+
+    /// The transform of the body before the last step taken. Bodies created
+    /// since the last step have the same previous and current transforms.
+    pub fn previous_transform(&self, id: ID) -> Option<Transform> {
+        self.previous_transforms.get(&id).cloned()
+            .or_else(|| self.current_transform(id))
+    }
+
+    /// The transform of the body after the last step taken.
+    pub fn current_transform(&self, id: ID) -> Option<Transform> {
+        self.current_transforms.get(&id).cloned()
+            .or_else(|| {
+                self.world.bodies_iter()
+                    .find(|body| body.id() == id)
+                    .map(|body| body.transform().clone())
+            })
+    }
+
+    /// Interpolates between the previous and current transforms of the body
+    /// using the current alpha.
+    pub fn interpolated_transform(&self, id: ID) -> Option<Transform> {
+        let alpha = self.alpha();
+
+        match (self.previous_transform(id), self.current_transform(id)) {
+            (Some(previous), Some(current)) => {
+                let translation = previous.translation + (current.translation - previous.translation) * alpha;
+                let rotation_change = (current.rotation * previous.rotation.inverse()).rotation_vector();
+                let rotation = UnitQuat::from_rotation_vector(rotation_change * alpha) * previous.rotation;
+
+                Some(Transform::new(translation, rotation))
+            },
+
+            _otherwise => None,
+        }
+    }
+
+    fn record_transforms(&self) -> BTreeMap<ID, Transform> {
+        self.world.bodies_iter()
+            .map(|body| (body.id(), body.transform().clone()))
+            .collect()
+    }
+}
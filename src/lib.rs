@@ -27,8 +27,11 @@ pub mod tests {
 mod world_behaviour;
 
 mod world;
+mod mach_error;
+mod world_config;
 mod mach_world;
 mod custom_world;
+mod fixed_stepper;
 
 #[macro_use]
 pub mod utils;
@@ -40,11 +43,14 @@ pub mod collisions;
 
 pub use self::maths::{Transform, UnitQuat, UnitVec3D, Vec3D};
 pub use self::world::World;
+pub use self::mach_error::MachError;
+pub use self::world_config::WorldConfig;
 pub use self::dynamics::DynamicBody;
 pub use self::collisions::CollisionObjectSpace;
 pub use self::collisions::shapes::convex_shapes::{ConvexShape, ShapeRef};
 pub use self::mach_world::{MachBody, MachWorld};
 pub use self::custom_world::CustomWorld;
+pub use self::fixed_stepper::{FixedStepper, FixedSteps};
 
 use std::fmt;
 use std::rc::Rc;
@@ -0,0 +1,56 @@
+use std::error::Error;
+use std::fmt;
+
+use ID;
+
+/// The recoverable failures of collision detection and contact resolution.
+#[derive(Clone, Debug, PartialEq)]
+pub enum MachError {
+    /// An iterative algorithm did not converge within its iteration limit.
+    IterationLimitExceeded {
+        /// The name of the algorithm.
+        process: &'static str,
+        /// The number of iterations which were run.
+        iterations: usize,
+    },
+    /// The shapes of the bodies do not span enough dimensions to build a
+    /// simplex or a contact from (e.g. every vertex coincides).
+    DegenerateShapes,
+    /// Neither of the bodies in contact can be moved by the solver.
+    UnsupportedBodies(ID, ID),
+    /// Rays can not be cast against the shape of the body.
+    UnsupportedRayCast(ID),
+}
+
+impl fmt::Display for MachError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            &MachError::IterationLimitExceeded { process, iterations } => {
+                write!(f, "{} did not converge within {} iterations", process, iterations)
+            },
+
+            &MachError::DegenerateShapes => {
+                write!(f, "the shapes are too degenerate to build a simplex from")
+            },
+
+            &MachError::UnsupportedBodies(id_0, id_1) => {
+                write!(f, "the contact between {} and {} has no rigid body to resolve it", id_0, id_1)
+            },
+
+            &MachError::UnsupportedRayCast(id) => {
+                write!(f, "rays can not be cast against the shape of {}", id)
+            },
+        }
+    }
+}
+
+impl Error for MachError {
+    fn description(&self) -> &str {
+        match self {
+            &MachError::IterationLimitExceeded { .. } => "iteration limit exceeded",
+            &MachError::DegenerateShapes => "degenerate shapes",
+            &MachError::UnsupportedBodies(_, _) => "unsupported bodies",
+            &MachError::UnsupportedRayCast(_) => "unsupported ray cast",
+        }
+    }
+}
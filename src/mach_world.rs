@@ -2,13 +2,13 @@
 #[path="../tests/worlds/mach_world_test.rs"]
 mod tests;
 
-use {CustomWorld, Scalar, World};
+use {CustomWorld, MachError, Scalar, World, WorldConfig};
 use maths::Vec3D;
 use utils::{Ref, Handle};
-use dynamics::{DynamicBodyExtension, FixedBodyDef, RigidBodyDef};
+use dynamics::{ContactEvent, DynamicBodyExtension, FixedBodyDef, ForceField, KinematicBodyDef, MaterialRegistry, RigidBodyDef, SolverStats};
 use dynamics::solvers::MachConstraintSolver;
 use dynamics::integrators::SemiImplicitEuler;
-use collisions::{Body, Contact};
+use collisions::{Body, CollisionFilter, Contact, OverlapEvent};
 use collisions::shapes::Ray;
 use collisions::detection::GJKEPADetection;
 use collisions::broadphase::BruteForceBroadphase;
@@ -20,12 +20,22 @@ pub struct MachWorld<E>(CustomWorld<BruteForceBroadphase<MachBody<E>>, MachConst
 
 impl<E> MachWorld<E> {
     pub fn new() -> MachWorld<E> {
+        MachWorld::with_config(WorldConfig::default())
+    }
+
+    pub fn with_config(config: WorldConfig) -> MachWorld<E> {
+        let integrator = if config.gyroscopic_torque {
+            SemiImplicitEuler::new().with_gyroscopic_torque()
+        } else {
+            SemiImplicitEuler::new()
+        };
         let world = CustomWorld::new(
-            GJKEPADetection::new(),
-            SemiImplicitEuler::new(),
+            GJKEPADetection::new().with_max_iterations(config.detection_iterations),
+            integrator,
             BruteForceBroadphase::new(),
             MachConstraintSolver::new(),
             Vec3D::zero(),
+            config,
         );
 
         MachWorld(world)
@@ -49,6 +59,18 @@ impl<E> World<MachBody<E>> for MachWorld<E> where E: 'static {
         self.0.set_gravity(gravity)
     }
 
+    fn add_force_field(&mut self, force_field: Box<ForceField>) {
+        self.0.add_force_field(force_field)
+    }
+
+    fn clear_force_fields(&mut self) {
+        self.0.clear_force_fields()
+    }
+
+    fn set_default_damping(&mut self, linear_damping: Scalar, angular_damping: Scalar) {
+        self.0.set_default_damping(linear_damping, angular_damping)
+    }
+
     fn create_rigid_body(&mut self, def: RigidBodyDef, extra: E) -> Handle<MachBody<E>> {
         self.0.create_rigid_body(def, extra)
     }
@@ -57,7 +79,47 @@ impl<E> World<MachBody<E>> for MachWorld<E> where E: 'static {
         self.0.create_fixed_body(def, extra)
     }
 
-    fn cast_ray<'a>(&'a self, ray: &Ray) -> Option<Ref<'a, MachBody<E>>> {
+    fn create_kinematic_body(&mut self, def: KinematicBodyDef, extra: E) -> Handle<MachBody<E>> {
+        self.0.create_kinematic_body(def, extra)
+    }
+
+    fn cast_ray<'a>(&'a self, ray: &Ray) -> Result<Option<Ref<'a, MachBody<E>>>, MachError> {
         self.0.cast_ray(ray)
     }
+
+    fn contact_events_iter<'a>(&'a self) -> Box<Iterator<Item=&'a ContactEvent> + 'a> {
+        self.0.contact_events_iter()
+    }
+
+    fn overlap_events_iter<'a>(&'a self) -> Box<Iterator<Item=&'a OverlapEvent<MachBody<E>>> + 'a> {
+        self.0.overlap_events_iter()
+    }
+
+    fn set_contact_modifier(&mut self, modifier: Box<Fn(&mut Contact<MachBody<E>>)>) {
+        self.0.set_contact_modifier(modifier)
+    }
+
+    fn clear_contact_modifier(&mut self) {
+        self.0.clear_contact_modifier()
+    }
+
+    fn collision_filter(&self) -> &CollisionFilter<E> {
+        self.0.collision_filter()
+    }
+
+    fn collision_filter_mut(&mut self) -> &mut CollisionFilter<E> {
+        self.0.collision_filter_mut()
+    }
+
+    fn material_registry(&self) -> &MaterialRegistry {
+        self.0.material_registry()
+    }
+
+    fn material_registry_mut(&mut self) -> &mut MaterialRegistry {
+        self.0.material_registry_mut()
+    }
+
+    fn solver_stats(&self) -> &SolverStats {
+        self.0.solver_stats()
+    }
 }
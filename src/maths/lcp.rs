@@ -1,34 +1,50 @@
 use std;
 
-use Scalar;
-use maths::SparseMatrix;
-
-pub type ValueConstraint = Fn(&LCP, Scalar) -> Scalar;
+use {INFINITY, NEG_INFINITY, Scalar};
+use maths::{SparseMatrix, SparseRowIter};
+
+/// The range of values allowed for an unknown of an `LCP`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum LCPBounds {
+    /// The unknown lies between the lower and the upper bound.
+    Fixed(Scalar, Scalar),
+    /// The magnitude of the unknown is at most the coefficient times the
+    /// value of the other unknown (e.g. friction limited by the normal
+    /// impulse). The other unknown is expected to be non-negative.
+    ScaledBy(usize, Scalar),
+}
 
+/// A (mixed) linear complementarity problem: find the unknowns `x` within
+/// their bounds such that each component of `matrix * x - bias` is zero, or
+/// non-negative where the unknown is at its lower bound, or non-positive where
+/// it is at its upper bound.
 pub struct LCP {
     bias: Vec<Scalar>,
     matrix: SparseMatrix,
     solution: Vec<Scalar>,
-    value_constraints: Vec<Box<ValueConstraint>>,
+    bounds: Vec<LCPBounds>,
 }
 
 impl LCP {
     pub fn new(size: usize) -> LCP {
-        let mut constraints: Vec<Box<ValueConstraint>> = Vec::with_capacity(size);
-        let mut bias = Vec::with_capacity(size);
-        let mut solution = Vec::with_capacity(size);
-
-        for _i in 0..size {
-            bias.push(0.0);
-            solution.push(0.0);
-            constraints.push(Box::new(|_problem, value| value));
-        }
+        LCP::with_matrix(SparseMatrix::new(size))
+    }
+
+    /// Creates a new `LCP` whose matrix is partitioned into consecutive
+    /// blocks of the given sizes. Only the blocks with non-zero entries are
+    /// stored.
+    pub fn with_blocks(block_sizes: &Vec<usize>) -> LCP {
+        LCP::with_matrix(SparseMatrix::with_blocks(block_sizes))
+    }
+
+    fn with_matrix(matrix: SparseMatrix) -> LCP {
+        let size = matrix.size();
 
         return LCP {
-            bias: bias,
-            matrix: SparseMatrix::new(size),
-            solution: solution,
-            value_constraints: constraints,
+            bias: vec!(0.0; size),
+            matrix: matrix,
+            solution: vec!(0.0; size),
+            bounds: vec!(LCPBounds::Fixed(NEG_INFINITY, INFINITY); size),
         };
     }
 
@@ -42,6 +58,13 @@ impl LCP {
         &mut self.matrix[(row, column)]
     }
 
+    /// Iterates over the columns and values of the non-zero entries in the
+    /// row of the matrix.
+    #[inline]
+    pub fn matrix_row_iter<'a>(&'a self, row: usize) -> SparseRowIter<'a> {
+        self.matrix.row_iter(row)
+    }
+
     #[inline]
     pub fn solution(&self, index: usize) -> Scalar {
         self.solution[index]
@@ -67,14 +90,38 @@ impl LCP {
         self.bias.len()
     }
 
+    /// Returns the bounds of the unknown, which are unbounded unless set.
     #[inline]
-    pub fn add_value_constraint(&mut self, index: usize, constraint: Box<ValueConstraint>) {
-        self.value_constraints[index] = constraint;
+    pub fn bounds(&self, index: usize) -> LCPBounds {
+        self.bounds[index]
     }
 
     #[inline]
-    pub fn apply_constraints(&self, index: usize, value: Scalar) -> Scalar {
-        (*self.value_constraints[index])(self, value)
+    pub fn set_bounds(&mut self, index: usize, bounds: LCPBounds) {
+        self.bounds[index] = bounds;
+    }
+
+    /// Returns the lower and upper bound of the unknown given the current
+    /// solution.
+    pub fn bounds_for_solution(&self, index: usize) -> (Scalar, Scalar) {
+        match self.bounds[index] {
+            LCPBounds::Fixed(lower, upper) => (lower, upper),
+
+            LCPBounds::ScaledBy(other_index, coefficient) => {
+                let limit = coefficient * self.solution[other_index];
+
+                (-limit, limit)
+            },
+        }
+    }
+
+    /// Restricts the value to the bounds of the unknown given the current
+    /// solution.
+    #[inline]
+    pub fn clamp(&self, index: usize, value: Scalar) -> Scalar {
+        let (lower, upper) = self.bounds_for_solution(index);
+
+        value.max(lower).min(upper)
     }
 }
 
@@ -1,5 +1,8 @@
 use maths::LCP;
+use algorithms::ConvergenceReport;
 
 pub trait LCPSolver {
-    fn solve_in_place(&self, &mut LCP);
+    /// Solves the problem, starting from and replacing its current solution,
+    /// and reports how the solver finished.
+    fn solve_in_place(&self, &mut LCP) -> ConvergenceReport;
 }
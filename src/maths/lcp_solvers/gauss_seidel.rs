@@ -1,35 +1,75 @@
+#[cfg(test)]
+#[path="../../../tests/maths/lcp_solvers/gauss_seidel_test.rs"]
+mod tests;
+
+use std::cmp;
+
 use {INFINITY, Scalar, TOLERANCE};
 use maths::{LCP, LCPSolver};
-use algorithms::{Execute, IterativeAlgorithm, LimitIterationsTo};
+use algorithms::{ConvergenceReport, Execute, IterativeAlgorithm, LimitIterationsTo};
+
+/// Solves the `LCP` using projected Gauss-Seidel iterations, clamping each
+/// unknown to its bounds as soon as it is updated.
+pub struct GaussSeidel {
+    max_iterations: usize,
+    convergence_tolerance: Scalar,
+}
+
+impl GaussSeidel {
+    pub fn new() -> GaussSeidel {
+        GaussSeidel {
+            max_iterations: 50,
+            convergence_tolerance: 10.0 * TOLERANCE,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> GaussSeidel {
+        self.max_iterations = max_iterations;
+
+        self
+    }
 
-pub struct GaussSeidel;
+    /// Sets the average change in the solution between iterations below which
+    /// the solution is considered to have converged.
+    pub fn with_convergence_tolerance(mut self, convergence_tolerance: Scalar) -> GaussSeidel {
+        self.convergence_tolerance = convergence_tolerance;
+
+        self
+    }
+}
 
 impl LCPSolver for GaussSeidel {
-    fn solve_in_place(&self, problem: &mut LCP) {
-        GaussSeidelAlgorithm::new(problem)
-            .limit_iterations_to(50)
-            .execute();
+    fn solve_in_place(&self, problem: &mut LCP) -> ConvergenceReport {
+        let (_problem, report) = ProjectedGaussSeidelAlgorithm::new(problem, self.convergence_tolerance, 1.0)
+            .limit_iterations_to(self.max_iterations)
+            .execute_with_report();
+
+        return report;
     }
 }
 
-struct GaussSeidelAlgorithm<'a> {
+/// The projected Gauss-Seidel iterations, moving each unknown by the
+/// relaxation factor times the change needed to satisfy its row.
+pub struct ProjectedGaussSeidelAlgorithm<'a> {
     problem: &'a mut LCP,
     convergence_threshold: Scalar,
+    relaxation_factor: Scalar,
     total_change: Scalar,
 }
 
-impl<'a> GaussSeidelAlgorithm<'a> {
-    fn new(problem: &'a mut LCP) -> GaussSeidelAlgorithm<'a> {
+impl<'a> ProjectedGaussSeidelAlgorithm<'a> {
+    pub fn new(problem: &'a mut LCP, convergence_tolerance: Scalar, relaxation_factor: Scalar) -> ProjectedGaussSeidelAlgorithm<'a> {
         let size = problem.size();
-        GaussSeidelAlgorithm {
+        ProjectedGaussSeidelAlgorithm {
             problem: problem,
             total_change: INFINITY,
-            convergence_threshold: 10.0 * TOLERANCE * size as Scalar,
+            convergence_threshold: convergence_tolerance * size as Scalar,
+            relaxation_factor: relaxation_factor,
         }
     }
 }
 
-impl<'a> IterativeAlgorithm for GaussSeidelAlgorithm<'a> {
+impl<'a> IterativeAlgorithm for ProjectedGaussSeidelAlgorithm<'a> {
     type Result = &'a mut LCP;
 
     fn result(self) -> Self::Result {
@@ -40,35 +80,31 @@ impl<'a> IterativeAlgorithm for GaussSeidelAlgorithm<'a> {
         self.total_change < self.convergence_threshold
     }
 
+    /// The average change in the solution over the last iteration.
+    fn residual(&self) -> Scalar {
+        self.total_change / cmp::max(self.problem.size(), 1) as Scalar
+    }
+
     fn next_iteration(&mut self) {
         let size = self.problem.size();
         self.total_change = 0.0;
 
         for i in 0..size {
-            if i != 0 && self.problem.solution(i).abs() < TOLERANCE {
-                *self.problem.unknown_mut(i) = 0.0;
-                continue;
-            }
-
             let mut delta = 0.0 as Scalar;
-            if i > 0 {
-                for j in 0..(i - 1) {
-                    delta = delta + self.problem.matrix(i, j) * self.problem.solution(j);
+            for (j, value) in self.problem.matrix_row_iter(i) {
+                if j != i {
+                    delta = delta + value * self.problem.solution(j);
                 }
             }
 
-            for j in (i + 1)..size {
-                delta = delta + self.problem.matrix(i, j) * self.problem.solution(j);
-            }
-
-            let value_before_constraint = (self.problem.bias(i) - delta)
-                / self.problem.matrix(i, i);
+            let previous_value = self.problem.solution(i);
+            let gauss_seidel_value = (self.problem.bias(i) - delta) / self.problem.matrix(i, i);
+            let relaxed_value = previous_value + self.relaxation_factor * (gauss_seidel_value - previous_value);
 
-            let value = self.problem.apply_constraints(i, value_before_constraint);
-            // println!("UNKNOWN[{}] = {} -> {}", i, value_before_constraint, value);
+            let value = self.problem.clamp(i, relaxed_value);
 
-            debug_assert!(value.is_finite(), format!("Non-finite solution {} when calculating the solution for index {}. LCP = {:?}", value, i, self.problem));
-            self.total_change += (self.problem.solution(i) - value).abs();
+            debug_assert!(value.is_finite(), "Non-finite solution {} when calculating the solution for index {}. LCP = {:?}", value, i, self.problem);
+            self.total_change += (previous_value - value).abs();
             *self.problem.unknown_mut(i) = value;
         }
     }
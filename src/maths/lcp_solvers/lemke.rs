@@ -0,0 +1,302 @@
+#[cfg(test)]
+#[path="../../../tests/maths/lcp_solvers/lemke_test.rs"]
+mod tests;
+
+use {INFINITY, Scalar};
+use maths::{LCP, LCPBounds, LCPSolver};
+use algorithms::ConvergenceReport;
+
+/// Entries of the entering column smaller than this fraction of its largest
+/// entry are treated as zero when choosing the pivot.
+static PIVOT_TOLERANCE: Scalar = 1e-6;
+
+/// Solves the `LCP` directly using Lemke's complementary pivoting method.
+/// Unlike the iterative solvers it finds the exact solution (up to rounding)
+/// for positive semi-definite matrices, but the cost grows with the cube of
+/// the number of unknowns, so it is only suitable for small problems.
+///
+/// The bounds are removed by measuring each unknown from one of its bounds,
+/// splitting unknowns without a finite bound into a positive and a negative
+/// part, and adding an extra unknown for each upper bound. The solution is
+/// left unchanged if none is found within the maximum number of pivots, or if
+/// the method terminates without one, which can happen when bounds are scaled
+/// by other unknowns. Each pivot is reported as an iteration.
+pub struct Lemke {
+    max_pivots: usize,
+}
+
+impl Lemke {
+    pub fn new() -> Lemke {
+        Lemke {
+            max_pivots: 1000,
+        }
+    }
+
+    pub fn with_max_pivots(mut self, max_pivots: usize) -> Lemke {
+        self.max_pivots = max_pivots;
+
+        self
+    }
+}
+
+/// A non-negative unknown of the standard problem, which moves one of the
+/// unknowns of the original problem away from its base value.
+struct Part {
+    index: usize,
+    sign: Scalar,
+}
+
+/// An extra unknown of the standard problem, which is complementary to the
+/// distance from an upper bound. The unknown is added to the rows of the parts
+/// it limits, which lets their rows become negative at the bound.
+struct Limit {
+    parts: Vec<usize>,
+    /// The upper bound on the sum of the parts, as a constant and a multiple
+    /// of another unknown of the original problem.
+    constant: Scalar,
+    scaled_by: Option<(usize, Scalar)>,
+}
+
+impl LCPSolver for Lemke {
+    fn solve_in_place(&self, problem: &mut LCP) -> ConvergenceReport {
+        let size = problem.size();
+        let mut base_values = vec!(0.0; size);
+        let mut parts: Vec<Part> = Vec::new();
+        let mut limits: Vec<Limit> = Vec::new();
+
+        for index in 0..size {
+            let first_part = parts.len();
+
+            match problem.bounds(index) {
+                LCPBounds::Fixed(lower, upper) if lower.is_finite() => {
+                    base_values[index] = lower;
+                    parts.push(Part { index: index, sign: 1.0 });
+
+                    if upper.is_finite() {
+                        limits.push(Limit {
+                            parts: vec!(first_part),
+                            constant: upper - lower,
+                            scaled_by: None,
+                        });
+                    }
+                },
+
+                LCPBounds::Fixed(_lower, upper) if upper.is_finite() => {
+                    base_values[index] = upper;
+                    parts.push(Part { index: index, sign: -1.0 });
+                },
+
+                LCPBounds::Fixed(_lower, _upper) => {
+                    parts.push(Part { index: index, sign: 1.0 });
+                    parts.push(Part { index: index, sign: -1.0 });
+                },
+
+                LCPBounds::ScaledBy(other_index, coefficient) => {
+                    parts.push(Part { index: index, sign: 1.0 });
+                    parts.push(Part { index: index, sign: -1.0 });
+
+                    // the magnitude of the unknown is the sum of its parts when
+                    // at most one of them is non-zero
+                    limits.push(Limit {
+                        parts: vec!(first_part, first_part + 1),
+                        constant: 0.0,
+                        scaled_by: Some((other_index, coefficient)),
+                    });
+                },
+            }
+        }
+
+        let mut parts_of_unknowns: Vec<Vec<usize>> = vec!(Vec::new(); size);
+
+        for (part_index, part) in parts.iter().enumerate() {
+            parts_of_unknowns[part.index].push(part_index);
+        }
+
+        // each part is complementary to the row of its unknown, negated for
+        // the negative parts, so that the row is zero between the bounds
+        let parts_size = parts.len();
+        let total_size = parts_size + limits.len();
+        let mut matrix = vec!(vec!(0.0; total_size); total_size);
+        let mut constants = vec!(0.0; total_size);
+
+        for (row, part) in parts.iter().enumerate() {
+            let mut constant = -problem.bias(part.index);
+
+            for (column, value) in problem.matrix_row_iter(part.index) {
+                constant += value * base_values[column];
+
+                for &other_part in parts_of_unknowns[column].iter() {
+                    matrix[row][other_part] = part.sign * value * parts[other_part].sign;
+                }
+            }
+
+            constants[row] = part.sign * constant;
+        }
+
+        for (limit_index, limit) in limits.iter().enumerate() {
+            let row = parts_size + limit_index;
+            constants[row] = limit.constant;
+
+            for &part in limit.parts.iter() {
+                matrix[part][row] = 1.0;
+                matrix[row][part] = -1.0;
+            }
+
+            if let Some((other_index, coefficient)) = limit.scaled_by {
+                constants[row] += coefficient * base_values[other_index];
+
+                for &other_part in parts_of_unknowns[other_index].iter() {
+                    matrix[row][other_part] += coefficient * parts[other_part].sign;
+                }
+            }
+        }
+
+        let (solution, pivots) = solve_standard_lcp(&matrix, &constants, self.max_pivots);
+
+        return match solution {
+            Some(solution) => {
+                for index in 0..size {
+                    *problem.unknown_mut(index) = base_values[index];
+                }
+
+                for (part, value) in parts.iter().zip(solution.into_iter()) {
+                    *problem.unknown_mut(part.index) += part.sign * value;
+                }
+
+                ConvergenceReport::new(pivots, 0.0, true)
+            },
+
+            None => ConvergenceReport::new(pivots, INFINITY, false),
+        };
+    }
+}
+
+/// Finds `z >= 0` such that `w = matrix * z + constants >= 0` with `w` and `z`
+/// complementary, by pivoting the unknowns in and out of the basis. Returns
+/// the solution if one was found, along with the number of pivots taken.
+fn solve_standard_lcp(matrix: &Vec<Vec<Scalar>>, constants: &Vec<Scalar>, max_pivots: usize) -> (Option<Vec<Scalar>>, usize) {
+    let size = constants.len();
+
+    if constants.iter().all(|&constant| constant >= 0.0) {
+        return (Some(vec!(0.0; size)), 0);
+    }
+
+    // the columns hold the coefficients of w, then z, then the artificial
+    // unknown, followed by the constants
+    let artificial = 2 * size;
+    let constant_column = artificial + 1;
+    let mut tableau: Vec<Vec<Scalar>> = (0..size)
+        .map(|row| {
+            let mut entries = vec!(0.0; constant_column + 1);
+            entries[row] = 1.0;
+
+            for column in 0..size {
+                entries[size + column] = -matrix[row][column];
+            }
+
+            entries[artificial] = -1.0;
+            entries[constant_column] = constants[row];
+
+            entries
+        })
+        .collect();
+    let mut basis: Vec<usize> = (0..size).collect();
+
+    // the artificial unknown replaces the most violated row, which makes the
+    // whole basis feasible
+    let first_row = (1..size).fold(0, |best, row| {
+        if constants[row] < constants[best] { row } else { best }
+    });
+    pivot(&mut tableau, first_row, artificial);
+    basis[first_row] = artificial;
+    let mut entering = size + first_row;
+    let mut pivots = 1;
+
+    while pivots < max_pivots {
+        let row = match leaving_row(&tableau, &basis, entering, artificial) {
+            Some(row) => row,
+            None => return (None, pivots),
+        };
+        let leaving = basis[row];
+
+        pivot(&mut tableau, row, entering);
+        basis[row] = entering;
+        pivots += 1;
+
+        if leaving == artificial {
+            let mut solution = vec!(0.0; size);
+
+            for (row, &unknown) in basis.iter().enumerate() {
+                if unknown >= size && unknown < artificial {
+                    solution[unknown - size] = tableau[row][constant_column];
+                }
+            }
+
+            return (Some(solution), pivots);
+        }
+
+        // the complement of the unknown which left the basis enters next
+        entering = if leaving < size { leaving + size } else { leaving - size };
+    }
+
+    return (None, pivots);
+}
+
+/// Finds the row whose basic unknown first reaches zero as the entering
+/// unknown increases, preferring the artificial unknown in case of ties.
+fn leaving_row(tableau: &Vec<Vec<Scalar>>, basis: &Vec<usize>, entering: usize, artificial: usize) -> Option<usize> {
+    let constant_column = artificial + 1;
+    let largest_entry = tableau.iter()
+        .map(|entries| entries[entering].abs())
+        .fold(0.0, Scalar::max);
+    let threshold = PIVOT_TOLERANCE * largest_entry;
+    let mut best: Option<(usize, Scalar)> = None;
+
+    for (row, entries) in tableau.iter().enumerate() {
+        if entries[entering] <= threshold {
+            continue;
+        }
+
+        let ratio = entries[constant_column] / entries[entering];
+
+        best = match best {
+            Some((best_row, best_ratio)) => {
+                let tie_tolerance = PIVOT_TOLERANCE * best_ratio.abs().max(1.0);
+
+                if ratio < best_ratio - tie_tolerance || (ratio <= best_ratio + tie_tolerance && basis[row] == artificial) {
+                    Some((row, ratio))
+                } else {
+                    Some((best_row, best_ratio))
+                }
+            },
+
+            None => Some((row, ratio)),
+        };
+    }
+
+    return best.map(|(row, _ratio)| row);
+}
+
+/// Makes the unknown of the column basic in the row, eliminating it from the
+/// other rows.
+fn pivot(tableau: &mut Vec<Vec<Scalar>>, pivot_row: usize, column: usize) {
+    let pivot_entry = tableau[pivot_row][column];
+
+    for entry in tableau[pivot_row].iter_mut() {
+        *entry /= pivot_entry;
+    }
+
+    let normalized_row = tableau[pivot_row].clone();
+
+    for (row, entries) in tableau.iter_mut().enumerate() {
+        let factor = entries[column];
+
+        if row == pivot_row || factor == 0.0 {
+            continue;
+        }
+
+        for (entry, &pivot_row_entry) in entries.iter_mut().zip(normalized_row.iter()) {
+            *entry -= factor * pivot_row_entry;
+        }
+    }
+}
@@ -1,3 +1,12 @@
+#[macro_use]
+#[cfg(test)]
+#[path="../../../tests/maths/lcp_solvers/lcp_solver_behaviour.rs"]
+mod behaviours;
+
+mod lemke;
 mod gauss_seidel;
+mod successive_over_relaxation;
 
+pub use self::lemke::Lemke;
 pub use self::gauss_seidel::GaussSeidel;
+pub use self::successive_over_relaxation::SuccessiveOverRelaxation;
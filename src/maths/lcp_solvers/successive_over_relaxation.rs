@@ -0,0 +1,61 @@
+#[cfg(test)]
+#[path="../../../tests/maths/lcp_solvers/successive_over_relaxation_test.rs"]
+mod tests;
+
+use {Scalar, TOLERANCE};
+use maths::{LCP, LCPSolver};
+use algorithms::{ConvergenceReport, Execute, LimitIterationsTo};
+use maths::lcp_solvers::gauss_seidel::ProjectedGaussSeidelAlgorithm;
+
+/// Solves the `LCP` using projected Gauss-Seidel iterations with successive
+/// over-relaxation (PGS-SOR). Each unknown moves past the value which would
+/// satisfy its row by the relaxation factor, which speeds up convergence for
+/// strongly coupled problems. Relaxation factors between 1 and 2 over-relax,
+/// while factors below 1 damp the iterations instead.
+pub struct SuccessiveOverRelaxation {
+    max_iterations: usize,
+    convergence_tolerance: Scalar,
+    relaxation_factor: Scalar,
+}
+
+impl SuccessiveOverRelaxation {
+    pub fn new() -> SuccessiveOverRelaxation {
+        SuccessiveOverRelaxation {
+            max_iterations: 50,
+            convergence_tolerance: 10.0 * TOLERANCE,
+            relaxation_factor: 1.3,
+        }
+    }
+
+    pub fn with_max_iterations(mut self, max_iterations: usize) -> SuccessiveOverRelaxation {
+        self.max_iterations = max_iterations;
+
+        self
+    }
+
+    /// Sets the average change in the solution between iterations below which
+    /// the solution is considered to have converged.
+    pub fn with_convergence_tolerance(mut self, convergence_tolerance: Scalar) -> SuccessiveOverRelaxation {
+        self.convergence_tolerance = convergence_tolerance;
+
+        self
+    }
+
+    /// Sets the relaxation factor, which must lie between 0 and 2 for the
+    /// iterations to converge.
+    pub fn with_relaxation_factor(mut self, relaxation_factor: Scalar) -> SuccessiveOverRelaxation {
+        self.relaxation_factor = relaxation_factor;
+
+        self
+    }
+}
+
+impl LCPSolver for SuccessiveOverRelaxation {
+    fn solve_in_place(&self, problem: &mut LCP) -> ConvergenceReport {
+        let (_problem, report) = ProjectedGaussSeidelAlgorithm::new(problem, self.convergence_tolerance, self.relaxation_factor)
+            .limit_iterations_to(self.max_iterations)
+            .execute_with_report();
+
+        return report;
+    }
+}
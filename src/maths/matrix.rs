@@ -93,6 +93,15 @@ impl Matrix {
         self.elements[3*col + row]
     }
 
+    /// Computes the transpose of the `Matrix`.
+    pub fn transpose(&self) -> Matrix {
+        Matrix::new(
+            self[0], self[3], self[6],
+            self[1], self[4], self[7],
+            self[2], self[5], self[8],
+        )
+    }
+
     /// Computes the determinant of the `Matrix`.
     pub fn determinant(&self) -> Scalar {
         return self[0]*(self[4]*self[8] - self[5]*self[7]) -
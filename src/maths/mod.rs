@@ -23,7 +23,7 @@ mod coordinate_transform;
 pub mod _2d;
 pub mod lcp_solvers;
 
-pub use self::lcp::LCP;
+pub use self::lcp::{LCP, LCPBounds};
 pub use self::quat::Quat;
 pub use self::vec_3d::Vec3D;
 pub use self::matrix::Matrix;
@@ -35,6 +35,6 @@ pub use self::lcp_solver::LCPSolver;
 pub use self::dot_product::DotProduct;
 pub use self::unit_vec_3d::UnitVec3D;
 pub use self::cross_product::CrossProduct;
-pub use self::sparse_matrix::SparseMatrix;
+pub use self::sparse_matrix::{SparseMatrix, SparseRowIter};
 pub use self::approximations::Approximations;
 pub use self::coordinate_transform::CoordinateTransform;
@@ -8,46 +8,184 @@ use std::ops::{Index, IndexMut};
 
 use Scalar;
 
+static ZERO: Scalar = 0.0;
+
+/// A non-zero block of the matrix, with its values stored row by row.
+struct Block {
+    column: usize,
+    values: Vec<Scalar>,
+}
+
+/// A square matrix stored in block compressed row form. The rows and columns
+/// are partitioned into the same consecutive blocks, and only the blocks which
+/// have been written to are stored, in order of their block column within
+/// each block row. Entries outside of the stored blocks are zero.
 pub struct SparseMatrix {
     size: usize,
-    elements: Vec<Scalar>,
+    block_offsets: Vec<usize>,
+    blocks_of_indices: Vec<usize>,
+    block_rows: Vec<Vec<Block>>,
 }
 
 impl SparseMatrix {
+    /// Creates a new zero `SparseMatrix` with a block for each row.
     pub fn new(size: usize) -> SparseMatrix {
-        let mut elements = Vec::with_capacity(size * size);
+        SparseMatrix::with_blocks(&vec!(1; size))
+    }
+
+    /// Creates a new zero `SparseMatrix` with consecutive blocks of the given
+    /// sizes along both the rows and the columns.
+    pub fn with_blocks(block_sizes: &Vec<usize>) -> SparseMatrix {
+        let mut block_offsets = Vec::with_capacity(block_sizes.len() + 1);
+        let mut blocks_of_indices = Vec::new();
+        block_offsets.push(0);
 
-        for _i in 0..elements.capacity() {
-            elements.push(0.0);
+        for (block, &block_size) in block_sizes.iter().enumerate() {
+            let offset = block_offsets[block] + block_size;
+            block_offsets.push(offset);
+
+            for _index in 0..block_size {
+                blocks_of_indices.push(block);
+            }
         }
 
         return SparseMatrix {
-            size: size,
-            elements: elements,
+            size: blocks_of_indices.len(),
+            block_offsets: block_offsets,
+            blocks_of_indices: blocks_of_indices,
+            block_rows: (0..block_sizes.len()).map(|_block| Vec::new()).collect(),
         };
     }
+
+    /// Returns the number of rows (and columns).
+    #[inline(always)]
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Returns the number of values stored for the non-zero blocks.
+    pub fn stored_len(&self) -> usize {
+        self.block_rows.iter()
+            .flat_map(|block_row| block_row.iter())
+            .map(|block| block.values.len())
+            .sum()
+    }
+
+    /// Iterates over the columns and values of the non-zero entries of the
+    /// row, in column order.
+    pub fn row_iter<'a>(&'a self, row: usize) -> SparseRowIter<'a> {
+        let block_row = self.blocks_of_indices[row];
+
+        SparseRowIter {
+            matrix: self,
+            local_row: row - self.block_offsets[block_row],
+            blocks: self.block_rows[block_row].iter(),
+            current: None,
+        }
+    }
+
+    #[inline(always)]
+    fn block_size(&self, block: usize) -> usize {
+        self.block_offsets[block + 1] - self.block_offsets[block]
+    }
+
+    /// Returns the block row and column of the entry along with its position
+    /// within the values of the block.
+    fn locate(&self, (row, column): (usize, usize)) -> (usize, usize, usize) {
+        let block_row = self.blocks_of_indices[row];
+        let block_column = self.blocks_of_indices[column];
+        let local_row = row - self.block_offsets[block_row];
+        let local_column = column - self.block_offsets[block_column];
+
+        (block_row, block_column, local_row * self.block_size(block_column) + local_column)
+    }
 }
 
 impl Index<(usize, usize)> for SparseMatrix {
     type Output = Scalar;
 
     fn index(&self, index: (usize, usize)) -> &Scalar {
-        &self.elements[self.size * index.0 + index.1]
+        let (block_row, block_column, position) = self.locate(index);
+        let blocks = &self.block_rows[block_row];
+
+        match blocks.binary_search_by_key(&block_column, |block| block.column) {
+            Ok(block_index) => &blocks[block_index].values[position],
+            Err(_) => &ZERO,
+        }
     }
 }
 
 impl IndexMut<(usize, usize)> for SparseMatrix {
+    /// Returns the entry, storing its block as a zero block if it was not
+    /// already stored.
     fn index_mut(&mut self, index: (usize, usize)) -> &mut Scalar {
-        &mut self.elements[self.size * index.0 + index.1]
+        let (block_row, block_column, position) = self.locate(index);
+        let block_len = self.block_size(block_row) * self.block_size(block_column);
+        let blocks = &mut self.block_rows[block_row];
+
+        let block_index = match blocks.binary_search_by_key(&block_column, |block| block.column) {
+            Ok(block_index) => block_index,
+
+            Err(block_index) => {
+                blocks.insert(block_index, Block {
+                    column: block_column,
+                    values: vec!(0.0; block_len),
+                });
+
+                block_index
+            },
+        };
+
+        &mut blocks[block_index].values[position]
     }
 }
 
 impl std::fmt::Debug for SparseMatrix {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> Result<(), std::fmt::Error> {
         try!(write!(f, "SparseMatrix {{ "));
-        for (i, element) in self.elements.iter().enumerate() {
-            try!(write!(f, "({}, {}): {}, ", i / self.size, i % self.size, element));
+        for row in 0..self.size {
+            for (column, value) in self.row_iter(row) {
+                try!(write!(f, "({}, {}): {}, ", row, column, value));
+            }
         }
         write!(f, "}}")
     }
 }
+
+/// An iterator over the non-zero entries of a row of a `SparseMatrix`.
+pub struct SparseRowIter<'a> {
+    matrix: &'a SparseMatrix,
+    local_row: usize,
+    blocks: std::slice::Iter<'a, Block>,
+    current: Option<(usize, &'a [Scalar])>,
+}
+
+impl<'a> Iterator for SparseRowIter<'a> {
+    type Item = (usize, Scalar);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some((column, values)) = self.current {
+                // stored blocks may still contain zero entries
+                if let Some((&value, remaining)) = values.split_first() {
+                    self.current = Some((column + 1, remaining));
+
+                    if value != 0.0 {
+                        return Some((column, value));
+                    }
+
+                    continue;
+                }
+            }
+
+            let block = match self.blocks.next() {
+                Some(block) => block,
+                None => return None,
+            };
+            let columns = self.matrix.block_size(block.column);
+            let start = self.local_row * columns;
+
+            self.current = Some((self.matrix.block_offsets[block.column], &block.values[start..(start + columns)]));
+        }
+    }
+}
@@ -8,8 +8,8 @@ mod arbitrary;
 
 use std::ops::Mul;
 
-use Scalar;
-use maths::{ApproxEq, Quat, UnitVec3D, Vec3D};
+use {Scalar, TOLERANCE};
+use maths::{ApproxEq, Matrix, Quat, UnitVec3D, Vec3D};
 
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct UnitQuat(Quat);
@@ -33,6 +33,28 @@ impl UnitQuat {
         return UnitQuat(Quat::new(c, s*axis.x, s*axis.y, s*axis.z));
     }
 
+    /// Computes the rotation about the direction of the rotation vector by an
+    /// angle equal to its length in radians (the exponential map).
+    pub fn from_rotation_vector(rotation_vector: Vec3D) -> UnitQuat {
+        let angle = rotation_vector.length();
+        let half_angle = angle / 2.0;
+
+        // the limit of sin(angle / 2) / angle is 1/2 as the angle approaches
+        // zero, which avoids the division for small rotations
+        let scale = if angle < TOLERANCE {
+            0.5 - angle * angle / 48.0
+        } else {
+            half_angle.sin() / angle
+        };
+
+        return UnitQuat::from_quat(Quat::new(
+            half_angle.cos(),
+            scale * rotation_vector.x,
+            scale * rotation_vector.y,
+            scale * rotation_vector.z,
+        ));
+    }
+
     pub fn rotate(&self, vect: Vec3D) -> Vec3D {
         let result = self * Quat::new(0.0, vect.x, vect.y, vect.z) * self.inverse();
         return Vec3D::new(result.i, result.j, result.k);
@@ -42,6 +64,21 @@ impl UnitQuat {
     pub fn inverse(&self) -> UnitQuat {
         UnitQuat(self.0.inverse())
     }
+
+    /// Computes the rotation vector equivalent to the rotation, with the
+    /// direction of the axis of rotation and a length equal to the angle of
+    /// rotation in radians. The shortest rotation is always taken.
+    pub fn rotation_vector(&self) -> Vec3D {
+        let quat = if self.0.r < 0.0 { -self.0 } else { self.0 };
+        let axis = Vec3D::new(quat.i, quat.j, quat.k);
+        let half_sine = axis.length();
+
+        if half_sine < TOLERANCE {
+            return axis * 2.0;
+        }
+
+        return axis * (2.0 * half_sine.atan2(quat.r) / half_sine);
+    }
 }
 
 impl From<UnitQuat> for Quat {
@@ -51,6 +88,19 @@ impl From<UnitQuat> for Quat {
     }
 }
 
+/// Converts the rotation into the equivalent orientation matrix.
+impl From<UnitQuat> for Matrix {
+    fn from(unit_quat: UnitQuat) -> Matrix {
+        let Quat { r, i, j, k } = unit_quat.0;
+
+        Matrix::new(
+            1.0 - 2.0*(j*j + k*k),       2.0*(i*j - r*k),       2.0*(i*k + r*j),
+                  2.0*(i*j + r*k), 1.0 - 2.0*(i*i + k*k),       2.0*(j*k - r*i),
+                  2.0*(i*k - r*j),       2.0*(j*k + r*i), 1.0 - 2.0*(i*i + j*j),
+        )
+    }
+}
+
 impl<'a, 'b> ApproxEq<&'a UnitQuat> for &'b UnitQuat {
     fn approx_eq(self, other: &'a UnitQuat) -> bool {
         self.0.approx_eq(&other.0)
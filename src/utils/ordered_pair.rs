@@ -0,0 +1,8 @@
+/// Orders the pair so that it can be used as a key for unordered pairs.
+pub fn ordered_pair<T>(a: T, b: T) -> (T, T) where T: Ord {
+    if a < b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
@@ -0,0 +1,89 @@
+#[cfg(test)]
+#[path="../../tests/utils/union_find_test.rs"]
+mod tests;
+
+/// Tracks a partition of the elements `0..len` into disjoint sets, merging
+/// sets in close to constant time.
+pub struct UnionFind {
+    parents: Vec<usize>,
+    ranks: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Creates a new `UnionFind` with each element in a set of its own.
+    pub fn new(len: usize) -> UnionFind {
+        UnionFind {
+            parents: (0..len).collect(),
+            ranks: vec!(0; len),
+        }
+    }
+
+    /// Returns the number of elements.
+    #[inline(always)]
+    pub fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Returns the representative element of the set containing the element.
+    /// Elements in the same set always have the same representative.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parents[root] != root {
+            root = self.parents[root];
+        }
+
+        // point the elements along the path straight at the root to keep
+        // later searches short
+        let mut current = element;
+        while self.parents[current] != root {
+            let next = self.parents[current];
+            self.parents[current] = root;
+            current = next;
+        }
+
+        return root;
+    }
+
+    /// Merges the sets containing the two elements.
+    pub fn union(&mut self, element_0: usize, element_1: usize) {
+        let root_0 = self.find(element_0);
+        let root_1 = self.find(element_1);
+
+        if root_0 == root_1 {
+            return;
+        }
+
+        if self.ranks[root_0] < self.ranks[root_1] {
+            self.parents[root_0] = root_1;
+        } else if self.ranks[root_0] > self.ranks[root_1] {
+            self.parents[root_1] = root_0;
+        } else {
+            self.parents[root_1] = root_0;
+            self.ranks[root_0] += 1;
+        }
+    }
+
+    /// Groups the elements by set, ordered by their smallest element. The
+    /// elements within each group are in ascending order.
+    pub fn sets(&mut self) -> Vec<Vec<usize>> {
+        let mut set_indices: Vec<Option<usize>> = vec!(None; self.len());
+        let mut sets: Vec<Vec<usize>> = Vec::new();
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = match set_indices[root] {
+                Some(index) => index,
+
+                None => {
+                    set_indices[root] = Some(sets.len());
+                    sets.push(Vec::new());
+                    sets.len() - 1
+                },
+            };
+
+            sets[index].push(element);
+        }
+
+        return sets;
+    }
+}
@@ -13,7 +13,9 @@ impl<F> UniqueVec3DGenerator<F> where F: FnMut() -> Vec3D {
         }
     }
 
-    pub fn gen_next(&mut self) -> Vec3D {
+    /// Returns the next point which differs from all of the previous points,
+    /// or `None` if none was found within 1000 attempts.
+    pub fn gen_next(&mut self) -> Option<Vec3D> {
         let mut counter = 0;
         while counter < 1000 {
             let guess = (self.next_func)();
@@ -21,13 +23,13 @@ impl<F> UniqueVec3DGenerator<F> where F: FnMut() -> Vec3D {
             if !self.history.iter().any(|point| point.approx_eq(guess)) {
                 self.history.push(guess);
 
-                return guess;
+                return Some(guess);
             }
 
             counter += 1;
         }
 
-        panic!("took more than 1000 iterations to compute a new unique point");
+        None
     }
 }
 
@@ -35,6 +37,6 @@ impl<F> Iterator for UniqueVec3DGenerator<F> where F: FnMut() -> Vec3D {
     type Item = Vec3D;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Some(self.gen_next())
+        self.gen_next()
     }
 }
@@ -1,15 +1,45 @@
-use Scalar;
+use {MachError, Scalar};
 use maths::Vec3D;
 use utils::{Ref, Handle};
-use dynamics::{DynamicBody, FixedBodyDef, RigidBodyDef};
-use collisions::Contact;
+use dynamics::{ContactEvent, DynamicBody, FixedBodyDef, ForceField, KinematicBodyDef, MaterialRegistry, RigidBodyDef, SolverStats};
+use collisions::{CollisionFilter, Contact, OverlapEvent};
 use collisions::shapes::Ray;
 
 pub trait World<T> where T: DynamicBody {
     fn update(&mut self, time_step: Scalar) -> Vec<Contact<T>>;
     fn set_gravity(&mut self, gravity: Vec3D);
+    /// Adds a field whose forces act on every rigid body in each step, on top
+    /// of gravity.
+    fn add_force_field(&mut self, force_field: Box<ForceField>);
+    fn clear_force_fields(&mut self);
+    /// Sets the damping used by rigid bodies created afterwards which do not
+    /// specify their own.
+    fn set_default_damping(&mut self, linear_damping: Scalar, angular_damping: Scalar);
     fn bodies_iter<'a>(&'a self) -> Box<Iterator<Item=Ref<T>> + 'a>;
     fn create_rigid_body(&mut self, def: RigidBodyDef, extension: <T as DynamicBody>::Extension) -> Handle<T>;
     fn create_fixed_body(&mut self, def: FixedBodyDef, extension: <T as DynamicBody>::Extension) -> Handle<T>;
-    fn cast_ray<'a>(&'a self, ray: &Ray) -> Option<Ref<'a, T>>;
+    /// Creates a body which is moved by its velocity or a target transform,
+    /// and pushes rigid bodies without being pushed back.
+    fn create_kinematic_body(&mut self, def: KinematicBodyDef, extension: <T as DynamicBody>::Extension) -> Handle<T>;
+    /// Finds the first body hit by the ray. Fails if the ray reaches a body
+    /// whose shape rays can not be cast against.
+    fn cast_ray<'a>(&'a self, ray: &Ray) -> Result<Option<Ref<'a, T>>, MachError>;
+    fn contact_events_iter<'a>(&'a self) -> Box<Iterator<Item=&'a ContactEvent> + 'a>;
+    fn overlap_events_iter<'a>(&'a self) -> Box<Iterator<Item=&'a OverlapEvent<T>> + 'a>;
+    /// Sets the hook called on each contact after detection and before the
+    /// contacts are resolved. The hook may disable the contact, scale its
+    /// friction or restitution, or override its normal.
+    fn set_contact_modifier(&mut self, modifier: Box<Fn(&mut Contact<T>)>);
+    fn clear_contact_modifier(&mut self);
+    fn collision_filter(&self) -> &CollisionFilter<<T as DynamicBody>::Extension>;
+    fn collision_filter_mut(&mut self) -> &mut CollisionFilter<<T as DynamicBody>::Extension>;
+    /// The named materials which bodies can refer to. The materials are looked
+    /// up when solving the contacts, so they can be registered or replaced at
+    /// any time. Bodies whose material is not registered fall back to their
+    /// own coefficients.
+    fn material_registry(&self) -> &MaterialRegistry;
+    fn material_registry_mut(&mut self) -> &mut MaterialRegistry;
+    /// How the constraint solver converged over every sub-step of the last
+    /// update.
+    fn solver_stats(&self) -> &SolverStats;
 }
@@ -0,0 +1,28 @@
+use dynamics::SolverConfig;
+
+/// Controls the accuracy of the simulation.
+#[derive(Clone, Copy, Debug)]
+pub struct WorldConfig {
+    pub solver: SolverConfig,
+    /// The number of equal steps each update is divided into.
+    pub sub_steps: usize,
+    /// The number of iterations after which collision detection is
+    /// considered to have failed. This only applies to worlds which create
+    /// their own collision detection.
+    pub detection_iterations: usize,
+    /// Whether to include the gyroscopic torque when integrating rigid
+    /// bodies, which lets bodies with an uneven mass distribution tumble.
+    /// This only applies to worlds which create their own integrator.
+    pub gyroscopic_torque: bool,
+}
+
+impl Default for WorldConfig {
+    fn default() -> WorldConfig {
+        WorldConfig {
+            solver: SolverConfig::default(),
+            sub_steps: 1,
+            detection_iterations: 1000,
+            gyroscopic_torque: false,
+        }
+    }
+}
@@ -0,0 +1,50 @@
+use Scalar;
+use algorithms::{Execute, IterativeAlgorithm, LimitIterationsTo};
+
+/// Halves its residual in each iteration until it is below the threshold.
+struct Halving {
+    residual: Scalar,
+    threshold: Scalar,
+}
+
+impl IterativeAlgorithm for Halving {
+    type Result = Scalar;
+
+    fn result(self) -> Scalar {
+        self.residual
+    }
+
+    fn has_converged(&self) -> bool {
+        self.residual < self.threshold
+    }
+
+    fn next_iteration(&mut self) {
+        self.residual /= 2.0;
+    }
+
+    fn residual(&self) -> Scalar {
+        self.residual
+    }
+}
+
+#[test]
+fn it_reports_convergence_within_the_limit() {
+    let (_result, report) = Halving { residual: 1.0, threshold: 0.2 }
+        .limit_iterations_to(10)
+        .execute_with_report();
+
+    assert_eq!(report.iterations(), 3);
+    assert_eq!(report.residual(), 0.125);
+    assert!(report.has_converged());
+}
+
+#[test]
+fn it_reports_stopping_at_the_limit_without_converging() {
+    let (_result, report) = Halving { residual: 1.0, threshold: 0.01 }
+        .limit_iterations_to(2)
+        .execute_with_report();
+
+    assert_eq!(report.iterations(), 2);
+    assert_eq!(report.residual(), 0.25);
+    assert!(!report.has_converged());
+}
@@ -0,0 +1,45 @@
+use ID;
+use collisions::CollisionFilter;
+
+#[test]
+fn it_allows_all_pairs_by_default() {
+    let filter = CollisionFilter::<()>::new();
+
+    assert!(filter.test(ID(0), &(), ID(1), &()));
+}
+
+#[test]
+fn it_ignores_pairs_regardless_of_order() {
+    let mut filter = CollisionFilter::<()>::new();
+
+    filter.ignore_pair(ID(3), ID(1));
+
+    assert!(filter.is_pair_ignored(ID(1), ID(3)));
+    assert!(!filter.test(ID(1), &(), ID(3), &()));
+    assert!(!filter.test(ID(3), &(), ID(1), &()));
+    assert!(filter.test(ID(1), &(), ID(2), &()));
+}
+
+#[test]
+fn it_can_restore_ignored_pairs() {
+    let mut filter = CollisionFilter::<()>::new();
+
+    filter.ignore_pair(ID(0), ID(1));
+    filter.restore_pair(ID(1), ID(0));
+
+    assert!(filter.test(ID(0), &(), ID(1), &()));
+}
+
+#[test]
+fn it_uses_the_callback_on_the_extension_data() {
+    let mut filter = CollisionFilter::<u32>::new();
+
+    filter.set_callback(|team_0, team_1| team_0 != team_1);
+
+    assert!(filter.test(ID(0), &1, ID(1), &2));
+    assert!(!filter.test(ID(0), &1, ID(1), &1));
+
+    filter.clear_callback();
+
+    assert!(filter.test(ID(0), &1, ID(1), &1));
+}
@@ -29,3 +29,45 @@ fn it_allows_environment_group_to_collide_with_all_other_groups() {
 fn it_does_not_allow_environment_group_to_collide_with_itself() {
     assert!(!CollisionGroup::test(CollisionGroup::Environment, CollisionGroup::Environment));
 }
+
+#[test]
+fn it_exposes_the_presets_as_category_and_mask_bits() {
+    let environment = CollisionGroup::Environment;
+    let custom = CollisionGroup::Custom {
+        category: CollisionGroup::A.category(),
+        mask: CollisionGroup::A.mask(),
+    };
+
+    assert_eq!(environment.mask() & environment.category(), 0);
+    assert_eq!(CollisionGroup::A.mask() & environment.category(), environment.category());
+    assert!(CollisionGroup::test(custom, CollisionGroup::A));
+    assert!(CollisionGroup::test(custom, CollisionGroup::Environment));
+    assert!(!CollisionGroup::test(custom, CollisionGroup::B));
+}
+
+#[test]
+fn it_requires_both_custom_groups_to_accept_each_other() {
+    let player = CollisionGroup::Custom { category: 1 << 8, mask: 1 << 9 };
+    let enemy = CollisionGroup::Custom { category: 1 << 9, mask: 1 << 8 };
+    let ghost = CollisionGroup::Custom { category: 1 << 10, mask: 1 << 8 };
+
+    assert!(CollisionGroup::test(player, enemy));
+    assert!(CollisionGroup::test(enemy, player));
+    assert!(!CollisionGroup::test(player, ghost));
+    assert!(!CollisionGroup::test(ghost, player));
+}
+
+#[test]
+fn it_does_not_allow_custom_groups_to_collide_with_the_environment_unless_masked_in() {
+    let with_environment = CollisionGroup::Custom {
+        category: 1 << 8,
+        mask: CollisionGroup::Environment.category(),
+    };
+    let without_environment = CollisionGroup::Custom {
+        category: 1 << 8,
+        mask: !CollisionGroup::Environment.category(),
+    };
+
+    assert!(CollisionGroup::test(with_environment, CollisionGroup::Environment));
+    assert!(!CollisionGroup::test(without_environment, CollisionGroup::Environment));
+}
@@ -33,7 +33,7 @@ macro_rules! assert_detection_behaviour {
                         }
                     );
 
-                    if let Some(contacts) = detection.compute_contacts(&control, &test_body) {
+                    if let Some(contacts) = detection.compute_contacts(&control, &test_body).unwrap() {
                         return quickcheck::TestResult::error(format!("expected no contacts to be returned, but found some = {:?}", contacts));
                     }
 
@@ -59,7 +59,7 @@ macro_rules! assert_detection_behaviour {
                         },
                     );
 
-                    let result = detection.compute_contacts(&control, &test_body);
+                    let result = detection.compute_contacts(&control, &test_body).unwrap();
 
                     assert!(result.is_some());
                 }
@@ -103,7 +103,7 @@ macro_rules! assert_detection_behaviour {
                     },
                 );
 
-                let result = detection.compute_contacts(&control, &test_body);
+                let result = detection.compute_contacts(&control, &test_body).unwrap();
 
                 assert!(result.is_some());
 
@@ -129,7 +129,7 @@ macro_rules! assert_detection_behaviour {
                     },
                 );
 
-                let result = detection.compute_contacts(&control, &test_body);
+                let result = detection.compute_contacts(&control, &test_body).unwrap();
 
                 assert!(result.is_some());
 
@@ -155,7 +155,7 @@ macro_rules! assert_detection_behaviour {
                     },
                 );
 
-                let contact_event = detection.compute_contacts(&control, &test_body)
+                let contact_event = detection.compute_contacts(&control, &test_body).unwrap()
                     .expect("test was setup to have a collision, but none was found");
 
                 assert_eq!(contact_event.points().len(), 2);
@@ -179,11 +179,10 @@ macro_rules! assert_detection_behaviour {
                     },
                 );
 
-                let contact_event = detection.compute_contacts(&control, &test_body)
+                let contact_event = detection.compute_contacts(&control, &test_body).unwrap()
                     .expect("expected a contact to be present, but none was found");
 
-                // contact normal can be positive or negative
-                assert_approx_eq!(contact_event.normal(), Vec3D::new(1.0, 0.0, 0.0).normalize());
+                assert_approx_eq!(contact_event.normal(), Vec3D::new(-1.0, 0.0, 0.0).normalize());
                 assert_approx_matching!(contact_event.points(), vec!(
                     Vec3D::new(0.495, 0.0, 0.0),
                     Vec3D::new(0.495, 0.5, 0.0),
@@ -192,6 +191,31 @@ macro_rules! assert_detection_behaviour {
                 ));
             }
 
+            #[test]
+            fn it_computes_penetration_depths_for_each_face_face_contact_point() {
+                let mut detection = validate(test_subject());
+                let control = handle(Cuboid::cube(1.0), Transform::identity());
+                let test_body = handle(
+                    Cuboid::cube(1.0),
+                    Transform {
+                        translation: Vec3D::new(0.99, 0.0, 0.0),
+                        rotation: UnitQuat::from_axis_angle(
+                            Vec3D::new(1.0, 0.0, 0.0).normalize(),
+                            PI / 4.0,
+                        ),
+                    },
+                );
+
+                let contact_event = detection.compute_contacts(&control, &test_body).unwrap()
+                    .expect("expected a contact to be present, but none was found");
+
+                assert_eq!(contact_event.points().len(), 4);
+
+                for &depth in contact_event.penetration_depths().iter() {
+                    assert_approx_eq!(depth, 0.01);
+                }
+            }
+
             // TODO maybe it should be pointing towards the second body, to be
             // consistent with the start-end principle
             #[test]
@@ -207,7 +231,7 @@ macro_rules! assert_detection_behaviour {
                         },
                     );
 
-                    let contact_event = detection.compute_contacts(&control, &test_body)
+                    let contact_event = detection.compute_contacts(&control, &test_body).unwrap()
                         .expect("Test was setup to always have an intersection, but that didn't happen");
 
                     let projection = contact_event.normal()
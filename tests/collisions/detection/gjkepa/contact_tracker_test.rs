@@ -1,5 +1,6 @@
 extern crate quickcheck;
 
+use MachError;
 use maths::{Approximations, Transform, UnitQuat, Vec3D};
 use utils::is_coplanar;
 use algorithms::{Execute, PanicOnIteration};
@@ -19,7 +20,7 @@ fn it_can_be_instantiated_with_intersecting_bodies() {
             }
         );
 
-        let mut contact_tracker = ContactTracker::new(&control, &data);
+        let mut contact_tracker = ContactTracker::new(&control, &data).unwrap();
 
         assert_valid_simplex(&contact_tracker);
 
@@ -45,7 +46,7 @@ fn it_can_be_instantiated_with_non_intersecting_bodies() {
             }
         );
 
-        let mut contact_tracker = ContactTracker::new(&control, &data);
+        let mut contact_tracker = ContactTracker::new(&control, &data).unwrap();
 
         assert_valid_simplex(&contact_tracker);
 
@@ -59,6 +60,25 @@ fn it_can_be_instantiated_with_non_intersecting_bodies() {
     quickcheck::quickcheck(property as fn(UnitQuat));
 }
 
+#[test]
+fn it_fails_to_be_instantiated_with_coplanar_bodies() {
+    let control = CollisionData::new(Box::new(Cuboid::new(1.0, 1.0, 0.0)), Transform::identity());
+    let data = CollisionData::new(Box::new(Cuboid::new(1.0, 1.0, 0.0)), Transform {
+        translation: Vec3D::new(0.5, 0.25, 0.0),
+        .. Transform::identity()
+    });
+
+    assert_eq!(ContactTracker::new(&control, &data).err(), Some(MachError::DegenerateShapes));
+}
+
+#[test]
+fn it_fails_to_be_instantiated_with_bodies_whose_vertices_all_coincide() {
+    let control = CollisionData::new(Box::new(Cuboid::cube(0.0)), Transform::identity());
+    let data = CollisionData::new(Box::new(Cuboid::cube(0.0)), Transform::identity());
+
+    assert_eq!(ContactTracker::new(&control, &data).err(), Some(MachError::DegenerateShapes));
+}
+
 fn find_origin<'a>(tracker: &'a mut ContactTracker, data_0: &'a CollisionData, data_1: &'a CollisionData) -> Option<&'a GJKSimplex> {
     GJK::using_simplex(tracker.simplex_mut(), data_0, data_1)
         .panic_on_iteration(1000, "looking for origin (in tests)")
@@ -2,11 +2,11 @@ extern crate quickcheck;
 
 use std::collections::HashMap;
 
-use Scalar;
-use maths::{Approximations, Transform, UnitQuat, Vec3D};
+use {PI, Scalar};
+use maths::{Approximations, Quat, Transform, UnitQuat, Vec3D};
 use algorithms::{Execute, PanicOnIteration};
-use collisions::CollisionData;
-use collisions::shapes::convex_shapes::{Cuboid, ConvexPolyhedron};
+use collisions::{CollisionData, ContactSet};
+use collisions::shapes::convex_shapes::{Cuboid, ConvexPolyhedron, Sphere};
 use collisions::detection::gjkepa::{ContactTracker, EPA, GJK, GJKSimplex};
 
 // TODO fix this failing scenario
@@ -54,7 +54,7 @@ quickcheck! {
             }
         );
 
-        let mut contact_tracker = ContactTracker::new(&control, &data);
+        let mut contact_tracker = ContactTracker::new(&control, &data).unwrap();
         let simplex = find_origin(&mut contact_tracker, &control, &data)
             .expect("Expected simplex to contain origin but it did not");
 
@@ -73,6 +73,112 @@ quickcheck! {
     }
 }
 
+#[test]
+fn it_finds_the_contact_between_slightly_tilted_stacked_boxes() {
+    let lower = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::new(
+        Vec3D::new(-0.035517234, 0.013116839, 6.4448786),
+        UnitQuat::from_quat(Quat::new(0.99999684, -6.756456e-5, -0.0025085672, -0.0003761097)),
+    ));
+    let upper = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::new(
+        Vec3D::new(-0.048896294, 0.014549594, 7.439914),
+        UnitQuat::from_quat(Quat::new(0.99999714, -0.00016464425, -0.0023776742, -0.00047542434)),
+    ));
+
+    let mut contact_tracker = ContactTracker::new(&lower, &upper).unwrap();
+    let simplex = find_origin(&mut contact_tracker, &lower, &upper)
+        .expect("Expected simplex to contain origin but it did not");
+
+    let contact_set = EPA::new(simplex, &lower, &upper)
+        .panic_on_iteration(1000, "EPA failed to converge after 1000 iterations (in test)")
+        .execute()
+        .compute_contact_set()
+        .expect("expected the contact set to be computed but it was not");
+
+    assert!(Vec3D::from(contact_set.normal()).z < -0.99, "expected the contact normal to point down, but it was {:?}", contact_set.normal());
+    assert_eq!(contact_set.points().len(), 4);
+    assert!((contact_set.penetration_depth(0) - 0.005).abs() < 1e-3, "expected a penetration depth of 0.005, but it was {}", contact_set.penetration_depth(0));
+}
+
+#[test]
+fn it_finds_a_face_contact_between_large_slightly_tilted_boxes() {
+    let lower = CollisionData::new(Box::new(Cuboid::cube(10.0)), Transform::new(
+        Vec3D::new(0.0, 0.0, -5.0),
+        UnitQuat::identity(),
+    ));
+    let upper = CollisionData::new(Box::new(Cuboid::cube(10.0)), Transform::new(
+        Vec3D::new(0.0, 0.0, 4.97),
+        UnitQuat::from_axis_angle(Vec3D::new(1.0, 0.0, 0.0).normalize(), 0.004),
+    ));
+
+    let contact_set = contact_set_between(&lower, &upper);
+
+    assert!(Vec3D::from(contact_set.normal()).z < -0.99, "expected the contact normal to point down, but it was {:?}", contact_set.normal());
+    assert_eq!(contact_set.points().len(), 4);
+}
+
+#[test]
+fn it_finds_the_overlap_of_parallel_edges_in_contact() {
+    // both boxes are rotated about the x axis, by different angles, so that
+    // the bottom edge of the upper box rests along the top edge of the lower
+    // box, offset by half of their length
+    let x_axis = Vec3D::new(1.0, 0.0, 0.0).normalize();
+    let lower = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::new(
+        Vec3D::zero(),
+        UnitQuat::from_axis_angle(x_axis, PI / 4.0),
+    ));
+    let upper = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::new(
+        Vec3D::new(0.5, 0.5 * ((PI / 6.0).cos() - (PI / 6.0).sin()), 0.5 * (2.0 as Scalar).sqrt() + 0.5 * ((PI / 6.0).cos() + (PI / 6.0).sin()) - 0.01),
+        UnitQuat::from_axis_angle(x_axis, PI / 6.0),
+    ));
+
+    let contact_set = contact_set_between(&lower, &upper);
+    let mut xs: Vec<Scalar> = contact_set.points().iter().map(|point| point.x).collect();
+    xs.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    assert!(Vec3D::from(contact_set.normal()).x.abs() < 1e-3, "expected the contact normal to be perpendicular to the edges, but it was {:?}", contact_set.normal());
+    assert_eq!(xs.len(), 2);
+    assert!(xs[0].abs() < 1e-3 && (xs[1] - 0.5).abs() < 1e-3, "expected the contact to span the overlap of the edges, but it spanned {:?}", xs);
+}
+
+#[test]
+fn it_includes_the_radius_of_a_sphere_in_the_contact() {
+    let ground = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::new(
+        Vec3D::new(0.0, 0.0, -0.5),
+        UnitQuat::identity(),
+    ));
+    let sphere = CollisionData::new(Box::new(Sphere::new(0.5)), Transform::new(
+        Vec3D::new(0.0, 0.0, 0.49),
+        UnitQuat::identity(),
+    ));
+
+    let mut contact_tracker = ContactTracker::new(&ground, &sphere).unwrap();
+    let simplex = find_origin(&mut contact_tracker, &ground, &sphere)
+        .expect("Expected simplex to contain origin but it did not");
+
+    let contact_set = EPA::new(simplex, &ground, &sphere)
+        .panic_on_iteration(1000, "EPA failed to converge after 1000 iterations (in test)")
+        .execute()
+        .compute_contact_set()
+        .expect("expected the contact set to be computed but it was not");
+
+    assert!(Vec3D::from(contact_set.normal()).z < -0.99, "expected the contact normal to point down, but it was {:?}", contact_set.normal());
+    assert_eq!(contact_set.points().len(), 1);
+    assert!((contact_set.penetration_depth(0) - 0.01).abs() < 1e-3, "expected a penetration depth of 0.01, but it was {}", contact_set.penetration_depth(0));
+    assert!((contact_set.points()[0].z + 0.005).abs() < 1e-3, "expected the contact point to lie between the surfaces, but it was {:?}", contact_set.points()[0]);
+}
+
+fn contact_set_between(data_0: &CollisionData, data_1: &CollisionData) -> ContactSet {
+    let mut contact_tracker = ContactTracker::new(data_0, data_1).unwrap();
+    let simplex = find_origin(&mut contact_tracker, data_0, data_1)
+        .expect("Expected simplex to contain origin but it did not");
+
+    EPA::new(simplex, data_0, data_1)
+        .panic_on_iteration(1000, "EPA failed to converge after 1000 iterations (in test)")
+        .execute()
+        .compute_contact_set()
+        .expect("expected the contact set to be computed but it was not")
+}
+
 fn find_origin<'a>(tracker: &'a mut ContactTracker, data_0: &'a CollisionData, data_1: &'a CollisionData) -> Option<&'a GJKSimplex> {
     GJK::using_simplex(tracker.simplex_mut(), data_0, data_1)
         .panic_on_iteration(1000, "looking for origin (in tests)")
@@ -2,6 +2,46 @@ assert_detection_behaviour! {
     use collisions::detection::gjkepa::GJKEPADetection;
 
     fn test_subject() -> GJKEPADetection {
-        GJKEPADetection { }
+        GJKEPADetection::new()
     }
 }
+
+use {ID, MachError};
+use maths::Vec3D;
+use utils::Handle;
+use collisions::{Body, BodyDef, CollisionObject, Detection};
+use collisions::shapes::convex_shapes::{ConvexShape, Cuboid};
+use tests::support::TestBody;
+
+fn handle<S>(id: u32, shape: S, translation: Vec3D) -> Handle<TestBody> where S: ConvexShape + 'static {
+    Handle::new(Body::new(ID(id), BodyDef {
+        shape: Box::new(shape),
+        translation: translation,
+        .. BodyDef::default()
+    }, ()))
+}
+
+#[test]
+fn it_fails_to_compute_contacts_between_coplanar_bodies() {
+    let mut detection = GJKEPADetection::new();
+    let control = handle(0, Cuboid::new(1.0, 1.0, 0.0), Vec3D::zero());
+    let test_body = handle(1, Cuboid::new(1.0, 1.0, 0.0), Vec3D::new(0.5, 0.25, 0.0));
+
+    let result = detection.compute_contacts(&control, &test_body);
+
+    assert_eq!(result.err(), Some(MachError::DegenerateShapes));
+}
+
+#[test]
+fn it_fails_to_compute_contacts_when_gjk_runs_out_of_iterations() {
+    let mut detection = GJKEPADetection::new().with_max_iterations(0);
+    let control = handle(0, Cuboid::cube(1.0), Vec3D::zero());
+    let test_body = handle(1, Cuboid::cube(1.0), Vec3D::new(0.5, 0.25, 0.0));
+
+    let result = detection.compute_contacts(&control, &test_body);
+
+    assert_eq!(result.err(), Some(MachError::IterationLimitExceeded {
+        process: "GJK",
+        iterations: 0,
+    }));
+}
@@ -0,0 +1,96 @@
+use {PI, Scalar};
+use maths::{UnitQuat, UnitVec3D, Vec3D};
+use collisions::detection::PolyhedronClipping;
+
+fn square(center: Vec3D, half_size: Scalar, rotation: UnitQuat) -> Vec<Vec3D> {
+    vec!(
+        center + rotation.rotate(Vec3D::new(0.0,  half_size,  half_size)),
+        center + rotation.rotate(Vec3D::new(0.0, -half_size,  half_size)),
+        center + rotation.rotate(Vec3D::new(0.0,  half_size, -half_size)),
+        center + rotation.rotate(Vec3D::new(0.0, -half_size, -half_size)),
+    )
+}
+
+#[test]
+fn it_clips_the_incident_face_against_the_reference_face() {
+    let contact_normal = UnitVec3D::from(Vec3D::new(-1.0, 0.0, 0.0));
+    let face_0 = square(Vec3D::new(0.50, 0.0, 0.0), 0.5, UnitQuat::identity());
+    let face_1 = square(Vec3D::new(0.49, 0.5, 0.5), 0.5, UnitQuat::identity());
+
+    let contact_set = PolyhedronClipping::new(face_0, face_1, contact_normal)
+        .compute_contact_set();
+
+    assert_approx_eq!(contact_set.normal(), contact_normal);
+    assert_approx_matching!(contact_set.points(), vec!(
+        Vec3D::new(0.495, 0.0, 0.0),
+        Vec3D::new(0.495, 0.5, 0.0),
+        Vec3D::new(0.495, 0.0, 0.5),
+        Vec3D::new(0.495, 0.5, 0.5),
+    ));
+
+    for &depth in contact_set.penetration_depths().iter() {
+        assert_approx_eq!(depth, 0.01);
+    }
+}
+
+#[test]
+fn it_computes_individual_penetration_depths_for_tilted_features() {
+    let contact_normal = UnitVec3D::from(Vec3D::new(-1.0, 0.0, 0.0));
+    let face_0 = square(Vec3D::new(0.5, 0.0, 0.0), 0.5, UnitQuat::identity());
+    let tilt = UnitQuat::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0).normalize(), 0.02);
+    let face_1 = vec!(
+        Vec3D::new(0.49, -0.25, 0.0),
+        Vec3D::new(0.49, -0.25, 0.0) + tilt.rotate(Vec3D::new(0.0, 0.5, 0.0)),
+    );
+
+    let contact_set = PolyhedronClipping::new(face_0, face_1, contact_normal)
+        .compute_contact_set();
+
+    assert_eq!(contact_set.points().len(), 2);
+
+    let deepest = contact_set.penetration_depths().iter()
+        .fold(0.0 as Scalar, |max, &depth| if depth > max { depth } else { max });
+    let shallowest = contact_set.penetration_depths().iter()
+        .fold(1.0 as Scalar, |min, &depth| if depth < min { depth } else { min });
+
+    assert_approx_eq!(shallowest, 0.01);
+    assert_approx_eq!(deepest, 0.01 + 0.5 * (0.02 as Scalar).sin());
+}
+
+#[test]
+fn it_selects_at_most_four_contact_points() {
+    let contact_normal = UnitVec3D::from(Vec3D::new(-1.0, 0.0, 0.0));
+    let face_0 = square(Vec3D::new(0.50, 0.0, 0.0), 0.5, UnitQuat::identity());
+    let rotation = UnitQuat::from_axis_angle(Vec3D::new(1.0, 0.0, 0.0).normalize(), PI / 4.0);
+    let face_1 = square(Vec3D::new(0.49, 0.0, 0.0), 0.5, rotation);
+
+    let contact_set = PolyhedronClipping::new(face_0, face_1, contact_normal)
+        .compute_contact_set();
+
+    assert_eq!(contact_set.points().len(), 4);
+
+    for &depth in contact_set.penetration_depths().iter() {
+        assert_approx_eq!(depth, 0.01);
+    }
+}
+
+#[test]
+fn it_uses_the_face_of_the_second_body_as_reference_for_edge_face_contacts() {
+    let contact_normal = UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0));
+    let edge_0 = vec!(
+        Vec3D::new(0.49, 0.0, 0.0),
+        Vec3D::new(0.49, 0.0, 1.0),
+    );
+    let face_1 = square(Vec3D::new(0.5, 0.0, 0.0), 0.5, UnitQuat::identity());
+
+    let clipping = PolyhedronClipping::new(edge_0, face_1, contact_normal);
+
+    assert_approx_eq!(clipping.reference_normal(), contact_normal);
+
+    let contact_set = clipping.compute_contact_set();
+
+    assert_approx_matching!(contact_set.points(), vec!(
+        Vec3D::new(0.495, 0.0, 0.0),
+        Vec3D::new(0.495, 0.0, 0.5),
+    ));
+}
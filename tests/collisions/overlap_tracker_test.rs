@@ -0,0 +1,62 @@
+use ID;
+use utils::Handle;
+use collisions::{Body, BodyDef, CollisionObject, OverlapEvent, OverlapTracker};
+use tests::support::TestBody;
+
+fn handle(id: u32) -> Handle<TestBody> {
+    Handle::new(Body::new(ID(id), BodyDef::default(), ()))
+}
+
+fn ids(event: &OverlapEvent<TestBody>) -> (ID, ID) {
+    let (handle_0, handle_1) = event.handles();
+
+    (handle_0.borrow().id(), handle_1.borrow().id())
+}
+
+#[test]
+fn it_generates_enter_events_for_new_pairs() {
+    let mut tracker = OverlapTracker::new();
+    let (handle_0, handle_1) = (handle(0), handle(1));
+
+    let events = tracker.update(vec!((handle_0, handle_1)));
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(ids(&events[0]), (ID(0), ID(1)));
+    match events[0] {
+        OverlapEvent::Enter(..) => (),
+        ref event => panic!("expected an enter event, but got {:?}", event),
+    }
+}
+
+#[test]
+fn it_generates_stay_events_for_pairs_that_are_still_overlapping() {
+    let mut tracker = OverlapTracker::new();
+    let (handle_0, handle_1) = (handle(0), handle(1));
+
+    tracker.update(vec!((handle_0.clone(), handle_1.clone())));
+    let events = tracker.update(vec!((handle_1, handle_0)));
+
+    assert_eq!(events.len(), 1);
+    match events[0] {
+        OverlapEvent::Stay(..) => (),
+        ref event => panic!("expected a stay event, but got {:?}", event),
+    }
+}
+
+#[test]
+fn it_generates_exit_events_for_pairs_no_longer_overlapping() {
+    let mut tracker = OverlapTracker::new();
+    let (handle_0, handle_1) = (handle(0), handle(1));
+
+    tracker.update(vec!((handle_0, handle_1)));
+    let events = tracker.update(vec!());
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(ids(&events[0]), (ID(0), ID(1)));
+    match events[0] {
+        OverlapEvent::Exit(..) => (),
+        ref event => panic!("expected an exit event, but got {:?}", event),
+    }
+
+    assert_eq!(tracker.update(vec!()).len(), 0);
+}
@@ -41,3 +41,27 @@ fn it_returns_an_error_with_coplanar_points() {
             panic!("expected a coplanar error but got {:?} instead", other_error),
     }
 }
+
+#[test]
+fn it_ignores_repeated_vertices() {
+    let corners: Vec<Vec3D> = (0..8)
+        .map(|index| Vec3D::new(
+            if index & 1 == 0 { -0.5 } else { 0.5 },
+            if index & 2 == 0 { -0.5 } else { 0.5 },
+            if index & 4 == 0 { -0.5 } else { 0.5 },
+        ))
+        .collect();
+    let vertices: Vec<Vec3D> = corners.iter()
+        .chain(corners.iter())
+        .chain(corners.iter().take(1))
+        .cloned()
+        .collect();
+
+    let polyhedron = ConvexPolyhedron::from_vertices(&vertices)
+        .expect("expected the polyhedron to be created but it was not");
+    let triangulation: Vec<[usize; 3]> = polyhedron.triangulation_iter().cloned().collect();
+
+    assert_eq!(triangulation.len(), 12);
+    assert!(triangulation.iter().all(|face| face.iter().all(|&index| index < corners.len())),
+        "expected only the first of each repeated vertex to be used but found {:?}", triangulation);
+}
@@ -1,6 +1,6 @@
 extern crate quickcheck;
 
-use maths::{UnitVec3D, Vec3D};
+use maths::{CrossProduct, DotProduct, UnitVec3D, Vec3D};
 use collisions::shapes::Direction;
 use collisions::shapes::convex_shapes::{ConvexShape, Cuboid};
 use collisions::shapes::behaviour::support_map_behaviour;
@@ -62,6 +62,18 @@ fn computing_the_volume() {
     assert_eq!(c.volume(), 24.0);
 }
 
+#[test]
+fn covering_the_surface_with_triangles() {
+    let cuboid = Cuboid::new(2.0, 3.0, 4.0);
+
+    let triangles: Vec<[Vec3D; 3]> = cuboid.triangles_iter().collect();
+    let enclosed_volume = triangles.iter()
+        .fold(0.0, |volume, triangle| volume + triangle[0].cross(triangle[1]).dot(triangle[2]).abs() / 6.0);
+
+    assert_eq!(triangles.len(), 12);
+    assert_approx_eq!(enclosed_volume, 24.0);
+}
+
 quickcheck! {
     fn it_behaves_like_a_support_map(cuboid: Cuboid, direction: UnitVec3D) -> quickcheck::TestResult {
         quickcheck_expect!(support_map_behaviour(Box::new(cuboid) as Box<ConvexShape>, direction));
@@ -0,0 +1,44 @@
+use std::rc::Rc;
+
+use maths::{Matrix, Vec3D};
+use collisions::shapes::convex_shapes::{ConvexShape, Cuboid, TriangleMesh};
+
+fn cube_mesh(offset: Vec3D) -> TriangleMesh {
+    let vertices = vec!(
+        Vec3D::new(0.0, 0.0, 0.0), Vec3D::new(1.0, 0.0, 0.0),
+        Vec3D::new(1.0, 1.0, 0.0), Vec3D::new(0.0, 1.0, 0.0),
+        Vec3D::new(0.0, 0.0, 1.0), Vec3D::new(1.0, 0.0, 1.0),
+        Vec3D::new(1.0, 1.0, 1.0), Vec3D::new(0.0, 1.0, 1.0),
+    ).into_iter().map(|vertex| vertex + offset).collect();
+
+    TriangleMesh::new(Rc::new(vertices), vec!(
+        (0, 2, 1), (0, 3, 2),
+        (4, 5, 6), (4, 6, 7),
+        (0, 1, 5), (0, 5, 4),
+        (1, 2, 6), (1, 6, 5),
+        (2, 3, 7), (2, 7, 6),
+        (3, 0, 4), (3, 4, 7),
+    ))
+}
+
+#[test]
+fn it_computes_the_volume_of_the_enclosed_solid() {
+    let mesh = cube_mesh(Vec3D::new(1.0, 2.0, 3.0));
+
+    assert_approx_eq!(mesh.volume(), 1.0);
+}
+
+#[test]
+fn it_computes_the_center_of_mass_away_from_the_local_origin() {
+    let mesh = cube_mesh(Vec3D::new(1.0, 2.0, 3.0));
+
+    assert_approx_eq!(mesh.center_of_mass(), Vec3D::new(1.5, 2.5, 3.5));
+}
+
+#[test]
+fn it_computes_the_normalized_inertia_about_the_center_of_mass() {
+    let mesh = cube_mesh(Vec3D::new(1.0, 2.0, 3.0));
+    let expected: Matrix = Cuboid::cube(1.0).inertia();
+
+    assert_eq!(mesh.inertia(), expected);
+}
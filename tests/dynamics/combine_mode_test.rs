@@ -0,0 +1,17 @@
+use dynamics::CombineMode;
+
+#[test]
+fn it_combines_coefficients_using_each_mode() {
+    assert_approx_eq!(CombineMode::Average.combine(0.2, 0.8), 0.5);
+    assert_approx_eq!(CombineMode::GeometricMean.combine(0.2, 0.8), 0.4);
+    assert_approx_eq!(CombineMode::Minimum.combine(0.2, 0.8), 0.2);
+    assert_approx_eq!(CombineMode::Multiply.combine(0.2, 0.8), 0.16);
+    assert_approx_eq!(CombineMode::Maximum.combine(0.2, 0.8), 0.8);
+}
+
+#[test]
+fn it_uses_the_mode_declared_last_when_the_modes_differ() {
+    assert_eq!(CombineMode::Average.dominant(CombineMode::Minimum), CombineMode::Minimum);
+    assert_eq!(CombineMode::Maximum.dominant(CombineMode::Multiply), CombineMode::Maximum);
+    assert_eq!(CombineMode::GeometricMean.dominant(CombineMode::Average), CombineMode::GeometricMean);
+}
@@ -0,0 +1,72 @@
+use {ID, Scalar};
+use dynamics::{ContactEvent, ContactEventKind};
+
+fn event(kind: ContactEventKind, normal_impulse: Scalar, relative_speed: Scalar) -> ContactEvent {
+    ContactEvent::new(kind, (ID(0), ID(1)), normal_impulse, relative_speed)
+}
+
+#[test]
+fn it_merges_the_events_of_a_pair_into_a_single_started_event() {
+    let events = ContactEvent::merge(&[
+        event(ContactEventKind::Started, 1.0, 3.0),
+        event(ContactEventKind::Persisted, 0.5, 2.0),
+        event(ContactEventKind::Persisted, 0.25, 1.0),
+    ]);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind(), ContactEventKind::Started);
+    assert_eq!(events[0].ids(), (ID(0), ID(1)));
+    assert_approx_eq!(events[0].normal_impulse(), 1.75);
+    assert_approx_eq!(events[0].relative_speed(), 3.0);
+}
+
+#[test]
+fn it_merges_the_events_of_a_pair_touching_throughout_into_a_persisted_event() {
+    let events = ContactEvent::merge(&[
+        event(ContactEventKind::Persisted, 1.0, 3.0),
+        event(ContactEventKind::Persisted, 0.5, 2.0),
+    ]);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind(), ContactEventKind::Persisted);
+    assert_approx_eq!(events[0].normal_impulse(), 1.5);
+}
+
+#[test]
+fn it_merges_the_events_of_a_pair_separating_into_an_ended_event() {
+    let events = ContactEvent::merge(&[
+        event(ContactEventKind::Persisted, 1.0, 3.0),
+        event(ContactEventKind::Ended, 0.0, 2.0),
+    ]);
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind(), ContactEventKind::Ended);
+    assert_approx_eq!(events[0].normal_impulse(), 0.0);
+    assert_approx_eq!(events[0].relative_speed(), 2.0);
+}
+
+#[test]
+fn it_reports_pairs_touching_briefly_with_started_and_ended_events() {
+    let events = ContactEvent::merge(&[
+        event(ContactEventKind::Started, 1.0, 3.0),
+        event(ContactEventKind::Ended, 0.0, 2.0),
+    ]);
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].kind(), ContactEventKind::Started);
+    assert_approx_eq!(events[0].normal_impulse(), 1.0);
+    assert_eq!(events[1].kind(), ContactEventKind::Ended);
+}
+
+#[test]
+fn it_keeps_the_pairs_in_the_order_they_first_appear() {
+    let events = ContactEvent::merge(&[
+        ContactEvent::new(ContactEventKind::Started, (ID(2), ID(3)), 1.0, 0.0),
+        ContactEvent::new(ContactEventKind::Started, (ID(1), ID(0)), 1.0, 0.0),
+        ContactEvent::new(ContactEventKind::Persisted, (ID(2), ID(3)), 1.0, 0.0),
+    ]);
+
+    assert_eq!(events.len(), 2);
+    assert_eq!(events[0].ids(), (ID(2), ID(3)));
+    assert_eq!(events[1].ids(), (ID(1), ID(0)));
+}
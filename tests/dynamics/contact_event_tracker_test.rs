@@ -0,0 +1,90 @@
+use ID;
+use maths::{UnitVec3D, Vec3D};
+use utils::Handle;
+use dynamics::{ContactEventKind, ContactEventTracker, DynamicBodyExtension, FixedBodyData, FixedBodyDef, RigidBodyData, RigidBodyDef};
+use collisions::{Body, BodyDef, CollisionObject, Contact, ContactSet};
+use collisions::narrowphase::NullNarrowphase;
+
+type TestBody = Body<DynamicBodyExtension<()>, NullNarrowphase>;
+
+fn rigid_body(id: u32, velocity: Vec3D) -> Handle<TestBody> {
+    let data = RigidBodyData::new(&RigidBodyDef {
+        velocity: velocity,
+        .. RigidBodyDef::default()
+    }, ());
+
+    Handle::new(Body::new(ID(id), BodyDef::default(), DynamicBodyExtension::Rigid(Box::new(data))))
+}
+
+fn fixed_body(id: u32) -> Handle<TestBody> {
+    let data = FixedBodyData::new(&FixedBodyDef::default(), ());
+
+    Handle::new(Body::new(ID(id), BodyDef::default(), DynamicBodyExtension::Fixed(Box::new(data))))
+}
+
+fn contact(handle_0: &Handle<TestBody>, handle_1: &Handle<TestBody>) -> Contact<TestBody> {
+    let set = ContactSet::with_penetration_depths(
+        UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0)),
+        vec!(Vec3D::new(0.5, 0.0, 0.0)),
+        vec!(0.01),
+    );
+
+    Contact::new(set, handle_0.clone(), handle_1.clone())
+}
+
+#[test]
+fn it_measures_the_relative_speed_at_the_contact_center() {
+    let handle_0 = rigid_body(0, Vec3D::new(1.0, 0.0, 0.0));
+    let handle_1 = rigid_body(1, Vec3D::new(-2.0, 0.0, 0.0));
+
+    let relative_speed = ContactEventTracker::relative_speed_of(&contact(&handle_0, &handle_1));
+
+    assert_approx_eq!(relative_speed, 3.0);
+}
+
+#[test]
+fn it_generates_started_events_for_new_contacts() {
+    let mut tracker = ContactEventTracker::new();
+    let handle_0 = rigid_body(0, Vec3D::new(1.0, 0.0, 0.0));
+    let handle_1 = fixed_body(1);
+
+    let events = tracker.update(&vec!(contact(&handle_0, &handle_1)), &vec!(1.0), &vec!(2.5));
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind(), ContactEventKind::Started);
+    assert_eq!(events[0].ids(), (ID(0), ID(1)));
+    assert_approx_eq!(events[0].relative_speed(), 1.0);
+    assert_approx_eq!(events[0].normal_impulse(), 2.5);
+}
+
+#[test]
+fn it_generates_persisted_events_for_contacts_in_consecutive_updates() {
+    let mut tracker = ContactEventTracker::new();
+    let handle_0 = rigid_body(0, Vec3D::new(1.0, 0.0, 0.0));
+    let handle_1 = fixed_body(1);
+
+    tracker.update(&vec!(contact(&handle_0, &handle_1)), &vec!(1.0), &vec!(2.5));
+    let events = tracker.update(&vec!(contact(&handle_0, &handle_1)), &vec!(0.0), &vec!(0.5));
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind(), ContactEventKind::Persisted);
+    assert_approx_eq!(events[0].normal_impulse(), 0.5);
+}
+
+#[test]
+fn it_generates_ended_events_for_contacts_no_longer_present() {
+    let mut tracker = ContactEventTracker::new();
+    let handle_0 = rigid_body(0, Vec3D::new(1.0, 0.0, 0.0));
+    let handle_1 = fixed_body(1);
+
+    tracker.update(&vec!(contact(&handle_0, &handle_1)), &vec!(1.0), &vec!(2.5));
+    let events = tracker.update(&vec!(), &vec!(), &vec!());
+
+    assert_eq!(events.len(), 1);
+    assert_eq!(events[0].kind(), ContactEventKind::Ended);
+    assert_eq!(events[0].ids(), (ID(0), ID(1)));
+    assert_approx_eq!(events[0].normal_impulse(), 0.0);
+    assert_approx_eq!(events[0].relative_speed(), 1.0);
+
+    assert_eq!(tracker.update(&vec!(), &vec!(), &vec!()).len(), 0);
+}
@@ -0,0 +1,100 @@
+use {PI, Scalar};
+use maths::{Transform, UnitVec3D, Vec3D};
+use dynamics::{ForceField, ForceFieldSample};
+use dynamics::force_fields::{FluidVolume, Submersion};
+use collisions::CollisionData;
+use collisions::shapes::convex_shapes::{ConvexShape, Cuboid, Sphere};
+
+fn water() -> FluidVolume {
+    FluidVolume::half_space(Vec3D::zero(), Vec3D::new(0.0, 0.0, 1.0).normalize(), 1.0)
+}
+
+fn submersion_of<S: ConvexShape + 'static>(fluid: &FluidVolume, shape: S, transform: Transform) -> Option<Submersion> {
+    fluid.submersion_of(&CollisionData::new(Box::new(shape), transform))
+}
+
+#[test]
+fn it_submerges_the_part_of_a_cube_below_the_surface() {
+    let submersion = submersion_of(&water(), Cuboid::cube(2.0), Transform::identity().with_translation(1.0, 2.0, 0.5))
+        .expect("expected the cube to be submerged but was not");
+
+    assert_approx_eq!(submersion.volume(), 2.0);
+    assert_approx_eq!(submersion.center_of_buoyancy(), Vec3D::new(1.0, 2.0, -0.25));
+}
+
+#[test]
+fn it_clips_a_rotated_cube_against_the_surface() {
+    let axis = UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0));
+    let submersion = submersion_of(&water(), Cuboid::cube(2.0), Transform::identity().rotate(axis, PI / 4.0))
+        .expect("expected the cube to be submerged but was not");
+
+    assert_approx_eq!(submersion.volume(), 4.0);
+    assert_approx_eq!(submersion.center_of_buoyancy(), Vec3D::new(0.0, 0.0, -(2.0 as Scalar).sqrt() / 3.0));
+}
+
+#[test]
+fn it_submerges_all_of_a_body_below_the_surface() {
+    let submersion = submersion_of(&water(), Cuboid::cube(2.0), Transform::identity().with_translation(0.0, 0.0, -5.0))
+        .expect("expected the cube to be submerged but was not");
+
+    assert_approx_eq!(submersion.volume(), 8.0);
+    assert_approx_eq!(submersion.center_of_buoyancy(), Vec3D::new(0.0, 0.0, -5.0));
+}
+
+#[test]
+fn it_does_not_submerge_a_body_above_the_surface() {
+    let submersion = submersion_of(&water(), Cuboid::cube(2.0), Transform::identity().with_translation(0.0, 0.0, 5.0));
+
+    assert!(submersion.is_none(), "expected no submersion but found {:?}", submersion);
+}
+
+#[test]
+fn it_submerges_the_cap_of_a_sphere_below_the_surface() {
+    let submersion = submersion_of(&water(), Sphere::new(1.0), Transform::identity())
+        .expect("expected the sphere to be submerged but was not");
+
+    assert_approx_eq!(submersion.volume(), PI * 2.0 / 3.0);
+    assert_approx_eq!(submersion.center_of_buoyancy(), Vec3D::new(0.0, 0.0, -0.375));
+}
+
+#[test]
+fn it_clips_bodies_against_every_side_of_an_axis_aligned_volume() {
+    let fluid = FluidVolume::axis_aligned(Vec3D::new(0.0, 0.0, -10.0), Vec3D::new(10.0, 10.0, 0.0), 1.0);
+    let submersion = submersion_of(&fluid, Cuboid::cube(2.0), Transform::identity())
+        .expect("expected the cube to be submerged but was not");
+
+    assert_approx_eq!(submersion.volume(), 1.0);
+    assert_approx_eq!(submersion.center_of_buoyancy(), Vec3D::new(0.5, 0.5, -0.5));
+}
+
+#[test]
+fn it_submerges_the_quarter_of_a_sphere_centered_on_an_edge_of_an_axis_aligned_volume() {
+    let fluid = FluidVolume::axis_aligned(Vec3D::new(0.0, -10.0, -10.0), Vec3D::new(10.0, 10.0, 0.0), 1.0);
+    let submersion = submersion_of(&fluid, Sphere::new(1.0), Transform::identity())
+        .expect("expected the sphere to be submerged but was not");
+    let expected_center_of_buoyancy = Vec3D::new(0.375, 0.0, -0.375);
+
+    // the sphere is clipped as an approximating polyhedron
+    assert!((submersion.volume() - PI / 3.0).abs() < 0.01,
+        "expected a volume of {} but found {}", PI / 3.0, submersion.volume());
+    assert!((submersion.center_of_buoyancy() - expected_center_of_buoyancy).length() < 0.01,
+        "expected the center of buoyancy at {:?} but found {:?}", expected_center_of_buoyancy, submersion.center_of_buoyancy());
+}
+
+#[test]
+fn it_applies_buoyancy_at_the_center_of_buoyancy_and_drags_the_body() {
+    let fluid = water()
+        .with_linear_drag(10.0)
+        .with_angular_drag(4.0);
+    let collision_data = CollisionData::new(Box::new(Cuboid::cube(2.0)), Transform::identity());
+    let sample = ForceFieldSample::new(&collision_data, Vec3D::new(1.0, 0.0, 0.0), 1.0)
+        .with_velocity(Vec3D::new(2.0, 0.0, 0.0))
+        .with_angular_velocity(Vec3D::new(0.0, 0.0, 1.0))
+        .with_gravity(Vec3D::new(0.0, 0.0, -10.0));
+
+    let (force, torque) = fluid.force_and_torque_on(&sample);
+
+    // half of the cube is submerged, which halves the drag
+    assert_approx_eq!(force, Vec3D::new(-10.0, 0.0, 40.0));
+    assert_approx_eq!(torque, Vec3D::new(0.0, 40.0, -2.0));
+}
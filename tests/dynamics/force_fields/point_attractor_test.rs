@@ -0,0 +1,29 @@
+use maths::{Transform, Vec3D};
+use dynamics::{ForceField, ForceFieldSample};
+use dynamics::force_fields::PointAttractor;
+use collisions::CollisionData;
+use collisions::shapes::convex_shapes::Cuboid;
+
+fn force_at(attractor: &PointAttractor, center_of_mass: Vec3D) -> Vec3D {
+    let collision_data = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::identity());
+    let sample = ForceFieldSample::new(&collision_data, center_of_mass, 2.0);
+
+    attractor.force_and_torque_on(&sample).0
+}
+
+#[test]
+fn it_pulls_bodies_towards_the_center_with_the_inverse_square_of_the_distance() {
+    let attractor = PointAttractor::new(Vec3D::new(1.0, 0.0, 0.0), 8.0);
+
+    assert_approx_eq!(force_at(&attractor, Vec3D::new(1.0, 2.0, 0.0)), Vec3D::new(0.0, -4.0, 0.0));
+    assert_approx_eq!(force_at(&attractor, Vec3D::new(1.0, 0.0, -4.0)), Vec3D::new(0.0, 0.0, 1.0));
+}
+
+#[test]
+fn it_limits_the_force_within_the_min_distance() {
+    let attractor = PointAttractor::new(Vec3D::zero(), 8.0)
+        .with_min_distance(2.0);
+
+    assert_approx_eq!(force_at(&attractor, Vec3D::new(0.5, 0.0, 0.0)), Vec3D::new(-4.0, 0.0, 0.0));
+    assert_approx_eq!(force_at(&attractor, Vec3D::zero()), Vec3D::zero());
+}
@@ -0,0 +1,30 @@
+use maths::{Transform, UnitVec3D, Vec3D};
+use dynamics::{ForceField, ForceFieldSample};
+use dynamics::force_fields::Vortex;
+use collisions::CollisionData;
+use collisions::shapes::convex_shapes::Cuboid;
+
+fn force_at(vortex: &Vortex, center_of_mass: Vec3D) -> Vec3D {
+    let collision_data = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::identity());
+    let sample = ForceFieldSample::new(&collision_data, center_of_mass, 2.0);
+
+    vortex.force_and_torque_on(&sample).0
+}
+
+#[test]
+fn it_swirls_bodies_counter_clockwise_around_the_axis() {
+    let vortex = Vortex::new(Vec3D::zero(), UnitVec3D::from(Vec3D::new(0.0, 0.0, 1.0)), 4.0);
+
+    // the height along the axis does not matter
+    assert_approx_eq!(force_at(&vortex, Vec3D::new(2.0, 0.0, 3.0)), Vec3D::new(0.0, 4.0, 0.0));
+    assert_approx_eq!(force_at(&vortex, Vec3D::new(0.0, 4.0, -1.0)), Vec3D::new(-2.0, 0.0, 0.0));
+}
+
+#[test]
+fn it_grows_linearly_within_the_core() {
+    let vortex = Vortex::new(Vec3D::zero(), UnitVec3D::from(Vec3D::new(0.0, 0.0, 1.0)), 4.0)
+        .with_core_radius(2.0);
+
+    assert_approx_eq!(force_at(&vortex, Vec3D::new(1.0, 0.0, 0.0)), Vec3D::new(0.0, 2.0, 0.0));
+    assert_approx_eq!(force_at(&vortex, Vec3D::new(0.0, 0.0, 1.0)), Vec3D::zero());
+}
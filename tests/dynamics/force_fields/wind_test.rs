@@ -0,0 +1,18 @@
+use maths::{Transform, Vec3D};
+use dynamics::{ForceField, ForceFieldSample};
+use dynamics::force_fields::Wind;
+use collisions::CollisionData;
+use collisions::shapes::convex_shapes::Cuboid;
+
+#[test]
+fn it_drags_bodies_towards_the_velocity_of_the_wind() {
+    let wind = Wind::new(Vec3D::new(5.0, 0.0, 0.0), 0.5);
+    let collision_data = CollisionData::new(Box::new(Cuboid::cube(1.0)), Transform::identity());
+    let sample = ForceFieldSample::new(&collision_data, Vec3D::new(3.0, 2.0, 1.0), 1.0)
+        .with_velocity(Vec3D::new(1.0, 2.0, 0.0));
+
+    let (force, torque) = wind.force_and_torque_on(&sample);
+
+    assert_approx_eq!(force, Vec3D::new(2.0, -1.0, 0.0));
+    assert_approx_eq!(torque, Vec3D::zero());
+}
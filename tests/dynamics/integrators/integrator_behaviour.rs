@@ -5,6 +5,7 @@ macro_rules! assert_integrator_behaviour(
         mod integrator_behaviour {
             use super::test_subject;
 
+            use {PI, Scalar};
             use maths::{Motion, Transform, UnitQuat, UnitVec3D, Vec3D};
             use dynamics::{Integratable, Integrator};
 
@@ -21,7 +22,7 @@ macro_rules! assert_integrator_behaviour(
                 {
                     let mut integratable = Integratable::new(&mut transform, &mut motion);
 
-                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero());
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::zero());
                 }
 
                 assert_approx_eq!(transform.translation, Vec3D::zero());
@@ -41,8 +42,8 @@ macro_rules! assert_integrator_behaviour(
                 {
                     let mut integratable = Integratable::new(&mut transform, &mut motion);
 
-                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero());
-                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero());
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::zero());
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::zero());
                 }
 
                 assert_approx_eq!(motion.velocity, Vec3D::new(1.0, 0.0, 0.0));
@@ -59,7 +60,7 @@ macro_rules! assert_integrator_behaviour(
                 {
                     let mut integratable = Integratable::new(&mut transform, &mut motion);
 
-                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero());
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::zero());
                 }
 
                 assert_approx_eq!(transform.translation, Vec3D::new(0.5, 0.0, 0.0));
@@ -76,12 +77,186 @@ macro_rules! assert_integrator_behaviour(
                 {
                     let mut integratable = Integratable::new(&mut transform, &mut motion);
 
-                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::new(1.0, 0.0, 0.0));
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::new(1.0, 0.0, 0.0), Vec3D::zero());
                 }
 
                 assert_approx_eq!(transform.translation.normalize(), UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0)));
                 assert_approx_eq!(motion.velocity, Vec3D::new(1.5, 0.0, 0.0));
             }
+
+            #[test]
+            fn it_correctly_integrates_simple_constant_torque_angular_motion() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary()
+                    .with_angular_velocity(0.0, 0.0, 1.0);
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::new(0.0, 0.0, 1.0));
+                }
+
+                assert_approx_eq!(transform.translation, Vec3D::zero());
+                assert_approx_eq!(motion.velocity, Vec3D::zero());
+                assert_approx_eq!(motion.angular_velocity, Vec3D::new(0.0, 0.0, 1.5));
+                assert!(transform.rotation.rotate(Vec3D::new(1.0, 0.0, 0.0)).y > 0.0);
+            }
+
+            #[test]
+            fn it_completes_an_exact_number_of_revolutions_at_high_angular_velocity() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary()
+                    .with_angular_velocity(0.0, 0.0, 50.0);
+                let revolutions = 10.0;
+                let steps = 600;
+                let time_step = revolutions * 2.0 * PI / 50.0 / steps as Scalar;
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+                    for _ in 0..steps {
+                        integrator.integrate_in_place(&mut integratable, time_step, Vec3D::zero(), Vec3D::zero());
+                    }
+                }
+
+                assert_approx_eq!(transform.rotation.rotate(Vec3D::new(1.0, 0.0, 0.0)), Vec3D::new(1.0, 0.0, 0.0));
+                assert_approx_eq!(motion.angular_velocity, Vec3D::new(0.0, 0.0, 50.0));
+            }
+
+            #[test]
+            fn it_does_not_damp_the_motion_without_damping() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary()
+                    .with_velocity(1.0, 0.0, 0.0)
+                    .with_angular_velocity(0.0, 1.0, 0.0);
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion)
+                        .with_damping(0.0, 0.0);
+
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::zero());
+                }
+
+                assert_approx_eq!(motion.velocity, Vec3D::new(1.0, 0.0, 0.0));
+                assert_approx_eq!(motion.angular_velocity, Vec3D::new(0.0, 1.0, 0.0));
+            }
+
+            #[test]
+            fn it_damps_the_velocity_and_angular_velocity() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary()
+                    .with_velocity(1.0, 0.0, 0.0)
+                    .with_angular_velocity(0.0, 1.0, 0.0);
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion)
+                        .with_damping(0.5, 2.0);
+
+                    integrator.integrate_in_place(&mut integratable, 0.5, Vec3D::zero(), Vec3D::zero());
+                }
+
+                // each step divides the velocities by (1 + damping * time_step)
+                assert_approx_eq!(motion.velocity, Vec3D::new(0.8, 0.0, 0.0));
+                assert_approx_eq!(motion.angular_velocity, Vec3D::new(0.0, 0.5, 0.0));
+                // the velocity is damped before the body is moved
+                assert!(transform.translation.x > 0.0 && transform.translation.x < 0.5);
+                assert_approx_eq!(transform.translation.x, 0.4);
+            }
+
+            #[test]
+            fn it_compounds_the_damping_over_several_steps() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary()
+                    .with_velocity(1.0, 0.0, 0.0)
+                    .with_angular_velocity(0.0, 1.0, 0.0);
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion)
+                        .with_damping(1.0, 3.0);
+
+                    for _ in 0..10 {
+                        integrator.integrate_in_place(&mut integratable, 0.1, Vec3D::zero(), Vec3D::zero());
+                    }
+                }
+
+                let expected_speed = (1.0 as Scalar / 1.1).powi(10);
+                let expected_angular_speed = (1.0 as Scalar / 1.3).powi(10);
+                assert_approx_eq!(motion.velocity, Vec3D::new(expected_speed, 0.0, 0.0));
+                assert_approx_eq!(motion.angular_velocity, Vec3D::new(0.0, expected_angular_speed, 0.0));
+                // the sum of the damped velocities over each step
+                let expected_distance = (1..11).fold(0.0, |total, step| total + (1.0 as Scalar / 1.1).powi(step) * 0.1);
+                assert_approx_eq!(transform.translation.x, expected_distance);
+            }
+
+            #[test]
+            fn it_brings_damped_motion_to_rest() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary()
+                    .with_velocity(1.0, 0.0, 0.0)
+                    .with_angular_velocity(0.0, 1.0, 0.0);
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion)
+                        .with_damping(1.0, 1.0);
+
+                    for _ in 0..1000 {
+                        integrator.integrate_in_place(&mut integratable, 0.1, Vec3D::zero(), Vec3D::zero());
+                    }
+                }
+
+                assert_approx_eq!(motion.velocity, Vec3D::zero());
+                assert_approx_eq!(motion.angular_velocity, Vec3D::zero());
+            }
+
+            #[test]
+            fn it_follows_the_analytic_solution_for_a_position_dependent_acceleration() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity().translate(1.0, 0.0, 0.0);
+                let mut motion = Motion::stationary();
+                let time_step = 0.001;
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+                    for step in 0..1000 {
+                        let time = step as Scalar * time_step;
+
+                        integrator.integrate_with_acceleration(&mut integratable, time, time_step, &|position, _velocity, _time| position * -4.0, Vec3D::zero());
+                    }
+                }
+
+                let expected_position: Scalar = (2.0 as Scalar).cos();
+                let expected_velocity: Scalar = -2.0 * (2.0 as Scalar).sin();
+                assert!((transform.translation.x - expected_position).abs() < 0.01);
+                assert!((motion.velocity.x - expected_velocity).abs() < 0.01);
+            }
+
+            #[test]
+            fn it_follows_the_analytic_solution_for_a_time_dependent_acceleration() {
+                let integrator = validate(test_subject());
+                let mut transform = Transform::identity();
+                let mut motion = Motion::stationary();
+                let time_step = 0.001;
+
+                {
+                    let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+                    for step in 0..1000 {
+                        let time = step as Scalar * time_step;
+
+                        integrator.integrate_with_acceleration(&mut integratable, time, time_step, &|_position, _velocity, time| Vec3D::new(time, 0.0, 0.0), Vec3D::zero());
+                    }
+                }
+
+                assert!((transform.translation.x - 1.0 / 6.0).abs() < 0.01);
+                assert!((motion.velocity.x - 0.5).abs() < 0.01);
+            }
         }
     );
 );
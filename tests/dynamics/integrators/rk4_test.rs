@@ -0,0 +1,59 @@
+assert_integrator_behaviour! {
+    use dynamics::integrators::RK4;
+
+    pub fn test_subject() -> RK4 {
+        RK4::new()
+    }
+}
+
+use Scalar;
+use maths::{Motion, Transform, Vec3D};
+use dynamics::{Integratable, Integrator};
+use tests::support::assert_conserves_angular_momentum;
+
+#[test]
+fn it_accurately_integrates_a_spring_with_large_time_steps() {
+    let integrator = RK4::new();
+    let mut transform = Transform::identity().translate(1.0, 0.0, 0.0);
+    let mut motion = Motion::stationary();
+
+    {
+        let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+        for step in 0..10 {
+            let time = step as Scalar * 0.1;
+
+            integrator.integrate_with_acceleration(&mut integratable, time, 0.1, &|position, _velocity, _time| position * -4.0, Vec3D::zero());
+        }
+    }
+
+    let expected_position: Scalar = (2.0 as Scalar).cos();
+    let expected_velocity: Scalar = -2.0 * (2.0 as Scalar).sin();
+    assert!((transform.translation.x - expected_position).abs() < 1e-3);
+    assert!((motion.velocity.x - expected_velocity).abs() < 1e-3);
+}
+
+#[test]
+fn it_exactly_integrates_an_acceleration_increasing_linearly_with_time() {
+    let integrator = RK4::new();
+    let mut transform = Transform::identity();
+    let mut motion = Motion::stationary();
+
+    {
+        let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+        for step in 0..4 {
+            let time = step as Scalar * 0.25;
+
+            integrator.integrate_with_acceleration(&mut integratable, time, 0.25, &|_position, _velocity, time| Vec3D::new(time, 0.0, 0.0), Vec3D::zero());
+        }
+    }
+
+    assert_approx_eq!(transform.translation, Vec3D::new(1.0 / 6.0, 0.0, 0.0));
+    assert_approx_eq!(motion.velocity, Vec3D::new(0.5, 0.0, 0.0));
+}
+
+#[test]
+fn it_conserves_angular_momentum_with_the_gyroscopic_torque() {
+    assert_conserves_angular_momentum(RK4::new().with_gyroscopic_torque());
+}
@@ -2,6 +2,13 @@ assert_integrator_behaviour! {
     use dynamics::integrators::SemiImplicitEuler;
 
     pub fn test_subject() -> SemiImplicitEuler {
-        SemiImplicitEuler
+        SemiImplicitEuler::new()
     }
 }
+
+use tests::support::assert_conserves_angular_momentum;
+
+#[test]
+fn it_conserves_angular_momentum_with_the_gyroscopic_torque() {
+    assert_conserves_angular_momentum(SemiImplicitEuler::new().with_gyroscopic_torque());
+}
@@ -0,0 +1,56 @@
+assert_integrator_behaviour! {
+    use dynamics::integrators::VelocityVerlet;
+
+    pub fn test_subject() -> VelocityVerlet {
+        VelocityVerlet::new()
+    }
+}
+
+use Scalar;
+use maths::{Motion, Transform, Vec3D};
+use dynamics::{Integratable, Integrator};
+use tests::support::assert_conserves_angular_momentum;
+
+#[test]
+fn it_keeps_the_energy_of_a_spring_bounded() {
+    let integrator = VelocityVerlet::new();
+    let mut transform = Transform::identity().translate(1.0, 0.0, 0.0);
+    let mut motion = Motion::stationary();
+
+    {
+        let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+        for step in 0..2000 {
+            let time = step as Scalar * 0.05;
+
+            integrator.integrate_with_acceleration(&mut integratable, time, 0.05, &|position, _velocity, _time| position * -4.0, Vec3D::zero());
+        }
+    }
+
+    let energy = 0.5 * motion.velocity.squared_length() + 2.0 * transform.translation.squared_length();
+    assert!((energy - 2.0).abs() < 0.02);
+}
+
+#[test]
+fn it_exactly_integrates_the_velocity_for_an_acceleration_increasing_linearly_with_time() {
+    let integrator = VelocityVerlet::new();
+    let mut transform = Transform::identity();
+    let mut motion = Motion::stationary();
+
+    {
+        let mut integratable = Integratable::new(&mut transform, &mut motion);
+
+        for step in 0..4 {
+            let time = step as Scalar * 0.25;
+
+            integrator.integrate_with_acceleration(&mut integratable, time, 0.25, &|_position, _velocity, time| Vec3D::new(time, 0.0, 0.0), Vec3D::zero());
+        }
+    }
+
+    assert_approx_eq!(motion.velocity, Vec3D::new(0.5, 0.0, 0.0));
+}
+
+#[test]
+fn it_conserves_angular_momentum_with_the_gyroscopic_torque() {
+    assert_conserves_angular_momentum(VelocityVerlet::new().with_gyroscopic_torque());
+}
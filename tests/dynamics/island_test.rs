@@ -0,0 +1,47 @@
+use dynamics::Island;
+use collisions::Contact;
+use tests::support::{contact, fixed_body, rigid_body, DynamicTestBody};
+
+fn contact_indices_of(islands: Vec<Island>) -> Vec<Vec<usize>> {
+    islands.iter()
+        .map(|island| island.contact_indices().clone())
+        .collect()
+}
+
+#[test]
+fn it_groups_contacts_connected_through_rigid_bodies() {
+    let bodies = (0..5).map(rigid_body).collect::<Vec<_>>();
+    let contacts = vec!(
+        contact(&bodies[0], &bodies[1]),
+        contact(&bodies[2], &bodies[3]),
+        contact(&bodies[1], &bodies[4]),
+        contact(&bodies[3], &bodies[2]),
+    );
+
+    let islands = Island::partition(&contacts);
+
+    assert_eq!(contact_indices_of(islands), vec!(vec!(0, 2), vec!(1, 3)));
+}
+
+#[test]
+fn it_does_not_connect_contacts_through_fixed_bodies() {
+    let ground = fixed_body(0);
+    let bodies = (1..4).map(rigid_body).collect::<Vec<_>>();
+    let contacts = vec!(
+        contact(&bodies[0], &ground),
+        contact(&ground, &bodies[1]),
+        contact(&bodies[2], &ground),
+        contact(&bodies[1], &bodies[2]),
+    );
+
+    let islands = Island::partition(&contacts);
+
+    assert_eq!(contact_indices_of(islands), vec!(vec!(0), vec!(1, 2, 3)));
+}
+
+#[test]
+fn it_does_not_create_islands_without_contacts() {
+    let contacts: Vec<Contact<DynamicTestBody>> = Vec::new();
+
+    assert_eq!(Island::partition(&contacts), Vec::new());
+}
@@ -0,0 +1,31 @@
+use maths::{Matrix, Vec3D};
+use dynamics::MassProperties;
+use collisions::shapes::convex_shapes::{ConvexShape, Cuboid, Sphere};
+
+#[test]
+fn it_scales_the_normalized_inertia_by_the_mass() {
+    let shape = Cuboid::new(2.0, 1.0, 1.0);
+    let mass_properties = MassProperties::from_mass(&shape, 3.0);
+
+    assert_approx_eq!(mass_properties.mass, 3.0);
+    assert_approx_eq!(mass_properties.center_of_mass, Vec3D::zero());
+    assert_eq!(mass_properties.inertia, shape.inertia() * 3.0);
+}
+
+#[test]
+fn it_computes_the_mass_from_the_density_and_volume() {
+    let shape = Cuboid::new(2.0, 1.0, 1.0);
+    let mass_properties = MassProperties::from_density(&shape, 3.0);
+
+    assert_approx_eq!(mass_properties.mass, 6.0);
+    assert_eq!(mass_properties.inertia, Matrix::diag(1.0, 2.5, 2.5));
+}
+
+#[test]
+fn it_computes_the_inverse_properties() {
+    let shape = Sphere::new(1.0);
+    let mass_properties = MassProperties::from_mass(&shape, 2.5);
+
+    assert_approx_eq!(mass_properties.mass_inverse(), 0.4);
+    assert_eq!(mass_properties.inertia_inverse(), Matrix::identity());
+}
@@ -0,0 +1,173 @@
+use dynamics::{CombineMode, ContactMaterial, DynamicBodyExtension, MaterialData, MaterialRegistry, RigidBodyData, RigidBodyDef};
+
+fn rigid_body_with(def: RigidBodyDef) -> DynamicBodyExtension<()> {
+    DynamicBodyExtension::Rigid(Box::new(RigidBodyData::new(&def, ())))
+}
+
+fn named_rigid_body(name: &str) -> DynamicBodyExtension<()> {
+    rigid_body_with(RigidBodyDef {
+        material: Some(name.to_string()),
+        .. RigidBodyDef::default()
+    })
+}
+
+#[test]
+fn it_multiplies_the_coefficients_by_default() {
+    let registry = MaterialRegistry::new();
+    let body_0 = rigid_body_with(RigidBodyDef {
+        friction_coefficient: 0.5,
+        restitution_coefficient: 0.2,
+        .. RigidBodyDef::default()
+    });
+    let body_1 = rigid_body_with(RigidBodyDef {
+        friction_coefficient: 0.4,
+        restitution_coefficient: 0.5,
+        .. RigidBodyDef::default()
+    });
+
+    let material = registry.contact_material_between(&body_0, &body_1);
+
+    assert_approx_eq!(material.friction_coefficient, 0.2);
+    assert_approx_eq!(material.restitution_coefficient, 0.1);
+}
+
+#[test]
+fn it_averages_the_rolling_and_torsional_friction_by_default() {
+    let mut registry = MaterialRegistry::new();
+    registry.register("rubber", MaterialData {
+        rolling_friction: 0.2,
+        torsional_friction: 0.1,
+        .. MaterialData::default()
+    });
+
+    let material = registry.contact_material_between(&named_rigid_body("rubber"), &rigid_body_with(RigidBodyDef::default()));
+
+    assert_approx_eq!(material.rolling_friction, 0.1);
+    assert_approx_eq!(material.torsional_friction, 0.05);
+}
+
+#[test]
+fn it_combines_the_rolling_and_torsional_friction_using_their_own_combine_modes() {
+    let mut registry = MaterialRegistry::new();
+    registry.register("rubber", MaterialData {
+        rolling_friction: 0.2,
+        torsional_friction: 0.1,
+        rolling_friction_combine: CombineMode::Maximum,
+        torsional_friction_combine: CombineMode::Minimum,
+        .. MaterialData::default()
+    });
+
+    let material = registry.contact_material_between(&named_rigid_body("rubber"), &rigid_body_with(RigidBodyDef::default()));
+
+    assert_approx_eq!(material.rolling_friction, 0.2);
+    assert_approx_eq!(material.torsional_friction, 0.0);
+}
+
+#[test]
+fn it_combines_registered_materials_using_their_combine_modes() {
+    let mut registry = MaterialRegistry::new();
+    registry.register("ice", MaterialData {
+        friction_coefficient: 0.05,
+        restitution_coefficient: 0.1,
+        friction_combine: CombineMode::Minimum,
+        restitution_combine: CombineMode::Average,
+        .. MaterialData::default()
+    });
+    registry.register("rubber", MaterialData {
+        friction_coefficient: 0.9,
+        restitution_coefficient: 0.8,
+        friction_combine: CombineMode::Average,
+        restitution_combine: CombineMode::Maximum,
+        .. MaterialData::default()
+    });
+
+    let material = registry.contact_material_between(&named_rigid_body("ice"), &named_rigid_body("rubber"));
+
+    assert_approx_eq!(material.friction_coefficient, 0.05);
+    assert_approx_eq!(material.restitution_coefficient, 0.8);
+}
+
+#[test]
+fn it_uses_the_registered_material_instead_of_the_coefficients_of_the_body() {
+    let mut registry = MaterialRegistry::new();
+    registry.register("steel", MaterialData {
+        friction_coefficient: 0.5,
+        restitution_coefficient: 0.4,
+        .. MaterialData::default()
+    });
+    let body_0 = rigid_body_with(RigidBodyDef {
+        friction_coefficient: 0.0,
+        restitution_coefficient: 0.0,
+        material: Some("steel".to_string()),
+        .. RigidBodyDef::default()
+    });
+    let body_1 = rigid_body_with(RigidBodyDef {
+        friction_coefficient: 1.0,
+        restitution_coefficient: 1.0,
+        .. RigidBodyDef::default()
+    });
+
+    let material = registry.contact_material_between(&body_0, &body_1);
+
+    assert_approx_eq!(material.friction_coefficient, 0.5);
+    assert_approx_eq!(material.restitution_coefficient, 0.4);
+}
+
+#[test]
+fn it_falls_back_to_the_coefficients_of_the_body_when_its_material_is_not_registered() {
+    let registry = MaterialRegistry::new();
+    let body_0 = rigid_body_with(RigidBodyDef {
+        friction_coefficient: 0.5,
+        restitution_coefficient: 0.2,
+        material: Some("unobtainium".to_string()),
+        .. RigidBodyDef::default()
+    });
+    let body_1 = rigid_body_with(RigidBodyDef {
+        friction_coefficient: 0.4,
+        restitution_coefficient: 0.5,
+        .. RigidBodyDef::default()
+    });
+
+    let material = registry.contact_material_between(&body_0, &body_1);
+
+    assert_approx_eq!(material.friction_coefficient, 0.2);
+    assert_approx_eq!(material.restitution_coefficient, 0.1);
+}
+
+#[test]
+fn it_overrides_pairs_of_materials_regardless_of_order() {
+    let mut registry = MaterialRegistry::new();
+    registry.register("ice", MaterialData::default());
+    registry.register("rubber", MaterialData::default());
+    let overriding_material = ContactMaterial {
+        friction_coefficient: 0.1,
+        restitution_coefficient: 0.3,
+        rolling_friction: 0.0,
+        torsional_friction: 0.0,
+    };
+
+    registry.override_pair("rubber", "ice", overriding_material);
+
+    assert_eq!(registry.pair_override("ice", "rubber"), Some(&overriding_material));
+    assert_eq!(registry.contact_material_between(&named_rigid_body("ice"), &named_rigid_body("rubber")), overriding_material);
+    assert_eq!(registry.contact_material_between(&named_rigid_body("rubber"), &named_rigid_body("ice")), overriding_material);
+    assert_eq!(registry.contact_material_between(&named_rigid_body("ice"), &named_rigid_body("ice")), MaterialData::default().combine(&MaterialData::default()));
+}
+
+#[test]
+fn it_can_restore_overridden_pairs() {
+    let mut registry = MaterialRegistry::new();
+    registry.register("ice", MaterialData::default());
+    registry.register("rubber", MaterialData::default());
+
+    registry.override_pair("ice", "rubber", ContactMaterial {
+        friction_coefficient: 0.1,
+        restitution_coefficient: 0.3,
+        rolling_friction: 0.0,
+        torsional_friction: 0.0,
+    });
+    registry.restore_pair("rubber", "ice");
+
+    assert_eq!(registry.pair_override("ice", "rubber"), None);
+    assert_eq!(registry.contact_material_between(&named_rigid_body("ice"), &named_rigid_body("rubber")), MaterialData::default().combine(&MaterialData::default()));
+}
@@ -0,0 +1,56 @@
+use {ID, MachError};
+use maths::Vec3D;
+use dynamics::{ConstraintSolver, MaterialRegistry, RigidBodyRefMut, SolverConfig, SolverStats};
+use dynamics::integrators::SemiImplicitEuler;
+use dynamics::solvers::MachConstraintSolver;
+use tests::support::{contact, fixed_body, rigid_body};
+
+#[test]
+fn it_skips_contacts_without_a_rigid_body() {
+    let fixed_bodies = (fixed_body(0), fixed_body(1));
+    let bodies = (rigid_body(2), fixed_body(3));
+    let contacts = vec!(
+        contact(&fixed_bodies.0, &fixed_bodies.1),
+        contact(&bodies.0, &bodies.1),
+    );
+    let mut stats = SolverStats::new();
+
+    let normal_impulses = MachConstraintSolver::new().solve_with_contacts(
+        &contacts,
+        &SemiImplicitEuler::new(),
+        0.1,
+        &SolverConfig::default(),
+        &MaterialRegistry::new(),
+        &mut stats,
+    );
+
+    assert_eq!(normal_impulses[0], 0.0);
+    assert_eq!(stats.velocity_reports().len(), 1);
+    assert_eq!(stats.failures(), &vec!((ID(0), ID(1), MachError::UnsupportedBodies(ID(0), ID(1)))));
+}
+
+#[test]
+fn it_starts_from_the_impulses_of_the_previous_step() {
+    let bodies = (rigid_body(0), fixed_body(1));
+    let contacts = vec!(contact(&bodies.0, &bodies.1));
+    let mut solver = MachConstraintSolver::new();
+    let mut stats = SolverStats::new();
+    let mut impulses = Vec::new();
+
+    for _ in 0..2 {
+        *RigidBodyRefMut::try_from(&mut *bodies.0.borrow_mut()).unwrap()
+            .velocity_mut() = Vec3D::new(-1.0, 0.0, 0.0);
+
+        impulses.push(solver.solve_with_contacts(
+            &contacts,
+            &SemiImplicitEuler::new(),
+            0.1,
+            &SolverConfig::default(),
+            &MaterialRegistry::new(),
+            &mut stats,
+        ));
+    }
+
+    assert_approx_eq!(impulses[1][0], impulses[0][0]);
+    assert!(stats.velocity_reports()[1].iterations() < stats.velocity_reports()[0].iterations());
+}
@@ -0,0 +1,47 @@
+assert_lcp_solver_behaviour! {
+    use maths::lcp_solvers::GaussSeidel;
+
+    pub fn test_subject() -> GaussSeidel {
+        GaussSeidel::new()
+            .with_max_iterations(10000)
+            .with_convergence_tolerance(1e-7)
+    }
+}
+
+use INFINITY;
+use maths::{LCP, LCPBounds, LCPSolver};
+
+#[test]
+fn it_solves_a_problem_with_a_block_diagonal_matrix() {
+    let mut problem = LCP::with_blocks(&vec!(2, 1));
+    *problem.matrix_mut(0, 0) = 4.0;
+    *problem.matrix_mut(0, 1) = 1.0;
+    *problem.matrix_mut(1, 0) = 1.0;
+    *problem.matrix_mut(1, 1) = 3.0;
+    *problem.matrix_mut(2, 2) = 2.0;
+    *problem.bias_mut(0) = 1.0;
+    *problem.bias_mut(1) = 2.0;
+    *problem.bias_mut(2) = 3.0;
+
+    test_subject().solve_in_place(&mut problem);
+
+    assert_approx_eq!(problem.solution(0), 1.0 / 11.0);
+    assert_approx_eq!(problem.solution(1), 7.0 / 11.0);
+    assert_approx_eq!(problem.solution(2), 1.5);
+}
+
+#[test]
+fn it_limits_unknowns_scaled_by_other_unknowns() {
+    let mut problem = LCP::new(2);
+    *problem.matrix_mut(0, 0) = 1.0;
+    *problem.matrix_mut(1, 1) = 1.0;
+    *problem.bias_mut(0) = 2.0;
+    *problem.bias_mut(1) = -5.0;
+    problem.set_bounds(0, LCPBounds::Fixed(0.0, INFINITY));
+    problem.set_bounds(1, LCPBounds::ScaledBy(0, 0.5));
+
+    test_subject().solve_in_place(&mut problem);
+
+    assert_approx_eq!(problem.solution(0), 2.0);
+    assert_approx_eq!(problem.solution(1), -1.0);
+}
@@ -0,0 +1,99 @@
+macro_rules! assert_lcp_solver_behaviour(
+    { $( $lines:item )+ } => (
+        $( $lines )+
+
+        mod lcp_solver_behaviour {
+            extern crate quickcheck;
+
+            use super::test_subject;
+
+            use {INFINITY, Scalar};
+            use maths::{LCP, LCPBounds, LCPSolver};
+            use tests::support::PositiveDefiniteLCP;
+
+            fn validate<S: LCPSolver>(input: S) -> S {
+                input
+            }
+
+            fn residual_of(problem: &LCP, row: usize) -> Scalar {
+                problem.matrix_row_iter(row)
+                    .map(|(column, entry)| entry * problem.solution(column))
+                    .sum::<Scalar>() - problem.bias(row)
+            }
+
+            fn expect_solved(problem: &LCP) -> quickcheck::TestResult {
+                for row in 0..problem.size() {
+                    let value = problem.solution(row);
+                    let residual = residual_of(problem, row);
+                    let (lower, upper) = problem.bounds_for_solution(row);
+
+                    quickcheck_assert!(value >= lower - 1e-3 && value <= upper + 1e-3,
+                        format!("expected unknown {} to lie within [{}, {}], but it was {}", row, lower, upper, value));
+
+                    if value > lower + 1e-3 {
+                        quickcheck_assert!(residual <= 1e-2,
+                            format!("expected the residual of row {} to be non-positive above the lower bound, but it was {} (LCP = {:?})", row, residual, problem));
+                    }
+
+                    if value < upper - 1e-3 {
+                        quickcheck_assert!(residual >= -1e-2,
+                            format!("expected the residual of row {} to be non-negative below the upper bound, but it was {} (LCP = {:?})", row, residual, problem));
+                    }
+                }
+
+                quickcheck::TestResult::passed()
+            }
+
+            #[test]
+            fn it_solves_random_positive_definite_problems() {
+                fn property(description: PositiveDefiniteLCP) -> quickcheck::TestResult {
+                    let mut problem = description.to_lcp();
+
+                    validate(test_subject()).solve_in_place(&mut problem);
+
+                    expect_solved(&problem)
+                }
+
+                quickcheck::quickcheck(property as fn(PositiveDefiniteLCP) -> quickcheck::TestResult);
+            }
+
+            #[test]
+            fn it_includes_the_coupling_with_the_previous_unknown() {
+                let mut problem = LCP::new(3);
+                for row in 0..3 {
+                    *problem.matrix_mut(row, row) = 2.0;
+                    problem.set_bounds(row, LCPBounds::Fixed(-10.0, INFINITY));
+                }
+                *problem.matrix_mut(0, 1) = 1.0;
+                *problem.matrix_mut(1, 0) = 1.0;
+                *problem.matrix_mut(1, 2) = 1.0;
+                *problem.matrix_mut(2, 1) = 1.0;
+                *problem.bias_mut(0) = 3.0;
+                *problem.bias_mut(1) = 4.0;
+                *problem.bias_mut(2) = 3.0;
+
+                validate(test_subject()).solve_in_place(&mut problem);
+
+                assert_approx_eq!(problem.solution(0), 1.0);
+                assert_approx_eq!(problem.solution(1), 1.0);
+                assert_approx_eq!(problem.solution(2), 1.0);
+            }
+
+            #[test]
+            fn it_keeps_the_unknowns_within_their_bounds() {
+                let mut problem = LCP::new(2);
+                *problem.matrix_mut(0, 0) = 2.0;
+                *problem.matrix_mut(1, 1) = 2.0;
+                *problem.bias_mut(0) = 10.0;
+                *problem.bias_mut(1) = -10.0;
+                problem.set_bounds(0, LCPBounds::Fixed(0.0, 1.0));
+                problem.set_bounds(1, LCPBounds::Fixed(0.0, 1.0));
+
+                validate(test_subject()).solve_in_place(&mut problem);
+
+                assert_approx_eq!(problem.solution(0), 1.0);
+                assert_approx_eq!(problem.solution(1), 0.0);
+            }
+        }
+    );
+);
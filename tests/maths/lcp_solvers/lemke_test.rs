@@ -0,0 +1,138 @@
+assert_lcp_solver_behaviour! {
+    use maths::lcp_solvers::Lemke;
+
+    pub fn test_subject() -> Lemke {
+        Lemke::new()
+    }
+}
+
+extern crate quickcheck;
+
+use {INFINITY, NEG_INFINITY};
+use maths::{LCP, LCPBounds, LCPSolver};
+use maths::lcp_solvers::{GaussSeidel, SuccessiveOverRelaxation};
+use tests::support::PositiveDefiniteLCP;
+
+#[test]
+fn it_agrees_with_the_iterative_solvers_on_random_positive_definite_problems() {
+    fn property(description: PositiveDefiniteLCP) -> quickcheck::TestResult {
+        let mut direct_problem = description.to_lcp();
+        let mut gauss_seidel_problem = description.to_lcp();
+        let mut over_relaxed_problem = description.to_lcp();
+
+        Lemke::new().solve_in_place(&mut direct_problem);
+        GaussSeidel::new()
+            .with_max_iterations(10000)
+            .with_convergence_tolerance(1e-7)
+            .solve_in_place(&mut gauss_seidel_problem);
+        SuccessiveOverRelaxation::new()
+            .with_max_iterations(10000)
+            .with_convergence_tolerance(1e-7)
+            .solve_in_place(&mut over_relaxed_problem);
+
+        for index in 0..direct_problem.size() {
+            let expected = direct_problem.solution(index);
+
+            quickcheck_assert!((gauss_seidel_problem.solution(index) - expected).abs() < 1e-2,
+                format!("expected Gauss-Seidel to find {} for unknown {}, but it found {}", expected, index, gauss_seidel_problem.solution(index)));
+            quickcheck_assert!((over_relaxed_problem.solution(index) - expected).abs() < 1e-2,
+                format!("expected PGS-SOR to find {} for unknown {}, but it found {}", expected, index, over_relaxed_problem.solution(index)));
+        }
+
+        quickcheck::TestResult::passed()
+    }
+
+    quickcheck::quickcheck(property as fn(PositiveDefiniteLCP) -> quickcheck::TestResult);
+}
+
+#[test]
+fn it_solves_problems_with_unbounded_unknowns() {
+    let mut problem = LCP::new(2);
+    *problem.matrix_mut(0, 0) = 2.0;
+    *problem.matrix_mut(0, 1) = 1.0;
+    *problem.matrix_mut(1, 0) = 1.0;
+    *problem.matrix_mut(1, 1) = 2.0;
+    *problem.bias_mut(0) = -3.0;
+    *problem.bias_mut(1) = 3.0;
+
+    let report = Lemke::new().solve_in_place(&mut problem);
+
+    assert!(report.has_converged(), "expected Lemke's method to converge, but it did not");
+    assert_approx_eq!(problem.solution(0), -3.0);
+    assert_approx_eq!(problem.solution(1), 3.0);
+}
+
+#[test]
+fn it_solves_problems_with_only_an_upper_bound() {
+    let mut problem = LCP::new(2);
+    *problem.matrix_mut(0, 0) = 1.0;
+    *problem.matrix_mut(1, 1) = 1.0;
+    *problem.bias_mut(0) = 2.0;
+    *problem.bias_mut(1) = -2.0;
+    problem.set_bounds(0, LCPBounds::Fixed(NEG_INFINITY, 1.0));
+    problem.set_bounds(1, LCPBounds::Fixed(NEG_INFINITY, 1.0));
+
+    let report = Lemke::new().solve_in_place(&mut problem);
+
+    assert!(report.has_converged(), "expected Lemke's method to converge, but it did not");
+    assert_approx_eq!(problem.solution(0), 1.0);
+    assert_approx_eq!(problem.solution(1), -2.0);
+}
+
+#[test]
+fn it_limits_unknowns_by_the_unknowns_scaling_their_bounds() {
+    let mut problem = LCP::new(3);
+    *problem.matrix_mut(0, 0) = 1.0;
+    *problem.matrix_mut(1, 1) = 1.0;
+    *problem.matrix_mut(2, 2) = 1.0;
+    *problem.bias_mut(0) = 2.0;
+    *problem.bias_mut(1) = -5.0;
+    *problem.bias_mut(2) = 0.5;
+    problem.set_bounds(0, LCPBounds::Fixed(0.0, INFINITY));
+    problem.set_bounds(1, LCPBounds::ScaledBy(0, 0.5));
+    problem.set_bounds(2, LCPBounds::ScaledBy(0, 0.5));
+
+    let report = Lemke::new().solve_in_place(&mut problem);
+
+    assert!(report.has_converged(), "expected Lemke's method to converge, but it did not");
+    assert_approx_eq!(problem.solution(0), 2.0);
+    assert_approx_eq!(problem.solution(1), -1.0);
+    assert_approx_eq!(problem.solution(2), 0.5);
+}
+
+#[test]
+fn it_agrees_with_gauss_seidel_on_coupled_problems_with_scaled_bounds() {
+    let mut direct_problem = LCP::new(3);
+    *direct_problem.matrix_mut(0, 0) = 2.0;
+    *direct_problem.matrix_mut(0, 1) = 0.5;
+    *direct_problem.matrix_mut(1, 0) = 0.5;
+    *direct_problem.matrix_mut(1, 1) = 2.0;
+    *direct_problem.matrix_mut(1, 2) = 0.5;
+    *direct_problem.matrix_mut(2, 1) = 0.5;
+    *direct_problem.matrix_mut(2, 2) = 2.0;
+    *direct_problem.bias_mut(0) = 4.0;
+    *direct_problem.bias_mut(1) = 3.0;
+    *direct_problem.bias_mut(2) = -0.2;
+    direct_problem.set_bounds(0, LCPBounds::Fixed(0.0, INFINITY));
+    direct_problem.set_bounds(1, LCPBounds::ScaledBy(0, 0.5));
+    direct_problem.set_bounds(2, LCPBounds::ScaledBy(0, 0.5));
+    let mut iterative_problem = LCP::new(3);
+    for row in 0..3 {
+        for column in 0..3 {
+            *iterative_problem.matrix_mut(row, column) = direct_problem.matrix(row, column);
+        }
+        *iterative_problem.bias_mut(row) = direct_problem.bias(row);
+        iterative_problem.set_bounds(row, direct_problem.bounds(row));
+    }
+
+    let report = Lemke::new().solve_in_place(&mut direct_problem);
+    GaussSeidel::new()
+        .with_max_iterations(10000)
+        .with_convergence_tolerance(1e-7)
+        .solve_in_place(&mut iterative_problem);
+
+    assert!(report.has_converged(), "expected Lemke's method to converge, but it did not");
+    for index in 0..3 {
+        assert_approx_eq!(direct_problem.solution(index), iterative_problem.solution(index));
+    }
+}
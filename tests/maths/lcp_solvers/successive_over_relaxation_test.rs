@@ -0,0 +1,43 @@
+assert_lcp_solver_behaviour! {
+    use maths::lcp_solvers::SuccessiveOverRelaxation;
+
+    pub fn test_subject() -> SuccessiveOverRelaxation {
+        SuccessiveOverRelaxation::new()
+            .with_max_iterations(10000)
+            .with_convergence_tolerance(1e-7)
+    }
+}
+
+use maths::{LCP, LCPSolver};
+use maths::lcp_solvers::GaussSeidel;
+
+fn strongly_coupled_problem() -> LCP {
+    let mut problem = LCP::new(2);
+    *problem.matrix_mut(0, 0) = 1.0;
+    *problem.matrix_mut(0, 1) = 0.9;
+    *problem.matrix_mut(1, 0) = 0.9;
+    *problem.matrix_mut(1, 1) = 1.0;
+    *problem.bias_mut(0) = 1.0;
+    *problem.bias_mut(1) = -1.0;
+
+    problem
+}
+
+#[test]
+fn it_converges_faster_than_gauss_seidel_for_strongly_coupled_problems() {
+    let mut over_relaxed_problem = strongly_coupled_problem();
+    let mut problem = strongly_coupled_problem();
+
+    SuccessiveOverRelaxation::new()
+        .with_max_iterations(20)
+        .with_relaxation_factor(1.5)
+        .solve_in_place(&mut over_relaxed_problem);
+    GaussSeidel::new()
+        .with_max_iterations(20)
+        .solve_in_place(&mut problem);
+
+    // the exact solution is (10, -10)
+    let over_relaxed_error = (over_relaxed_problem.solution(0) - 10.0).abs();
+    let error = (problem.solution(0) - 10.0).abs();
+    assert!(over_relaxed_error < 0.1 * error, "expected over-relaxation to reduce the error of {}, but it was {}", error, over_relaxed_error);
+}
@@ -177,3 +177,12 @@ fn multiplying_by_a_matrix() {
     assert_eq!((m[3], m[4], m[5]), ( 96.0,  81.0,  66.0));
     assert_eq!((m[6], m[7], m[8]), (150.0, 126.0, 102.0));
 }
+
+#[test]
+fn computing_the_transpose() {
+    let m = Matrix::new(1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0).transpose();
+
+    assert_eq!((m[0], m[1], m[2]), (1.0, 4.0, 7.0));
+    assert_eq!((m[3], m[4], m[5]), (2.0, 5.0, 8.0));
+    assert_eq!((m[6], m[7], m[8]), (3.0, 6.0, 9.0));
+}
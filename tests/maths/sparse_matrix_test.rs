@@ -9,3 +9,41 @@ fn it_zeroes_all_entries_by_default() {
     assert_eq!(matrix[(1, 0)], 0.0);
     assert_eq!(matrix[(1, 1)], 0.0);
 }
+
+#[test]
+fn it_stores_the_assigned_entries() {
+    let mut matrix = SparseMatrix::with_blocks(&vec!(2, 1));
+
+    matrix[(0, 2)] = 3.0;
+    matrix[(1, 0)] = -1.5;
+
+    assert_eq!(matrix[(0, 2)], 3.0);
+    assert_eq!(matrix[(1, 0)], -1.5);
+    assert_eq!(matrix[(0, 0)], 0.0);
+    assert_eq!(matrix[(2, 0)], 0.0);
+}
+
+#[test]
+fn it_only_stores_the_blocks_with_assigned_entries() {
+    let mut matrix = SparseMatrix::with_blocks(&vec!(2, 3, 2));
+
+    matrix[(0, 1)] = 1.0;
+    matrix[(6, 2)] = 1.0;
+    matrix[(6, 3)] = 1.0;
+
+    assert_eq!(matrix.size(), 7);
+    assert_eq!(matrix.stored_len(), 2 * 2 + 2 * 3);
+}
+
+#[test]
+fn it_iterates_over_the_non_zero_entries_of_a_row_in_column_order() {
+    let mut matrix = SparseMatrix::with_blocks(&vec!(2, 2, 2));
+
+    matrix[(3, 5)] = 4.0;
+    matrix[(3, 0)] = 2.0;
+    matrix[(3, 3)] = 1.0;
+    matrix[(2, 1)] = 7.0;
+
+    assert_eq!(matrix.row_iter(3).collect::<Vec<_>>(), vec!((0, 2.0), (3, 1.0), (5, 4.0)));
+    assert_eq!(matrix.row_iter(4).collect::<Vec<_>>(), vec!());
+}
@@ -1,5 +1,5 @@
-use PI;
-use maths::{Quat, UnitQuat, Vec3D};
+use {PI, Scalar};
+use maths::{Matrix, Quat, UnitQuat, Vec3D};
 
 #[test]
 fn it_can_be_instantiated_using_the_axis_angle_formulation() {
@@ -22,3 +22,48 @@ fn it_can_rotate_a_vector() {
 
     assert_approx_eq!(res, Vec3D::new(0.8333333333333335, 0.5202200572599405, -0.18688672392660716));
 }
+
+#[test]
+fn it_can_be_converted_to_an_orientation_matrix() {
+    let radians: Scalar = 3.0;
+    let q = UnitQuat::from_axis_angle(Vec3D::new(2.0, 3.0, 6.0).normalize(), radians);
+
+    let m = Matrix::from(q);
+
+    assert_eq!(m, Matrix::rotation(radians, Vec3D::new(2.0, 3.0, 6.0)));
+    assert_eq!(m * m.transpose(), Matrix::identity());
+}
+
+#[test]
+fn it_can_be_instantiated_from_a_rotation_vector() {
+    let axis = Vec3D::new(2.0, 3.0, 6.0).normalize();
+    let q = UnitQuat::from_rotation_vector(Vec3D::from(axis) * 2.5);
+
+    assert_approx_eq!(q, UnitQuat::from_axis_angle(axis, 2.5));
+}
+
+#[test]
+fn it_can_be_instantiated_from_a_zero_rotation_vector() {
+    assert_approx_eq!(UnitQuat::from_rotation_vector(Vec3D::zero()), UnitQuat::identity());
+}
+
+#[test]
+fn it_can_be_converted_to_a_rotation_vector() {
+    let axis = Vec3D::new(2.0, 3.0, 6.0).normalize();
+    let q = UnitQuat::from_axis_angle(axis, 2.5);
+
+    assert_approx_eq!(q.rotation_vector(), Vec3D::from(axis) * 2.5);
+}
+
+#[test]
+fn it_takes_the_shortest_rotation_when_converted_to_a_rotation_vector() {
+    let axis = Vec3D::new(0.0, 0.0, 1.0).normalize();
+    let q = UnitQuat::from_axis_angle(axis, 1.5 * PI);
+
+    assert_approx_eq!(q.rotation_vector(), Vec3D::new(0.0, 0.0, -0.5 * PI));
+}
+
+#[test]
+fn it_converts_the_identity_to_a_zero_rotation_vector() {
+    assert_approx_eq!(UnitQuat::identity().rotation_vector(), Vec3D::zero());
+}
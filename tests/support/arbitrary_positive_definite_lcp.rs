@@ -0,0 +1,68 @@
+extern crate quickcheck;
+
+use {INFINITY, Scalar};
+use maths::{LCP, LCPBounds};
+
+/// The description of an `LCP` with a symmetric positive-definite matrix,
+/// which always has a unique solution. Each unknown is either non-negative or
+/// bounded on both sides.
+#[derive(Clone, Debug)]
+pub struct PositiveDefiniteLCP {
+    matrix: Vec<Vec<Scalar>>,
+    bias: Vec<Scalar>,
+    bounds: Vec<(Scalar, Scalar)>,
+}
+
+impl PositiveDefiniteLCP {
+    pub fn to_lcp(&self) -> LCP {
+        let mut problem = LCP::new(self.bias.len());
+
+        for (row, entries) in self.matrix.iter().enumerate() {
+            for (column, &entry) in entries.iter().enumerate() {
+                *problem.matrix_mut(row, column) = entry;
+            }
+
+            *problem.bias_mut(row) = self.bias[row];
+            problem.set_bounds(row, LCPBounds::Fixed(self.bounds[row].0, self.bounds[row].1));
+        }
+
+        return problem;
+    }
+}
+
+impl quickcheck::Arbitrary for PositiveDefiniteLCP {
+    fn arbitrary<G: quickcheck::Gen>(random: &mut G) -> Self {
+        let size = random.gen_range(1, 9);
+        let factor: Vec<Vec<Scalar>> = (0..size)
+            .map(|_row| (0..size).map(|_column| random.gen_range(-1.0, 1.0)).collect())
+            .collect();
+
+        // the product of a matrix with its own transpose is positive
+        // semi-definite, adding the identity makes it definite
+        let matrix = (0..size)
+            .map(|row| (0..size)
+                .map(|column| {
+                    let product: Scalar = (0..size)
+                        .map(|index| factor[index][row] * factor[index][column])
+                        .sum();
+
+                    if row == column { product + 1.0 } else { product }
+                })
+                .collect())
+            .collect();
+
+        PositiveDefiniteLCP {
+            matrix: matrix,
+            bias: (0..size).map(|_index| random.gen_range(-10.0, 10.0)).collect(),
+            bounds: (0..size)
+                .map(|_index| {
+                    if random.gen() {
+                        (0.0, INFINITY)
+                    } else {
+                        (random.gen_range(-5.0, 0.0), random.gen_range(0.0, 5.0))
+                    }
+                })
+                .collect(),
+        }
+    }
+}
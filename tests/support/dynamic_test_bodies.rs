@@ -0,0 +1,32 @@
+use ID;
+use maths::{UnitVec3D, Vec3D};
+use utils::Handle;
+use dynamics::{DynamicBodyExtension, FixedBodyData, FixedBodyDef, RigidBodyData, RigidBodyDef};
+use collisions::{Body, BodyDef, CollisionObject, Contact, ContactSet};
+use collisions::narrowphase::NullNarrowphase;
+
+pub type DynamicTestBody = Body<DynamicBodyExtension<()>, NullNarrowphase>;
+
+pub fn rigid_body(id: u32) -> Handle<DynamicTestBody> {
+    let data = RigidBodyData::new(&RigidBodyDef::default(), ());
+
+    Handle::new(Body::new(ID(id), BodyDef::default(), DynamicBodyExtension::Rigid(Box::new(data))))
+}
+
+pub fn fixed_body(id: u32) -> Handle<DynamicTestBody> {
+    let data = FixedBodyData::new(&FixedBodyDef::default(), ());
+
+    Handle::new(Body::new(ID(id), BodyDef::default(), DynamicBodyExtension::Fixed(Box::new(data))))
+}
+
+/// Creates a contact between the bodies with a single point, which does not
+/// depend on where the bodies are.
+pub fn contact(handle_0: &Handle<DynamicTestBody>, handle_1: &Handle<DynamicTestBody>) -> Contact<DynamicTestBody> {
+    let set = ContactSet::with_penetration_depths(
+        UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0)),
+        vec!(Vec3D::new(0.5, 0.0, 0.0)),
+        vec!(0.01),
+    );
+
+    Contact::new(set, handle_0.clone(), handle_1.clone())
+}
@@ -0,0 +1,33 @@
+use maths::{DotProduct, Matrix, Motion, Transform, Vec3D};
+use dynamics::{Integratable, Integrator};
+
+fn angular_momentum(transform: &Transform, motion: &Motion, inertia: Matrix) -> Vec3D {
+    let orientation = Matrix::from(transform.rotation);
+
+    orientation * inertia * orientation.transpose() * motion.angular_velocity
+}
+
+/// Spins a body about an axis close to its unstable principal axis, which
+/// tumbles while keeping its angular momentum and kinetic energy.
+pub fn assert_conserves_angular_momentum<I>(integrator: I) where I: Integrator {
+    let inertia = Matrix::diag(2.1, 0.1, 2.0);
+    let mut transform = Transform::identity();
+    let mut motion = Motion::stationary()
+        .with_angular_velocity(0.2, 3.0, 0.2);
+
+    let initial_angular_momentum = angular_momentum(&transform, &motion, inertia);
+    let initial_energy = 0.5 * motion.angular_velocity.dot(initial_angular_momentum);
+
+    for _ in 0..60 {
+        let mut integratable = Integratable::new(&mut transform, &mut motion)
+            .with_inertia(inertia);
+
+        integrator.integrate_in_place(&mut integratable, 1.0 / 60.0, Vec3D::zero(), Vec3D::zero());
+    }
+
+    let final_angular_momentum = angular_momentum(&transform, &motion, inertia);
+    let final_energy = 0.5 * motion.angular_velocity.dot(final_angular_momentum);
+
+    assert!((final_angular_momentum - initial_angular_momentum).length() < 0.02 * initial_angular_momentum.length());
+    assert!((final_energy - initial_energy).abs() < 0.01 * initial_energy);
+}
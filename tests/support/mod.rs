@@ -16,6 +16,9 @@ mod property_checker;
 mod arbitrary_radians;
 mod variable_size_vec;
 mod arbitrary_positive_scalar;
+mod arbitrary_positive_definite_lcp;
+mod dynamic_test_bodies;
+mod gyroscopic_behaviour;
 
 pub use self::action::Action;
 pub use self::property::Property;
@@ -23,6 +26,9 @@ pub use self::result_handle::ResultHandle;
 pub use self::property_checker::PropertyCheck;
 pub use self::arbitrary_radians::Radians;
 pub use self::arbitrary_positive_scalar::PositiveScalar;
+pub use self::arbitrary_positive_definite_lcp::PositiveDefiniteLCP;
+pub use self::dynamic_test_bodies::{contact, fixed_body, rigid_body, DynamicTestBody};
+pub use self::gyroscopic_behaviour::assert_conserves_angular_momentum;
 pub use self::assert_properties_for_actions::assert_properties_for_actions;
 pub use self::variable_size_vec::{Four, One, Ten, VariableSizeVec};
 
@@ -0,0 +1,32 @@
+use utils::UnionFind;
+
+#[test]
+fn it_starts_with_each_element_in_its_own_set() {
+    let mut union_find = UnionFind::new(3);
+
+    assert_eq!(union_find.sets(), vec!(vec!(0), vec!(1), vec!(2)));
+}
+
+#[test]
+fn it_merges_the_sets_of_the_elements() {
+    let mut union_find = UnionFind::new(6);
+
+    union_find.union(4, 1);
+    union_find.union(2, 5);
+    union_find.union(1, 3);
+
+    assert_eq!(union_find.find(3), union_find.find(4));
+    assert!(union_find.find(0) != union_find.find(1));
+    assert_eq!(union_find.sets(), vec!(vec!(0), vec!(1, 3, 4), vec!(2, 5)));
+}
+
+#[test]
+fn it_ignores_elements_already_in_the_same_set() {
+    let mut union_find = UnionFind::new(3);
+
+    union_find.union(0, 1);
+    union_find.union(1, 0);
+    union_find.union(0, 0);
+
+    assert_eq!(union_find.sets(), vec!(vec!(0, 1), vec!(2)));
+}
@@ -0,0 +1,145 @@
+use {FixedStepper, MachBody, MachWorld, World};
+use maths::{UnitQuat, Vec3D};
+use dynamics::{ContactEventKind, FixedBodyDef, RigidBodyDef};
+use collisions::{CollisionObject, OverlapEvent};
+use collisions::shapes::convex_shapes::Cuboid;
+
+fn moving_body_def() -> RigidBodyDef {
+    RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        velocity: Vec3D::new(1.0, 0.0, 0.0),
+        angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+        .. RigidBodyDef::default()
+    }
+}
+
+fn stepper() -> FixedStepper<MachBody<()>, MachWorld<()>> {
+    FixedStepper::new(MachWorld::new(), 0.25)
+}
+
+#[test]
+fn it_does_not_step_until_a_full_time_step_has_accumulated() {
+    let mut stepper = stepper();
+
+    assert_eq!(stepper.update(0.125).count(), 0);
+    assert_approx_eq!(stepper.alpha(), 0.5);
+
+    assert_eq!(stepper.update(0.25).count(), 1);
+    assert_approx_eq!(stepper.alpha(), 0.5);
+}
+
+#[test]
+fn it_takes_multiple_steps_when_enough_time_has_accumulated() {
+    let mut stepper = stepper();
+
+    assert_eq!(stepper.update(0.875).count(), 3);
+    assert_approx_eq!(stepper.alpha(), 0.5);
+}
+
+#[test]
+fn it_discards_the_time_exceeding_the_maximum_number_of_steps() {
+    let mut stepper = stepper().with_max_steps(2);
+
+    assert_eq!(stepper.update(1.875).count(), 2);
+    assert_approx_eq!(stepper.alpha(), 0.5);
+    assert_eq!(stepper.update(0.0).count(), 0);
+}
+
+#[test]
+fn it_returns_the_contacts_and_events_of_every_step() {
+    let mut stepper: FixedStepper<MachBody<()>, MachWorld<()>> = FixedStepper::new(MachWorld::new(), 0.01);
+    stepper.world_mut().set_gravity(Vec3D::new(0.0, 0.0, -10.0));
+    stepper.world_mut().create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        translation: Vec3D::new(0.0, 0.0, 0.99),
+        .. RigidBodyDef::default()
+    }, ());
+    stepper.world_mut().create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        .. FixedBodyDef::default()
+    }, ());
+    stepper.world_mut().create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        translation: Vec3D::new(0.0, 0.0, 1.0),
+        is_sensor: true,
+        .. FixedBodyDef::default()
+    }, ());
+
+    let steps = stepper.update(0.025);
+    let contact_event_kinds: Vec<ContactEventKind> = steps.contact_events().iter()
+        .map(|event| event.kind())
+        .collect();
+
+    assert_eq!(steps.count(), 2);
+    assert_eq!(steps.contacts().len(), 2);
+    assert_eq!(contact_event_kinds, vec!(ContactEventKind::Started, ContactEventKind::Persisted));
+    assert_eq!(steps.overlap_events().len(), 2);
+    match (&steps.overlap_events()[0], &steps.overlap_events()[1]) {
+        (&OverlapEvent::Enter(..), &OverlapEvent::Stay(..)) => (),
+        _otherwise => panic!("expected an enter event followed by a stay event"),
+    }
+}
+
+#[test]
+fn it_simulates_the_same_steps_regardless_of_the_elapsed_times() {
+    let mut stepper_0 = stepper();
+    let mut stepper_1 = stepper();
+    let handle_0 = stepper_0.world_mut().create_rigid_body(moving_body_def(), ());
+    let handle_1 = stepper_1.world_mut().create_rigid_body(moving_body_def(), ());
+
+    for &elapsed_time in [0.25, 0.25, 0.25, 0.25].iter() {
+        stepper_0.update(elapsed_time);
+    }
+
+    for &elapsed_time in [0.125, 0.5, 0.0625, 0.3125].iter() {
+        stepper_1.update(elapsed_time);
+    }
+
+    assert_approx_eq!(handle_0.borrow().transform().translation, handle_1.borrow().transform().translation);
+    assert_approx_eq!(handle_0.borrow().transform().rotation, handle_1.borrow().transform().rotation);
+}
+
+#[test]
+fn it_keeps_the_transforms_before_and_after_the_last_step() {
+    let mut stepper = stepper();
+    let id = stepper.world_mut().create_rigid_body(moving_body_def(), ()).borrow().id();
+
+    stepper.update(0.625);
+
+    let previous = stepper.previous_transform(id)
+        .expect("expected the previous transform to be present");
+    let current = stepper.current_transform(id)
+        .expect("expected the current transform to be present");
+
+    assert_approx_eq!(previous.translation, Vec3D::new(0.25, 0.0, 0.0));
+    assert_approx_eq!(current.translation, Vec3D::new(0.50, 0.0, 0.0));
+    assert_approx_eq!(current.rotation, UnitQuat::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0).normalize(), 0.5));
+}
+
+#[test]
+fn it_interpolates_between_the_previous_and_current_transforms() {
+    let mut stepper = stepper();
+    let id = stepper.world_mut().create_rigid_body(moving_body_def(), ()).borrow().id();
+
+    stepper.update(0.625);
+
+    let transform = stepper.interpolated_transform(id)
+        .expect("expected the interpolated transform to be present");
+
+    assert_approx_eq!(transform.translation, Vec3D::new(0.375, 0.0, 0.0));
+    assert_approx_eq!(transform.rotation, UnitQuat::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0).normalize(), 0.375));
+}
+
+#[test]
+fn it_uses_the_current_transform_for_bodies_created_since_the_last_step() {
+    let mut stepper = stepper();
+    let id = stepper.world_mut().create_rigid_body(moving_body_def(), ()).borrow().id();
+
+    let previous = stepper.previous_transform(id)
+        .expect("expected the previous transform to be present");
+    let current = stepper.current_transform(id)
+        .expect("expected the current transform to be present");
+
+    assert_approx_eq!(previous.translation, Vec3D::zero());
+    assert_approx_eq!(current.translation, Vec3D::zero());
+}
@@ -5,3 +5,664 @@ assert_world_behaviour! {
         MachWorld::new()
     }
 }
+
+use {MachBody, MachError, Scalar, World, WorldConfig};
+use maths::{Transform, UnitQuat, UnitVec3D, Vec3D};
+use dynamics::force_fields::{FluidVolume, PointAttractor, Wind};
+use dynamics::{CombineMode, ContactEventKind, FixedBodyDef, KinematicBodyDef, KinematicBodyRef, KinematicBodyRefMut, MaterialData, PositionCorrection, RigidBodyDef, RigidBodyRef, SolverConfig};
+use collisions::CollisionObject;
+use collisions::shapes::Ray;
+use collisions::shapes::convex_shapes::{Cuboid, Sphere};
+use utils::Handle;
+
+/// Advances the world in steps of a sixtieth of a second.
+fn run_for(world: &mut MachWorld<()>, duration: Scalar) {
+    for _ in 0..(duration * 60.0).round() as usize {
+        world.update(1.0 / 60.0);
+    }
+}
+
+fn rigid_velocity(handle: &Handle<MachBody<()>>) -> Vec3D {
+    let body = handle.borrow();
+    let rigid_body = RigidBodyRef::try_from(&*body)
+        .expect("expected the body to be rigid but was not");
+
+    *rigid_body.velocity()
+}
+
+fn rigid_angular_velocity(handle: &Handle<MachBody<()>>) -> Vec3D {
+    let body = handle.borrow();
+    let rigid_body = RigidBodyRef::try_from(&*body)
+        .expect("expected the body to be rigid but was not");
+
+    *rigid_body.angular_velocity()
+}
+
+fn overlapping_bodies_x_motion(config: WorldConfig) -> (Scalar, Scalar) {
+    let mut world: MachWorld<()> = MachWorld::with_config(config);
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        .. RigidBodyDef::default()
+    }, ());
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        translation: Vec3D::new(0.8, 0.0, 0.0),
+        .. FixedBodyDef::default()
+    }, ());
+
+    world.update(0.1);
+
+    let translation = handle.borrow().translation().x;
+
+    (translation, rigid_velocity(&handle).x)
+}
+
+fn ball_motion_on_rubber_after(rubber: MaterialData, velocity: Vec3D, angular_velocity: Vec3D, duration: Scalar) -> (Vec3D, Vec3D) {
+    let mut world: MachWorld<()> = MachWorld::new();
+    world.set_gravity(Vec3D::new(0.0, 0.0, -9.81));
+    world.material_registry_mut().register("rubber", rubber);
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::new(40.0, 40.0, 1.0)),
+        translation: Vec3D::new(0.0, 0.0, -0.5),
+        material: Some("rubber".to_string()),
+        .. FixedBodyDef::default()
+    }, ());
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Sphere::new(0.5)),
+        translation: Vec3D::new(0.0, 0.0, 0.5),
+        velocity: velocity,
+        angular_velocity: angular_velocity,
+        material: Some("rubber".to_string()),
+        .. RigidBodyDef::default()
+    }, ());
+
+    run_for(&mut world, duration);
+
+    (rigid_velocity(&handle), rigid_angular_velocity(&handle))
+}
+
+fn rolling_ball_speed_after(rolling_friction: Scalar, duration: Scalar) -> Scalar {
+    let rubber = MaterialData {
+        rolling_friction: rolling_friction,
+        .. MaterialData::default()
+    };
+
+    ball_motion_on_rubber_after(rubber, Vec3D::new(2.0, 0.0, 0.0), Vec3D::zero(), duration).0.length()
+}
+
+fn spinning_ball_angular_speed_after(torsional_friction: Scalar, duration: Scalar) -> Scalar {
+    let rubber = MaterialData {
+        torsional_friction: torsional_friction,
+        .. MaterialData::default()
+    };
+
+    ball_motion_on_rubber_after(rubber, Vec3D::zero(), Vec3D::new(0.0, 0.0, 5.0), duration).1.length()
+}
+
+fn sliding_distance_on_slope(friction_coefficient: Scalar, duration: Scalar) -> Scalar {
+    let mut world: MachWorld<()> = MachWorld::new();
+    let slope_rotation = UnitQuat::from_axis_angle(UnitVec3D::from(Vec3D::new(0.0, 1.0, 0.0)), (20.0 as Scalar).to_radians());
+    let slope_normal = slope_rotation.rotate(Vec3D::new(0.0, 0.0, 1.0));
+    let initial_translation = slope_normal * 1.01;
+    world.set_gravity(Vec3D::new(0.0, 0.0, -9.81));
+    world.material_registry_mut().register("rough", MaterialData {
+        friction_coefficient: friction_coefficient,
+        restitution_coefficient: 0.0,
+        friction_combine: CombineMode::Average,
+        .. MaterialData::default()
+    });
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::new(20.0, 20.0, 1.0)),
+        rotation: slope_rotation,
+        material: Some("rough".to_string()),
+        .. FixedBodyDef::default()
+    }, ());
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        translation: initial_translation,
+        rotation: slope_rotation,
+        material: Some("rough".to_string()),
+        .. RigidBodyDef::default()
+    }, ());
+
+    run_for(&mut world, duration);
+
+    let body = handle.borrow();
+
+    (body.translation() - initial_translation).length()
+}
+
+fn assert_tower_of_boxes_stands(height: usize, solver_config: SolverConfig) {
+    let mut world: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        solver: solver_config,
+        .. WorldConfig::default()
+    });
+    world.set_gravity(Vec3D::new(0.0, 0.0, -9.81));
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::new(20.0, 20.0, 1.0)),
+        translation: Vec3D::new(0.0, 0.0, -0.5),
+        .. FixedBodyDef::default()
+    }, ());
+    let handles = (0..height)
+        .map(|index| world.create_rigid_body(RigidBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(0.0, 0.0, 0.51 + 1.01 * index as Scalar),
+            .. RigidBodyDef::default()
+        }, ()))
+        .collect::<Vec<_>>();
+
+    run_for(&mut world, 10.0);
+
+    for (index, handle) in handles.iter().enumerate() {
+        let body = handle.borrow();
+        let translation = body.translation();
+        let horizontal_offset = Vec3D::new(translation.x, translation.y, 0.0).length();
+        let tilt = body.transform().rotation.rotation_vector().length();
+
+        assert!((translation.z - (0.5 + index as Scalar)).abs() < 0.1, "expected box {} to remain in place, but its height was {}", index, translation.z);
+        assert!(horizontal_offset < 0.2, "expected box {} to remain in place, but it moved {} sideways", index, horizontal_offset);
+        assert!(tilt < 0.05, "expected box {} to remain upright, but it tilted by {} radians", index, tilt);
+    }
+}
+
+#[test]
+fn it_divides_each_update_into_sub_steps() {
+    let mut world_0: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        sub_steps: 4,
+        .. WorldConfig::default()
+    });
+    let mut world_1: MachWorld<()> = MachWorld::new();
+
+    let handles = (
+        world_0.create_rigid_body(RigidBodyDef::default(), ()),
+        world_1.create_rigid_body(RigidBodyDef::default(), ()),
+    );
+    world_0.set_gravity(Vec3D::new(0.0, 0.0, -10.0));
+    world_1.set_gravity(Vec3D::new(0.0, 0.0, -10.0));
+
+    world_0.update(1.0);
+    for _ in 0..4 {
+        world_1.update(0.25);
+    }
+
+    assert_approx_eq!(*handles.0.borrow().translation(), *handles.1.borrow().translation());
+    assert_approx_eq!(rigid_velocity(&handles.0), rigid_velocity(&handles.1));
+}
+
+#[test]
+fn it_leaves_out_the_gyroscopic_torque_by_default() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::new(1.0, 5.0, 0.5)),
+        angular_velocity: Vec3D::new(0.2, 3.0, 0.2),
+        .. RigidBodyDef::default()
+    }, ());
+
+    run_for(&mut world, 1.0);
+
+    assert_approx_eq!(rigid_angular_velocity(&handle), Vec3D::new(0.2, 3.0, 0.2));
+}
+
+#[test]
+fn it_conserves_the_angular_momentum_of_a_free_spinning_cuboid_with_the_gyroscopic_torque() {
+    let mut world: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        gyroscopic_torque: true,
+        .. WorldConfig::default()
+    });
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::new(1.0, 5.0, 0.5)),
+        angular_velocity: Vec3D::new(0.2, 3.0, 0.2),
+        .. RigidBodyDef::default()
+    }, ());
+
+    let angular_momentum = || {
+        let body = handle.borrow();
+        let rigid_body = RigidBodyRef::try_from(&*body)
+            .expect("expected the body to be rigid but was not");
+
+        rigid_body.inertia() * rigid_body.angular_velocity()
+    };
+
+    let initial_angular_momentum = angular_momentum();
+    let initial_angular_velocity = rigid_angular_velocity(&handle);
+
+    run_for(&mut world, 1.0);
+
+    let final_angular_momentum = angular_momentum();
+    assert!((final_angular_momentum - initial_angular_momentum).length() < 0.02 * initial_angular_momentum.length());
+    assert!((rigid_angular_velocity(&handle) - initial_angular_velocity).length() > 0.1,
+        "expected the body to tumble but its angular velocity stayed at {:?}", initial_angular_velocity);
+}
+
+#[test]
+fn it_moves_kinematic_bodies_to_the_next_transform_over_every_sub_step() {
+    let mut world: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        sub_steps: 4,
+        .. WorldConfig::default()
+    });
+    let handle = world.create_kinematic_body(KinematicBodyDef::default(), ());
+    let rotation = UnitQuat::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0).normalize(), 0.5);
+
+    {
+        let mut body = handle.borrow_mut();
+        let mut kinematic_body = KinematicBodyRefMut::try_from(&mut *body)
+            .expect("expected the body to be kinematic but was not");
+
+        kinematic_body.set_next_transform(Transform::new(Vec3D::new(1.0, 0.0, 0.0), rotation));
+    }
+
+    world.update(0.5);
+
+    let body = handle.borrow();
+    let kinematic_body = KinematicBodyRef::try_from(&*body)
+        .expect("expected the body to be kinematic but was not");
+
+    assert_approx_eq!(kinematic_body.translation(), Vec3D::new(1.0, 0.0, 0.0));
+    assert_approx_eq!(kinematic_body.rotation(), rotation);
+    assert_approx_eq!(kinematic_body.velocity(), Vec3D::new(2.0, 0.0, 0.0));
+}
+
+#[test]
+fn it_reports_one_contact_event_per_pair_for_each_sub_stepped_update() {
+    let mut world: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        sub_steps: 4,
+        .. WorldConfig::default()
+    });
+    world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        translation: Vec3D::new(0.0, 0.0, 0.99),
+        .. RigidBodyDef::default()
+    }, ());
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        .. FixedBodyDef::default()
+    }, ());
+    world.set_gravity(Vec3D::new(0.0, 0.0, -10.0));
+
+    world.update(0.1);
+    let kinds: Vec<ContactEventKind> = world.contact_events_iter().map(|event| event.kind()).collect();
+    assert_eq!(kinds, vec!(ContactEventKind::Started));
+
+    world.update(0.1);
+    let kinds: Vec<ContactEventKind> = world.contact_events_iter().map(|event| event.kind()).collect();
+    assert_eq!(kinds, vec!(ContactEventKind::Persisted));
+}
+
+#[test]
+fn it_pushes_penetrating_bodies_apart_without_changing_their_velocities() {
+    let (translation, velocity) = overlapping_bodies_x_motion(WorldConfig::default());
+
+    // a fifth of the penetration beyond the slop is corrected in each step
+    assert!((translation + 0.039).abs() < 1e-3, "expected the penetration to be partially resolved, but the translation was {}", translation);
+    assert!(velocity.abs() < 1e-3, "expected the velocity to be unchanged, but it was {}", velocity);
+}
+
+#[test]
+fn it_separates_penetrating_bodies_with_baumgarte_stabilisation() {
+    let (translation, velocity) = overlapping_bodies_x_motion(WorldConfig {
+        solver: SolverConfig {
+            position_correction: PositionCorrection::Baumgarte,
+            .. SolverConfig::default()
+        },
+        .. WorldConfig::default()
+    });
+
+    // the same correction as with split impulses, but the body keeps moving
+    assert!((translation + 0.039).abs() < 1e-3, "expected the penetration to be partially resolved, but the translation was {}", translation);
+    assert!((velocity + 0.39).abs() < 1e-3, "expected a separating velocity, but the velocity was {}", velocity);
+}
+
+#[test]
+fn it_does_not_correct_penetrations_without_position_iterations() {
+    let (translation, velocity) = overlapping_bodies_x_motion(WorldConfig {
+        solver: SolverConfig {
+            position_iterations: 0,
+            .. SolverConfig::default()
+        },
+        .. WorldConfig::default()
+    });
+
+    assert!(translation.abs() < 1e-3, "expected the body not to move, but the translation was {}", translation);
+    assert!(velocity.abs() < 1e-3, "expected the velocity to be unchanged, but it was {}", velocity);
+}
+
+#[test]
+fn it_keeps_a_tower_of_boxes_standing_with_the_default_config() {
+    assert_tower_of_boxes_stands(5, SolverConfig::default());
+}
+
+#[test]
+fn it_keeps_a_tall_tower_of_boxes_standing_with_the_default_config() {
+    assert_tower_of_boxes_stands(10, SolverConfig::default());
+}
+
+#[test]
+fn it_keeps_a_ball_rolling_without_rolling_friction() {
+    let speed = rolling_ball_speed_after(0.0, 3.0);
+
+    assert!(speed > 1.0, "expected the ball to keep rolling, but its speed was {}", speed);
+}
+
+#[test]
+fn it_brings_a_rolling_ball_to_rest_with_rolling_friction() {
+    let speed = rolling_ball_speed_after(0.5, 3.0);
+
+    assert!(speed < 1e-3, "expected the ball to come to rest, but its speed was {}", speed);
+}
+
+#[test]
+fn it_slows_a_ball_with_rolling_friction_on_a_ground_without_any() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    world.set_gravity(Vec3D::new(0.0, 0.0, -9.81));
+    world.material_registry_mut().register("rubber", MaterialData {
+        rolling_friction: 0.5,
+        .. MaterialData::default()
+    });
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::new(40.0, 40.0, 1.0)),
+        translation: Vec3D::new(0.0, 0.0, -0.5),
+        .. FixedBodyDef::default()
+    }, ());
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Sphere::new(0.5)),
+        translation: Vec3D::new(0.0, 0.0, 0.5),
+        velocity: Vec3D::new(2.0, 0.0, 0.0),
+        material: Some("rubber".to_string()),
+        .. RigidBodyDef::default()
+    }, ());
+
+    run_for(&mut world, 3.0);
+
+    let speed = rigid_velocity(&handle).length();
+    assert!(speed < 1e-3, "expected the ball to come to rest, but its speed was {}", speed);
+}
+
+#[test]
+fn it_keeps_a_ball_spinning_without_torsional_friction() {
+    let angular_speed = spinning_ball_angular_speed_after(0.0, 1.0);
+
+    assert!(angular_speed > 4.5, "expected the ball to keep spinning, but its angular speed was {}", angular_speed);
+}
+
+#[test]
+fn it_stops_a_spinning_ball_with_torsional_friction() {
+    let angular_speed = spinning_ball_angular_speed_after(0.5, 1.0);
+
+    assert!(angular_speed < 1e-3, "expected the ball to stop spinning, but its angular speed was {}", angular_speed);
+}
+
+#[test]
+fn it_holds_a_box_on_a_slope_with_static_friction() {
+    let distance = sliding_distance_on_slope(0.7, 3.0);
+
+    assert!(distance < 0.05, "expected the box to stay on the slope, but it moved {}", distance);
+}
+
+#[test]
+fn it_lets_a_box_slide_down_a_slope_steeper_than_its_friction() {
+    let distance = sliding_distance_on_slope(0.2, 3.0);
+
+    assert!(distance > 1.0, "expected the box to slide down the slope, but it only moved {}", distance);
+}
+
+#[test]
+fn it_solves_separate_piles_independently() {
+    let resting_box_transform = |pile_size: usize| {
+        let mut world: MachWorld<()> = MachWorld::new();
+        world.set_gravity(Vec3D::new(0.0, 0.0, -9.81));
+        world.create_fixed_body(FixedBodyDef {
+            shape: Box::new(Cuboid::new(20.0, 20.0, 1.0)),
+            translation: Vec3D::new(0.0, 0.0, -0.5),
+            .. FixedBodyDef::default()
+        }, ());
+        let handle = world.create_rigid_body(RigidBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(-5.0, 0.0, 0.51),
+            angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+            .. RigidBodyDef::default()
+        }, ());
+        for index in 0..pile_size {
+            world.create_rigid_body(RigidBodyDef {
+                shape: Box::new(Cuboid::cube(1.0)),
+                translation: Vec3D::new(5.0, 0.0, 0.51 + 1.01 * index as Scalar),
+                .. RigidBodyDef::default()
+            }, ());
+        }
+
+        run_for(&mut world, 0.5);
+
+        let body = handle.borrow();
+
+        *body.transform()
+    };
+
+    let alone = resting_box_transform(0);
+    let beside_pile = resting_box_transform(3);
+
+    assert_eq!(beside_pile.translation, alone.translation);
+    assert_eq!(beside_pile.rotation, alone.rotation);
+}
+
+#[test]
+fn it_reports_the_solver_stats_of_each_island_and_sub_step() {
+    let mut world: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        sub_steps: 2,
+        .. WorldConfig::default()
+    });
+    for &x in [-5.0, 5.0].iter() {
+        world.create_rigid_body(RigidBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(x, 0.0, 0.0),
+            .. RigidBodyDef::default()
+        }, ());
+        world.create_fixed_body(FixedBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(x + 0.8, 0.0, 0.0),
+            .. FixedBodyDef::default()
+        }, ());
+    }
+
+    world.update(0.1);
+
+    let stats = world.solver_stats();
+    assert_eq!(stats.velocity_reports().len(), 4);
+    assert_eq!(stats.position_reports().len(), 4);
+    assert_eq!(stats.unconverged_count(), 0);
+}
+
+#[test]
+fn it_reports_solves_stopped_at_the_iteration_limit() {
+    let mut world: MachWorld<()> = MachWorld::with_config(WorldConfig {
+        solver: SolverConfig {
+            velocity_iterations: 1,
+            .. SolverConfig::default()
+        },
+        .. WorldConfig::default()
+    });
+    world.set_gravity(Vec3D::new(0.0, 0.0, -9.81));
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::new(10.0, 10.0, 1.0)),
+        translation: Vec3D::new(0.0, 0.0, -0.5),
+        .. FixedBodyDef::default()
+    }, ());
+    for index in 0..3 {
+        world.create_rigid_body(RigidBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(0.0, 0.0, 0.49 + 0.99 * index as Scalar),
+            .. RigidBodyDef::default()
+        }, ());
+    }
+
+    world.update(1.0 / 60.0);
+
+    let stats = world.solver_stats();
+    assert_eq!(stats.velocity_reports()[0].iterations(), 1);
+    assert!(!stats.velocity_reports()[0].has_converged());
+    assert!(stats.max_residual() > 0.0, "expected a residual to remain, but it was {}", stats.max_residual());
+}
+
+#[test]
+fn it_clears_the_solver_stats_on_each_update() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        velocity: Vec3D::new(-10.0, 0.0, 0.0),
+        .. RigidBodyDef::default()
+    }, ());
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        translation: Vec3D::new(0.8, 0.0, 0.0),
+        .. FixedBodyDef::default()
+    }, ());
+
+    world.update(0.01);
+    assert_eq!(world.solver_stats().velocity_reports().len(), 1);
+
+    // the body moves well clear of the fixed body
+    world.update(0.1);
+    assert!(handle.borrow().translation().x < -0.5);
+    assert_eq!(world.solver_stats().velocity_reports().len(), 0);
+    assert_eq!(world.solver_stats().total_iterations(), 0);
+}
+
+#[test]
+fn it_skips_pairs_of_degenerate_bodies_and_reports_them() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    let flat_body = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::new(1.0, 1.0, 0.0)),
+        .. RigidBodyDef::default()
+    }, ());
+    let flat_ground = world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::new(1.0, 1.0, 0.0)),
+        translation: Vec3D::new(0.5, 0.25, 0.0),
+        .. FixedBodyDef::default()
+    }, ());
+    let translation = {
+        let handle = world.create_rigid_body(RigidBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(10.0, 0.0, 0.0),
+            .. RigidBodyDef::default()
+        }, ());
+        world.create_fixed_body(FixedBodyDef {
+            shape: Box::new(Cuboid::cube(1.0)),
+            translation: Vec3D::new(10.8, 0.0, 0.0),
+            .. FixedBodyDef::default()
+        }, ());
+
+        world.update(0.1);
+
+        let body = handle.borrow();
+
+        body.translation().x
+    };
+
+    let expected_failure = (flat_body.borrow().id(), flat_ground.borrow().id(), MachError::DegenerateShapes);
+    assert!(world.solver_stats().failures().contains(&expected_failure), "expected the degenerate pair to be reported, but the failures were {:?}", world.solver_stats().failures());
+    assert!(translation < 10.0, "expected the other contact to be resolved, but the body stayed at {}", translation);
+}
+
+#[test]
+fn it_fails_to_cast_rays_against_unsupported_shapes() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    let unsupported_handle = world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        .. FixedBodyDef::default()
+    }, ());
+    // the sphere is further along the ray, behind the unsupported body
+    world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Sphere::new(1.0)),
+        translation: Vec3D::new(5.0, 0.0, 0.0),
+        .. FixedBodyDef::default()
+    }, ());
+    let ray = Ray::new(Vec3D::new(-5.0, 0.0, 0.0), UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0)));
+
+    let result = world.cast_ray(&ray).map(|hit| hit.map(|body| body.id()));
+
+    let unsupported_id = unsupported_handle.borrow().id();
+    assert_eq!(result, Err(MachError::UnsupportedRayCast(unsupported_id)));
+}
+
+#[test]
+fn it_casts_rays_against_spheres() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    let handle = world.create_fixed_body(FixedBodyDef {
+        shape: Box::new(Sphere::new(1.0)),
+        .. FixedBodyDef::default()
+    }, ());
+    let ray = Ray::new(Vec3D::new(-5.0, 0.0, 0.0), UnitVec3D::from(Vec3D::new(1.0, 0.0, 0.0)));
+
+    let result = world.cast_ray(&ray).map(|hit| hit.map(|body| body.id()));
+
+    assert_eq!(result, Ok(Some(handle.borrow().id())));
+}
+
+#[test]
+fn it_scales_the_gravity_of_each_body() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    world.set_gravity(Vec3D::new(0.0, 0.0, -10.0));
+    let handles = vec!(0.0, 0.5, -1.0).into_iter()
+        .enumerate()
+        .map(|(index, gravity_scale)| {
+            world.create_rigid_body(RigidBodyDef {
+                translation: Vec3D::new(5.0 * index as Scalar, 0.0, 0.0),
+                gravity_scale: gravity_scale,
+                .. RigidBodyDef::default()
+            }, ())
+        })
+        .collect::<Vec<_>>();
+
+    world.update(1.0);
+
+    let velocities = handles.iter()
+        .map(|handle| rigid_velocity(handle).z)
+        .collect::<Vec<Scalar>>();
+
+    assert_approx_eq!(velocities[0], 0.0);
+    assert_approx_eq!(velocities[1], -5.0);
+    assert_approx_eq!(velocities[2], 10.0);
+}
+
+#[test]
+fn it_applies_the_force_fields_to_every_rigid_body() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    world.add_force_field(Box::new(Wind::new(Vec3D::new(2.0, 0.0, 0.0), 1.0)));
+    world.add_force_field(Box::new(PointAttractor::new(Vec3D::new(0.0, 0.0, -2.0), 8.0)));
+    let handle = world.create_rigid_body(RigidBodyDef {
+        mass: 2.0,
+        .. RigidBodyDef::default()
+    }, ());
+
+    world.update(0.1);
+
+    // the wind pushes with a force of 2 and the attractor accelerates at 2
+    assert_approx_eq!(rigid_velocity(&handle), Vec3D::new(0.1, 0.0, -0.2));
+
+    world.clear_force_fields();
+    let velocity = rigid_velocity(&handle);
+    world.update(0.1);
+
+    assert_approx_eq!(rigid_velocity(&handle), velocity);
+}
+
+#[test]
+fn it_floats_bodies_lighter_than_the_fluid_at_their_equilibrium_depth() {
+    let mut world: MachWorld<()> = MachWorld::new();
+    world.set_gravity(Vec3D::new(0.0, 0.0, -10.0));
+    world.add_force_field(Box::new(
+        FluidVolume::half_space(Vec3D::zero(), Vec3D::new(0.0, 0.0, 1.0).normalize(), 1.0)
+            .with_linear_drag(2.0)
+            .with_angular_drag(1.0)
+    ));
+    let handle = world.create_rigid_body(RigidBodyDef {
+        shape: Box::new(Cuboid::cube(1.0)),
+        mass: 0.5,
+        translation: Vec3D::new(0.0, 0.0, 0.3),
+        .. RigidBodyDef::default()
+    }, ());
+
+    run_for(&mut world, 10.0);
+
+    // half of the cube displaces its own mass of fluid
+    let body = handle.borrow();
+    assert!(body.translation().z.abs() < 0.01, "expected the cube to float half submerged but its center was at {}", body.translation());
+}
@@ -5,11 +5,13 @@ macro_rules! assert_world_behaviour {
         mod world_behaviour {
             use super::test_subject;
 
+            use std::rc::Rc;
+
             use {PI, Scalar, World};
-            use maths::{CrossProduct, DotProduct, UnitQuat, Vec3D};
-            use dynamics::{DynamicBodyExtension, FixedBodyDef, RigidBodyDef, RigidBodyRef};
-            use collisions::CollisionObject;
-            use collisions::shapes::convex_shapes::Cuboid;
+            use maths::{CrossProduct, DotProduct, Transform, UnitQuat, Vec3D};
+            use dynamics::{ContactEvent, ContactEventKind, ContactMaterial, DynamicBodyExtension, DynamicBodyRef, FixedBodyDef, KinematicBodyDef, KinematicBodyRef, KinematicBodyRefMut, MaterialData, RigidBodyDef, RigidBodyRef, RigidBodyRefMut};
+            use collisions::{CollisionGroup, CollisionObject, OverlapEvent};
+            use collisions::shapes::convex_shapes::{ConvexShape, Cuboid, TriangleMesh};
 
             #[test]
             fn it_can_simulate_constant_velocity() {
@@ -30,6 +32,168 @@ macro_rules! assert_world_behaviour {
                 assert_approx_eq!(rigid_body.velocity(), Vec3D::new(1.0, -1.0, 0.5));
             }
 
+            #[test]
+            fn it_applies_accumulated_forces_for_a_single_update() {
+                let mut world = validate(test_subject());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    mass: 2.0,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                {
+                    let mut body = handle.borrow_mut();
+                    let mut rigid_body = RigidBodyRefMut::try_from(&mut *body)
+                        .expect("expected the body to be rigid but was not");
+
+                    rigid_body.apply_force(Vec3D::new(4.0, 0.0, 0.0));
+                    rigid_body.apply_torque(Vec3D::new(0.0, 0.0, 1.0));
+                }
+
+                world.update(0.5);
+                world.update(0.5);
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+
+                assert_approx_eq!(rigid_body.velocity(), Vec3D::new(1.0, 0.0, 0.0));
+                assert!(rigid_body.angular_velocity().z > 0.0);
+                assert_approx_eq!(rigid_body.force(), Vec3D::zero());
+                assert_approx_eq!(rigid_body.torque(), Vec3D::zero());
+            }
+
+            #[test]
+            fn it_damps_bodies_using_the_default_damping_of_the_world() {
+                let mut world = validate(test_subject());
+                world.set_default_damping(0.5, 0.5);
+
+                let damped_handle = world.create_rigid_body(RigidBodyDef {
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+                    .. RigidBodyDef::default()
+                }, ());
+                let undamped_handle = world.create_rigid_body(RigidBodyDef {
+                    translation: Vec3D::new(0.0, 5.0, 0.0),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+                    linear_damping: Some(0.0),
+                    angular_damping: Some(0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                world.update(0.5);
+
+                let damped_body = damped_handle.borrow();
+                let damped_rigid_body = RigidBodyRef::try_from(&*damped_body)
+                    .expect("expected the body to be rigid but was not");
+                // a single step divides the velocities by (1 + 0.5 * 0.5)
+                assert_approx_eq!(damped_rigid_body.velocity(), Vec3D::new(0.8, 0.0, 0.0));
+                assert_approx_eq!(damped_rigid_body.angular_velocity(), Vec3D::new(0.0, 0.0, 0.8));
+
+                let undamped_body = undamped_handle.borrow();
+                let undamped_rigid_body = RigidBodyRef::try_from(&*undamped_body)
+                    .expect("expected the body to be rigid but was not");
+                assert_approx_eq!(undamped_rigid_body.velocity(), Vec3D::new(1.0, 0.0, 0.0));
+                assert_approx_eq!(undamped_rigid_body.angular_velocity(), Vec3D::new(0.0, 0.0, 1.0));
+            }
+
+            #[test]
+            fn it_applies_impulses_immediately() {
+                let mut world = validate(test_subject());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    mass: 2.0,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                let mut body = handle.borrow_mut();
+                let mut rigid_body = RigidBodyRefMut::try_from(&mut *body)
+                    .expect("expected the body to be rigid but was not");
+
+                rigid_body.apply_impulse(Vec3D::new(0.0, 2.0, 0.0));
+                assert_approx_eq!(rigid_body.velocity(), Vec3D::new(0.0, 1.0, 0.0));
+                assert_approx_eq!(rigid_body.angular_velocity(), Vec3D::zero());
+
+                rigid_body.apply_impulse_at_point(Vec3D::new(0.0, 2.0, 0.0), Vec3D::new(0.5, 0.0, 0.0));
+                assert_approx_eq!(rigid_body.velocity(), Vec3D::new(0.0, 2.0, 0.0));
+                assert!(rigid_body.angular_velocity().z > 0.0);
+            }
+
+            #[test]
+            fn it_computes_the_mass_from_the_density() {
+                let mut world = validate(test_subject());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(2.0)),
+                    density: Some(2.0),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+
+                assert_approx_eq!(rigid_body.mass(), 16.0);
+            }
+
+            #[test]
+            fn it_rotates_bodies_about_their_center_of_mass() {
+                let mut world = validate(test_subject());
+                let vertices = vec!(
+                    Vec3D::new(0.0, 0.0, 0.0), Vec3D::new(1.0, 0.0, 0.0),
+                    Vec3D::new(1.0, 1.0, 0.0), Vec3D::new(0.0, 1.0, 0.0),
+                    Vec3D::new(0.0, 0.0, 1.0), Vec3D::new(1.0, 0.0, 1.0),
+                    Vec3D::new(1.0, 1.0, 1.0), Vec3D::new(0.0, 1.0, 1.0),
+                );
+                let mesh = TriangleMesh::new(Rc::new(vertices), vec!(
+                    (0, 2, 1), (0, 3, 2), (4, 5, 6), (4, 6, 7),
+                    (0, 1, 5), (0, 5, 4), (1, 2, 6), (1, 6, 5),
+                    (2, 3, 7), (2, 7, 6), (3, 0, 4), (3, 4, 7),
+                ));
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(mesh),
+                    angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                world.update(0.5);
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+
+                assert_approx_eq!(rigid_body.center_of_mass(), Vec3D::new(0.5, 0.5, 0.5));
+                assert!(rigid_body.translation().x > 0.0);
+            }
+
+            #[test]
+            fn it_rotates_the_inertia_tensor_into_world_coordinates() {
+                let mut world = validate(test_subject());
+                // a rotation about a skewed axis, which differs from its
+                // inverse even when applied to a symmetric tensor
+                let rotation = UnitQuat::from_axis_angle(Vec3D::new(1.0, 2.0, 3.0).normalize(), PI / 6.0);
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    mass: 3.0,
+                    shape: Box::new(Cuboid::new(1.0, 5.0, 0.5)),
+                    rotation: rotation,
+                    .. RigidBodyDef::default()
+                }, ());
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+
+                // the principal axes of the body are rotated with the body
+                let local_inertia = Cuboid::new(1.0, 5.0, 0.5).inertia() * 3.0;
+                let local_axes = [Vec3D::new(1.0, 0.0, 0.0), Vec3D::new(0.0, 1.0, 0.0), Vec3D::new(0.0, 0.0, 1.0)];
+                for (index, &local_axis) in local_axes.iter().enumerate() {
+                    let axis = rotation.rotate(local_axis);
+
+                    assert_approx_eq!(rigid_body.inertia() * axis, axis * local_inertia.get(index, index));
+                    assert_approx_eq!(rigid_body.inertia_inverse() * (rigid_body.inertia() * axis), axis);
+                }
+            }
+
             #[test]
             fn it_can_simulate_collisions_without_rotation() {
                 let mut world = validate(test_subject());
@@ -100,6 +264,454 @@ macro_rules! assert_world_behaviour {
                 assert!(rigid_body.velocity().dot(Vec3D::new(0.0, 0.0, 1.0)) > 0.0);
             }
 
+            #[test]
+            fn it_can_simulate_collisions_between_bodies_at_rest() {
+                let mut world = validate(test_subject());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. FixedBodyDef::default()
+                }, ());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.0, 0.0, 0.99),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                world.update(1.0 / 60.0);
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+                assert!(rigid_body.translation().z > 0.99, "expected the bodies to be pushed apart, but the translation was {}", rigid_body.translation());
+            }
+
+            #[test]
+            fn it_moves_kinematic_bodies_with_their_velocity() {
+                let mut world = validate(test_subject());
+                world.set_gravity(Vec3D::new(0.0, 0.0, -9.8));
+                let handle = world.create_kinematic_body(KinematicBodyDef {
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+                    .. KinematicBodyDef::default()
+                }, ());
+
+                world.update(0.5);
+
+                let body = handle.borrow();
+                let kinematic_body = KinematicBodyRef::try_from(&*body)
+                    .expect("expected the body to be kinematic but was not");
+
+                assert_approx_eq!(kinematic_body.translation(), Vec3D::new(0.5, 0.0, 0.0));
+                assert_approx_eq!(kinematic_body.velocity(), Vec3D::new(1.0, 0.0, 0.0));
+                assert_approx_eq!(kinematic_body.angular_velocity(), Vec3D::new(0.0, 0.0, 1.0));
+                assert!(kinematic_body.rotation().rotate(Vec3D::new(1.0, 0.0, 0.0)).y > 0.0);
+            }
+
+            #[test]
+            fn it_moves_kinematic_bodies_to_the_next_transform() {
+                let mut world = validate(test_subject());
+                let handle = world.create_kinematic_body(KinematicBodyDef::default(), ());
+                let rotation = UnitQuat::from_axis_angle(Vec3D::new(0.0, 0.0, 1.0).normalize(), 0.5);
+
+                {
+                    let mut body = handle.borrow_mut();
+                    let mut kinematic_body = KinematicBodyRefMut::try_from(&mut *body)
+                        .expect("expected the body to be kinematic but was not");
+
+                    kinematic_body.set_next_transform(Transform::new(Vec3D::new(1.0, 2.0, 0.0), rotation));
+                }
+
+                world.update(0.5);
+
+                let body = handle.borrow();
+                let kinematic_body = KinematicBodyRef::try_from(&*body)
+                    .expect("expected the body to be kinematic but was not");
+
+                assert_approx_eq!(kinematic_body.translation(), Vec3D::new(1.0, 2.0, 0.0));
+                assert_approx_eq!(kinematic_body.rotation(), rotation);
+                assert_approx_eq!(kinematic_body.velocity(), Vec3D::new(2.0, 4.0, 0.0));
+                assert_approx_eq!(kinematic_body.angular_velocity(), Vec3D::new(0.0, 0.0, 1.0));
+            }
+
+            #[test]
+            fn it_rotates_kinematic_bodies_about_their_center_of_mass() {
+                let mut world = validate(test_subject());
+                let vertices = vec!(
+                    Vec3D::new(0.0, 0.0, 0.0), Vec3D::new(1.0, 0.0, 0.0),
+                    Vec3D::new(1.0, 1.0, 0.0), Vec3D::new(0.0, 1.0, 0.0),
+                    Vec3D::new(0.0, 0.0, 1.0), Vec3D::new(1.0, 0.0, 1.0),
+                    Vec3D::new(1.0, 1.0, 1.0), Vec3D::new(0.0, 1.0, 1.0),
+                );
+                let mesh = TriangleMesh::new(Rc::new(vertices), vec!(
+                    (0, 2, 1), (0, 3, 2), (4, 5, 6), (4, 6, 7),
+                    (0, 1, 5), (0, 5, 4), (1, 2, 6), (1, 6, 5),
+                    (2, 3, 7), (2, 7, 6), (3, 0, 4), (3, 4, 7),
+                ));
+                let handle = world.create_kinematic_body(KinematicBodyDef {
+                    shape: Box::new(mesh),
+                    angular_velocity: Vec3D::new(0.0, 0.0, 1.0),
+                    .. KinematicBodyDef::default()
+                }, ());
+
+                world.update(0.5);
+
+                let body = handle.borrow();
+                let kinematic_body = KinematicBodyRef::try_from(&*body)
+                    .expect("expected the body to be kinematic but was not");
+                let center_of_mass = kinematic_body.center_of_mass();
+
+                assert_approx_eq!(center_of_mass, Vec3D::new(0.5, 0.5, 0.5));
+                assert_approx_eq!(
+                    DynamicBodyRef::from(&*body).velocity_at(center_of_mass + Vec3D::new(1.0, 0.0, 0.0)),
+                    Vec3D::new(0.0, 1.0, 0.0)
+                );
+            }
+
+            #[test]
+            fn it_pushes_rigid_bodies_with_kinematic_bodies_without_being_pushed_back() {
+                let mut world = validate(test_subject());
+                world.set_gravity(Vec3D::new(0.0, 0.0, -9.8));
+                let platform_handle = world.create_kinematic_body(KinematicBodyDef {
+                    shape: Box::new(Cuboid::new(4.0, 4.0, 1.0)),
+                    velocity: Vec3D::new(0.0, 0.0, 1.0),
+                    .. KinematicBodyDef::default()
+                }, ());
+                let box_handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.0, 0.0, 0.99),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                for _ in 0..10 {
+                    world.update(0.05);
+                }
+
+                let platform_body = platform_handle.borrow();
+                let platform = KinematicBodyRef::try_from(&*platform_body)
+                    .expect("expected the body to be kinematic but was not");
+                assert_approx_eq!(platform.translation(), Vec3D::new(0.0, 0.0, 0.5));
+                assert_approx_eq!(platform.velocity(), Vec3D::new(0.0, 0.0, 1.0));
+
+                let box_body = box_handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*box_body)
+                    .expect("expected the body to be rigid but was not");
+                assert!(rigid_body.translation().z > 1.2);
+            }
+
+            #[test]
+            fn it_reports_the_lifecycle_of_contacts() {
+                let mut world = validate(test_subject());
+                let handle_0 = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    restitution_coefficient: 1.0,
+                    .. RigidBodyDef::default()
+                }, ());
+                let handle_1 = world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(1.05, 0.0, 0.0),
+                    restitution_coefficient: 1.0,
+                    .. FixedBodyDef::default()
+                }, ());
+                let ids = (handle_0.borrow().id(), handle_1.borrow().id());
+
+                world.update(0.1);
+
+                let events: Vec<ContactEvent> = world.contact_events_iter().cloned().collect();
+                assert_eq!(events.len(), 1);
+                assert_eq!(events[0].kind(), ContactEventKind::Started);
+                assert_eq!(events[0].ids(), ids);
+                assert_approx_eq!(events[0].relative_speed(), 1.0);
+                assert!(events[0].normal_impulse() > 0.0);
+
+                world.update(0.5);
+
+                let events: Vec<ContactEvent> = world.contact_events_iter().cloned().collect();
+                assert_eq!(events.len(), 1);
+                assert_eq!(events[0].kind(), ContactEventKind::Ended);
+                assert_eq!(events[0].ids(), ids);
+            }
+
+            #[test]
+            fn it_does_not_resolve_contacts_disabled_by_the_contact_modifier() {
+                let mut world = validate(test_subject());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(1.05, 0.0, 0.0),
+                    .. FixedBodyDef::default()
+                }, ());
+
+                world.set_contact_modifier(Box::new(|contact| contact.disable()));
+
+                assert_eq!(world.update(0.1).len(), 0);
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+                assert_approx_eq!(rigid_body.translation(), Vec3D::new(0.1, 0.0, 0.0));
+                assert_approx_eq!(rigid_body.velocity(), Vec3D::new(1.0, 0.0, 0.0));
+            }
+
+            #[test]
+            fn it_applies_the_restitution_scale_from_the_contact_modifier() {
+                let mut world = validate(test_subject());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    restitution_coefficient: 1.0,
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(1.05, 0.0, 0.0),
+                    restitution_coefficient: 1.0,
+                    .. FixedBodyDef::default()
+                }, ());
+
+                world.set_contact_modifier(Box::new(|contact| contact.set_restitution_scale(0.0)));
+
+                assert_eq!(world.update(0.1).len(), 1);
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+                assert!(rigid_body.velocity().x.abs() < 0.1);
+            }
+
+            #[test]
+            fn it_uses_the_pair_override_for_registered_materials() {
+                let mut world = validate(test_subject());
+                world.material_registry_mut().register("rubber", MaterialData {
+                    restitution_coefficient: 1.0,
+                    .. MaterialData::default()
+                });
+                world.material_registry_mut().register("clay", MaterialData {
+                    restitution_coefficient: 1.0,
+                    .. MaterialData::default()
+                });
+                world.material_registry_mut().override_pair("rubber", "clay", ContactMaterial {
+                    friction_coefficient: 0.5,
+                    restitution_coefficient: 0.0,
+                    rolling_friction: 0.0,
+                    torsional_friction: 0.0,
+                });
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    material: Some("rubber".to_string()),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(1.05, 0.0, 0.0),
+                    material: Some("clay".to_string()),
+                    .. FixedBodyDef::default()
+                }, ());
+
+                assert_eq!(world.update(0.1).len(), 1);
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+                assert!(rigid_body.velocity().x.abs() < 0.1);
+            }
+
+            #[test]
+            fn it_does_not_generate_contacts_for_ignored_pairs() {
+                let mut world = validate(test_subject());
+                let handle_0 = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. RigidBodyDef::default()
+                }, ());
+                let handle_1 = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                let id_0 = handle_0.borrow().id();
+                let id_1 = handle_1.borrow().id();
+                world.collision_filter_mut().ignore_pair(id_1, id_0);
+
+                assert_eq!(world.update(0.1).len(), 0);
+            }
+
+            fn contacts_between_groups(group_0: CollisionGroup, group_1: CollisionGroup) -> usize {
+                let mut world = validate(test_subject());
+                world.create_rigid_body(RigidBodyDef {
+                    group: group_0,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_rigid_body(RigidBodyDef {
+                    group: group_1,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                world.update(0.1).len()
+            }
+
+            #[test]
+            fn it_only_generates_contacts_for_groups_accepting_the_category_of_each_other() {
+                let group_0 = CollisionGroup::Custom { category: 1 << 8, mask: 1 << 9 };
+                let group_1 = CollisionGroup::Custom { category: 1 << 9, mask: 1 << 8 };
+                let one_sided = CollisionGroup::Custom { category: 1 << 9, mask: 0 };
+
+                assert_eq!(contacts_between_groups(group_0, group_1), 1);
+                assert_eq!(contacts_between_groups(group_1, group_0), 1);
+                assert_eq!(contacts_between_groups(group_0, one_sided), 0);
+                assert_eq!(contacts_between_groups(group_0, group_0), 0);
+                assert_eq!(contacts_between_groups(CollisionGroup::A, CollisionGroup::A), 1);
+                assert_eq!(contacts_between_groups(CollisionGroup::A, CollisionGroup::B), 0);
+                assert_eq!(contacts_between_groups(CollisionGroup::Default, CollisionGroup::B), 0);
+            }
+
+            #[test]
+            fn it_filters_the_contacts_with_fixed_and_kinematic_bodies_by_their_groups() {
+                let mut world = validate(test_subject());
+                let static_group = CollisionGroup::Custom { category: 1 << 8, mask: 1 << 9 };
+                world.create_fixed_body(FixedBodyDef {
+                    group: static_group,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. FixedBodyDef::default()
+                }, ());
+                world.create_kinematic_body(KinematicBodyDef {
+                    group: static_group,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.0, 5.0, 0.0),
+                    .. KinematicBodyDef::default()
+                }, ());
+                let handle_0 = world.create_rigid_body(RigidBodyDef {
+                    group: CollisionGroup::Custom { category: 1 << 9, mask: 1 << 8 },
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.0, 5.9, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+
+                let contacts = world.update(0.1);
+
+                // the default group does not accept the custom category, and
+                // the fixed and kinematic bodies are far apart
+                assert_eq!(contacts.len(), 1);
+                let id_0 = handle_0.borrow().id();
+                let &(ref contact_handle_0, ref contact_handle_1) = contacts[0].handles();
+                assert!(contact_handle_0.borrow().id() == id_0 || contact_handle_1.borrow().id() == id_0);
+            }
+
+            #[test]
+            fn it_does_not_generate_contacts_for_pairs_rejected_by_the_filter_callback() {
+                let mut world = validate(test_subject());
+                world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    .. FixedBodyDef::default()
+                }, ());
+
+                world.collision_filter_mut().set_callback(|_extension_0, _extension_1| false);
+
+                assert_eq!(world.update(0.1).len(), 0);
+            }
+
+            #[test]
+            fn it_reports_sensor_overlaps_without_a_response() {
+                let mut world = validate(test_subject());
+                let handle = world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    is_sensor: true,
+                    .. FixedBodyDef::default()
+                }, ());
+
+                let contacts = world.update(0.1);
+
+                assert_eq!(contacts.len(), 0);
+                assert_eq!(world.overlap_events_iter().count(), 1);
+                match world.overlap_events_iter().next() {
+                    Some(&OverlapEvent::Enter(..)) => (),
+                    _otherwise => panic!("expected an enter event"),
+                }
+
+                let body = handle.borrow();
+                let rigid_body = RigidBodyRef::try_from(&*body)
+                    .expect("expected the body to be rigid but was not");
+                assert_approx_eq!(rigid_body.velocity(), Vec3D::new(1.0, 0.0, 0.0));
+            }
+
+            #[test]
+            fn it_reports_when_bodies_stop_overlapping_a_sensor() {
+                let mut world = validate(test_subject());
+                world.create_rigid_body(RigidBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(10.0, 0.0, 0.0),
+                    .. RigidBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    is_sensor: true,
+                    .. FixedBodyDef::default()
+                }, ());
+
+                world.update(0.01);
+                world.update(0.01);
+                match world.overlap_events_iter().next() {
+                    Some(&OverlapEvent::Stay(..)) => (),
+                    _otherwise => panic!("expected a stay event"),
+                }
+
+                world.update(0.5);
+                match world.overlap_events_iter().next() {
+                    Some(&OverlapEvent::Exit(..)) => (),
+                    _otherwise => panic!("expected an exit event"),
+                };
+            }
+
+            #[test]
+            fn it_reports_kinematic_bodies_entering_a_fixed_sensor() {
+                let mut world = validate(test_subject());
+                world.create_kinematic_body(KinematicBodyDef {
+                    group: CollisionGroup::Default,
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    velocity: Vec3D::new(1.0, 0.0, 0.0),
+                    .. KinematicBodyDef::default()
+                }, ());
+                world.create_fixed_body(FixedBodyDef {
+                    shape: Box::new(Cuboid::cube(1.0)),
+                    translation: Vec3D::new(0.9, 0.0, 0.0),
+                    is_sensor: true,
+                    .. FixedBodyDef::default()
+                }, ());
+
+                let contacts = world.update(0.1);
+
+                assert_eq!(contacts.len(), 0);
+                match world.overlap_events_iter().next() {
+                    Some(&OverlapEvent::Enter(..)) => (),
+                    _otherwise => panic!("expected an enter event"),
+                };
+            }
+
             fn validate<O, W>(input: W) -> W where O: CollisionObject<Extension=DynamicBodyExtension<()>>, W: World<O> {
                 input
             }